@@ -1,15 +1,35 @@
-// use aostar::aostar::AOStar;
-// use mdp::canadian_traveler::*;
-// use mdp::heuristic::ZeroHeuristic;
-//
-// #[test]
-// fn test_canadian_traveler_aostar() {
-//     let mdp = CanadianTravelerMDP::default();
-//     let zero_heuristic = ZeroHeuristic {};
-//     let err = 1e-1;
-//     let mut aostar = AOStar::new(mdp, zero_heuristic);
-//     aostar.ilaostar(err);
-//     aostar.dump();
-//     println!("{:?}", aostar.root_f());
-//     //     assert_approx_eq!(2.0 * (2.0 as f32).sqrt(), aostar.root_f(), err);
-// }
+use aostar::aostar::AOStar;
+use assert_approx_eq::assert_approx_eq;
+use mdp::canadian_traveler::*;
+use mdp::heuristic::{HminHeuristic, ZeroHeuristic};
+
+// Go to node 1 first; if its edge to the goal is blocked, try the edge from
+// node 2 before falling back to the direct edge:
+// 1 + 0.5 * 2 + 0.5 * (3 + 0.5 * 2 + 0.5 * 12) = 7.
+const OPTIMAL_VALUE: f32 = 7.0;
+
+#[test]
+fn test_canadian_traveler_aostar() {
+    let mdp = CanadianTravelerMDP::from_data("small.yaml");
+    let mut aostar = AOStar::new(mdp, ZeroHeuristic {});
+    aostar.aostar();
+    assert_approx_eq!(OPTIMAL_VALUE, aostar.root_f(), 1e-3);
+}
+
+#[test]
+fn test_canadian_traveler_laostar() {
+    let mdp = CanadianTravelerMDP::default();
+    let err = 1e-3;
+    let mut aostar = AOStar::new(mdp, ZeroHeuristic {});
+    aostar.ilaostar(err);
+    assert_approx_eq!(OPTIMAL_VALUE, aostar.root_f(), err);
+}
+
+#[test]
+fn test_canadian_traveler_laostar_hmin() {
+    let mdp = CanadianTravelerMDP::default();
+    let err = 1e-3;
+    let mut aostar = AOStar::new(mdp, HminHeuristic::new());
+    aostar.ilaostar(err);
+    assert_approx_eq!(OPTIMAL_VALUE, aostar.root_f(), err);
+}
@@ -53,6 +53,26 @@ impl<S: State, H, U> BRTDP<S, H, U> {
     }
 }
 
+impl<S: State, H, U> BRTDP<S, H, U> {
+    pub fn best_ub_action_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> Option<M::Action>
+    where
+        M: PMassMut<f32> + Cost + ActionEnumerable + ActionAvailability + StatesActions<State = S>,
+        H: HeuristicWithMDPMut<M>,
+        U: UpperBoundWithMDPMut<M>,
+    {
+        self.best_action_mut(s, mdp, ActionSelection::UB)
+    }
+
+    pub fn gap_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
+    where
+        M: StatesActions<State = S> + PMassMut<f32> + Cost,
+        H: HeuristicWithMDPMut<M>,
+        U: UpperBoundWithMDPMut<M>,
+    {
+        self.get_ub_mut(s, mdp) - self.get_lb_mut(s, mdp)
+    }
+}
+
 impl<S: State, H, U> BRTDP<S, H, U> {
     pub fn new(h: H, u: U) -> BRTDP<S, H, U> {
         BRTDP {
@@ -147,8 +167,8 @@ impl<S: State, H, U> BRTDP<S, H, U> {
 
         while let Some(s) = visited.pop_front() {
             let a = self.best_action_mut(&s, mdp, ActionSelection::LB).unwrap();
-            max_residual = max_residual.max(self.update_ub(&current_state, mdp));
-            max_residual = max_residual.max(self.update_lb(&current_state, &a, mdp));
+            max_residual = max_residual.max(self.update_ub(&s, mdp));
+            max_residual = max_residual.max(self.update_lb(&s, &a, mdp));
         }
 
         max_residual
@@ -168,9 +188,8 @@ impl<S: State, H, U> BRTDP<S, H, U> {
         U: UpperBoundWithMDPMut<M>,
     {
         let initial_state = mdp.initial_state();
-        for k in 0..num_trials {
-            let gap = self.get_ub_mut(&initial_state, mdp) - self.get_lb_mut(&initial_state, mdp);
-            if gap < 1e-3 {
+        for _k in 0..num_trials {
+            if self.gap_mut(&initial_state, mdp) < 1e-3 {
                 break;
             }
             self.trial(mdp, rng);
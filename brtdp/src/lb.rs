@@ -1,13 +1,10 @@
 use mdp::heuristic::HeuristicWithMDPMut;
-use mdp::mdp_traits::{
-    Cost,
-    PMassMut, State, StatesActions,
-};
+use mdp::mdp_traits::{Cost, PMassMut, State, StatesActions};
 
 use crate::BRTDP;
 
 impl<S: State, H, U> BRTDP<S, H, U> {
-    pub(crate) fn get_lb_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
+    pub fn get_lb_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
     where
         M: StatesActions<State = S> + PMassMut<f32> + Cost,
         H: HeuristicWithMDPMut<M>,
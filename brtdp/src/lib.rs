@@ -7,3 +7,4 @@ mod ub;
 
 pub use brtdp::BRTDP;
 pub use constant_upper_bound::ConstantUpperBound;
+pub use traits::UpperBoundWithMDPMut;
@@ -6,7 +6,7 @@ use mdp::{
 use crate::{action_selection::ActionSelection, traits::UpperBoundWithMDPMut, BRTDP};
 
 impl<S: State, H, U> BRTDP<S, H, U> {
-    pub(crate) fn get_ub_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
+    pub fn get_ub_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
     where
         M: StatesActions<State = S> + PMassMut<f32> + Cost,
         U: UpperBoundWithMDPMut<M>,
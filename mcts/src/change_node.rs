@@ -4,6 +4,7 @@ use core::hash::Hash;
 use mdp::arena::Arena;
 
 use crate::decision_node::MCTSDecisionNode;
+use crate::NodeSharing;
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct MCTSChanceNode<A: Eq + PartialEq + Debug + Copy + Clone + Hash> {
@@ -36,4 +37,18 @@ impl<A: Eq + PartialEq + Debug + Copy + Clone + Hash> MCTSChanceNode<A> {
         }
         None
     }
+
+    pub fn find_s_with<S: PartialEq + Eq + Clone + Copy + Hash + Debug>(
+        &self,
+        s: &S,
+        arena: &Arena<MCTSDecisionNode<S, A>>,
+        node_sharing: &NodeSharing<S>,
+    ) -> Option<usize> {
+        node_sharing.find_s(
+            s,
+            self.children
+                .iter()
+                .map(|child| (*child, arena.get_node(*child).assoc)),
+        )
+    }
 }
@@ -6,6 +6,7 @@ mod eval;
 mod into_iterator;
 pub mod mcts;
 mod mcts_episode_iterator;
+mod node_sharing;
 mod run_episode;
 mod traits;
 
@@ -14,5 +15,6 @@ pub use crate::budget::Budget;
 pub use crate::change_node::MCTSChanceNode;
 pub use crate::decision_node::MCTSDecisionNode;
 pub use crate::mcts::MCTS;
-pub use crate::traits::{MCTSTrait, RunEpisode, SetMCTSParams};
+pub use crate::node_sharing::NodeSharing;
+pub use crate::traits::{EpisodeReport, MCTSTrait, RunEpisode, SetMCTSParams};
 pub use mcts_episode_iterator::MCTSEpisodeIterator;
@@ -1,12 +1,14 @@
 use core::f32::MIN;
+use std::collections::HashMap;
 
 use mdp::arena::Arena;
 use mdp::episode_runner::monte_carlo_evaluation;
 use mdp::mdp_traits::*;
 use mdp::policy::policy_traits::GetActionMut;
+use mdp::value_estimator::{CostEstimator, CostEstimatorMut};
 
 use crate::decision_node::MCTSDecisionNode;
-use crate::{BackupOperator, Budget, MCTSTrait, SetMCTSParams};
+use crate::{BackupOperator, Budget, EpisodeReport, MCTSTrait, NodeSharing, SetMCTSParams};
 
 use rand::prelude::*;
 
@@ -14,11 +16,14 @@ pub struct MCTS<M: StatesActions, P> {
     pub mdp: M,
     pub(crate) base_line_policy: P,
     pub(crate) arena: Arena<MCTSDecisionNode<M::State, M::Action>>,
+    pub(crate) nodes_of_state: HashMap<M::State, Vec<usize>>,
     pub(crate) c: f32,
     pub(crate) num_rollouts: usize,
     pub(crate) budget: Budget,
     pub(crate) backup_operator: BackupOperator,
     pub(crate) lookahead: Option<usize>,
+    pub(crate) node_sharing: NodeSharing<M::State>,
+    pub(crate) episode_report: Option<EpisodeReport<M::State>>,
 }
 
 impl<M, P> MCTSTrait for MCTS<M, P>
@@ -83,11 +88,14 @@ impl<M: StatesActions + InitialState, P> MCTS<M, P> {
             mdp: mdp,
             base_line_policy: base_line_policy,
             arena: Arena::new(),
+            nodes_of_state: HashMap::new(),
             c: 0.5,
             num_rollouts: 10,
             budget: Budget::NumIterations(1000),
             backup_operator: BackupOperator::MonteCarlo,
             lookahead: None,
+            node_sharing: NodeSharing::ClosedLoop,
+            episode_report: None,
         };
 
         mcts.add_node(mcts.mdp.initial_state());
@@ -99,10 +107,21 @@ impl<M: StatesActions + InitialState, P> MCTS<M, P> {
         self
     }
 
+    pub fn set_node_sharing(mut self, node_sharing: NodeSharing<M::State>) -> MCTS<M, P> {
+        self.node_sharing = node_sharing;
+        self
+    }
+
+    pub fn set_episode_report(mut self, episode_report: EpisodeReport<M::State>) -> MCTS<M, P> {
+        self.episode_report = Some(episode_report);
+        self
+    }
+
     pub(crate) fn add_node(&mut self, s: M::State) -> usize {
         let next_id = self.arena.next_id();
 
         self.arena.add_node(MCTSDecisionNode::new(s, next_id));
+        self.nodes_of_state.entry(s).or_default().push(next_id);
         next_id
     }
 
@@ -118,6 +137,7 @@ impl<M: StatesActions + InitialState, P> MCTS<M, P> {
 
     pub fn clear(&mut self) {
         self.arena.clear();
+        self.nodes_of_state.clear();
         self.add_node(self.mdp.initial_state());
     }
 }
@@ -135,8 +155,9 @@ impl<
     > MCTS<M, P>
 {
     pub fn solve(&mut self, n: usize, rng: &mut ThreadRng) {
+        let s = self.arena.get_node(0).assoc;
         for _i in 0..n {
-            self.expand_recursive(0, rng);
+            self.expand_recursive(0, s, rng);
         }
     }
 
@@ -186,8 +207,13 @@ impl<
         s_node.v = s_node.max_child();
     }
 
-    pub(crate) fn expand_recursive(&mut self, s_id: usize, rng: &mut ThreadRng) -> f32 {
-        if self.mdp.is_terminal(&self.arena.get_node(s_id).assoc) {
+    pub(crate) fn expand_recursive(
+        &mut self,
+        s_id: usize,
+        s: M::State,
+        rng: &mut ThreadRng,
+    ) -> f32 {
+        if self.mdp.is_terminal(&s) {
             let s_node = self.arena.get_node_mut(s_id);
             s_node.num_visited += 1;
             s_node.v = 0.0;
@@ -200,7 +226,7 @@ impl<
             } else {
                 let mut runner = self
                     .base_line_policy
-                    .into_eval_mut(self.arena.get_node(s_id).assoc, &mut self.mdp)
+                    .into_eval_mut(s, &mut self.mdp)
                     .set_max_horizon(self.lookahead);
                 let cost = monte_carlo_evaluation(&mut runner, rng, self.num_rollouts);
                 //                 assert!(
@@ -219,17 +245,18 @@ impl<
             -1.0 * cost
         } else {
             if let Some(a_id) = self.arena.get_node(s_id).best_and_node_ucb(self.c) {
-                let s = self.arena.get_node(s_id).assoc;
                 let a = self.arena.get_node(s_id).children[a_id].a;
                 let ss = self.mdp.get_next_state_mut(&s, &a, rng);
 
                 unsafe {
                     let self_p = self as *mut Self;
-                    if let Some(ss_id) =
-                        (*self_p).arena.get_node(s_id).children[a_id].find_s(&ss, &(*self_p).arena)
-                    {
+                    if let Some(ss_id) = (*self_p).arena.get_node(s_id).children[a_id].find_s_with(
+                        &ss,
+                        &(*self_p).arena,
+                        &(*self_p).node_sharing,
+                    ) {
                         let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                        let future_r = (*self_p).expand_recursive(ss_id, rng);
+                        let future_r = (*self_p).expand_recursive(ss_id, ss, rng);
                         let nv = r + future_r;
                         (*self_p).update(s_id, a_id, r, future_r);
                         nv
@@ -241,7 +268,7 @@ impl<
                             .push(ss_id);
 
                         let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                        let future_r = (*self_p).expand_recursive(ss_id, rng);
+                        let future_r = (*self_p).expand_recursive(ss_id, ss, rng);
                         let nv = r + future_r;
                         (*self_p).update(s_id, a_id, r, future_r);
                         nv
@@ -300,11 +327,60 @@ impl<
     }
 }
 
+// Q-values are read from the most visited expanded node for `s`, negated into
+// costs. Actions never tried from there cost `f32::INFINITY`, and a state that
+// was never expanded gets the same cost for every action.
+impl<M: StatesActions, P> MCTS<M, P> {
+    fn find_decision_node(&self, s: &M::State) -> Option<&MCTSDecisionNode<M::State, M::Action>> {
+        self.nodes_of_state
+            .get(s)?
+            .iter()
+            .map(|id| self.arena.get_node(*id))
+            .filter(|node| !node.children.is_empty())
+            .max_by_key(|node| node.num_visited)
+    }
+}
+
+impl<M: StatesActions, P> CostEstimator<M> for MCTS<M, P> {
+    fn get_qsa_ssp(&self, s: &M::State, a: &M::Action, _mdp: &M) -> f32 {
+        match self.find_decision_node(s) {
+            Some(node) => node
+                .children
+                .iter()
+                .find(|child| child.a == *a && child.num_visited > 0)
+                .map_or(f32::INFINITY, |child| -child.q),
+            None => 0.0,
+        }
+    }
+
+    fn get_value_ssp(&self, s: &M::State, _mdp: &M) -> f32 {
+        match self.find_decision_node(s) {
+            Some(node) => node
+                .children
+                .iter()
+                .filter(|child| child.num_visited > 0)
+                .map(|child| -child.q)
+                .fold(f32::INFINITY, f32::min),
+            None => 0.0,
+        }
+    }
+}
+
+impl<M: StatesActions, P> CostEstimatorMut<M> for MCTS<M, P> {
+    fn get_qsa_ssp_mut(&mut self, s: &M::State, a: &M::Action, mdp: &mut M) -> f32 {
+        self.get_qsa_ssp(s, a, mdp)
+    }
+
+    fn get_value_ssp_mut(&mut self, s: &M::State, mdp: &mut M) -> f32 {
+        self.get_value_ssp(s, mdp)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use mdp::finite_horizon_wrapper::FiniteHorizonWrapper;
+    use mdp::finite_horizon_wrapper::{FiniteHorizonWrapper, FiniteHorizonWrapperState};
     use mdp::grid_world::GridWorldAction::AttemptUp;
     use mdp::grid_world::{GridWorldMDP, GridWorldState};
     use mdp::policy::random_from_candidates_policy::RandomFromCandidatesPolicy;
@@ -371,6 +447,30 @@ mod tests {
         mcts.dump();
     }
 
+    #[test]
+    fn test_mcts_open_loop() {
+        let mdp = GridWorldMDP::new(
+            4,
+            4,
+            GridWorldState::new(0, 0),
+            GridWorldState::new(3, 3),
+            vec![GridWorldState::new(2, 3)],
+            vec![],
+        );
+        let finite_horizon_mdp = FiniteHorizonWrapper::new(mdp, 4);
+
+        let random_policy = RandomPolicy {};
+        let mut mcts =
+            MCTS::new(finite_horizon_mdp, random_policy).set_node_sharing(NodeSharing::OpenLoop);
+        let mut rng = thread_rng();
+        mcts.solve(100, &mut rng);
+        for s_node in mcts.arena.nodes.iter() {
+            for a_node in s_node.children.iter() {
+                assert!(a_node.children.len() <= 1);
+            }
+        }
+    }
+
     #[test]
     fn test_mcts() {
         let mdp = GridWorldMDP::new(
@@ -393,4 +493,34 @@ mod tests {
         mcts.solve(1, &mut rng);
         mcts.dump();
     }
+
+    #[test]
+    fn test_mcts_root_cost_estimates() {
+        let mdp = GridWorldMDP::new(
+            4,
+            4,
+            GridWorldState::new(0, 0),
+            GridWorldState::new(3, 3),
+            vec![GridWorldState::new(2, 3)],
+            vec![],
+        );
+        let mut finite_horizon_mdp = FiniteHorizonWrapper::new(mdp, 8);
+        let s = finite_horizon_mdp.initial_state();
+
+        let random_policy = RandomPolicy {};
+        let mut mcts = MCTS::new(finite_horizon_mdp.clone(), random_policy);
+        let mut rng = thread_rng();
+        mcts.solve(200, &mut rng);
+
+        let value = mcts.get_value_ssp_mut(&s, &mut finite_horizon_mdp);
+        assert_eq!(value, -mcts.root_value());
+        for a in finite_horizon_mdp.enumerate_actions() {
+            assert!(mcts.get_qsa_ssp(&s, a, &finite_horizon_mdp) >= value);
+        }
+
+        let unseen = FiniteHorizonWrapperState::new(GridWorldState::new(3, 0), 100);
+        for a in finite_horizon_mdp.enumerate_actions() {
+            assert_eq!(mcts.get_qsa_ssp(&unseen, a, &finite_horizon_mdp), 0.0);
+        }
+    }
 }
@@ -14,6 +14,7 @@ use crate::{Budget, MCTS};
 pub struct MCTSEpisodeIterator<'a, M: StatesActions, P> {
     mcts: &'a mut MCTS<M, P>,
     node_id: usize,
+    s: M::State,
     budget: Budget,
     rng: &'a mut rand::rngs::ThreadRng,
 }
@@ -24,9 +25,11 @@ impl<'a, M: StatesActions + InitialState, P> MCTSEpisodeIterator<'a, M, P> {
         budget: Budget,
         rng: &'a mut rand::rngs::ThreadRng,
     ) -> Self {
+        let s = mcts.mdp.initial_state();
         MCTSEpisodeIterator {
             mcts,
             node_id: 0,
+            s,
             budget: budget,
             rng,
         }
@@ -50,7 +53,7 @@ impl<
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let s = self.mcts.arena.get_node(self.node_id).assoc;
+            let s = self.s;
             info!("state: {:?}", s);
 
             if self.mcts.mdp.is_terminal(&s) {
@@ -73,7 +76,7 @@ impl<
                     }
                 }
                 num_iterations += 1;
-                self.mcts.expand_recursive(self.node_id, &mut self.rng);
+                self.mcts.expand_recursive(self.node_id, s, &mut self.rng);
             }
 
             debug!("num iterations: {:?}", num_iterations);
@@ -98,7 +101,9 @@ impl<
             let ss = self.mcts.mdp.get_next_state_mut(&s, &a, &mut self.rng);
             info!("ss: {:?}", ss);
             if let Some(and_node_inner) = and_node {
-                if let Some(ss_id) = and_node_inner.find_s(&ss, &self.mcts.arena) {
+                if let Some(ss_id) =
+                    and_node_inner.find_s_with(&ss, &self.mcts.arena, &self.mcts.node_sharing)
+                {
                     self.node_id = ss_id;
                 } else {
                     self.node_id = self.mcts.add_node(ss);
@@ -107,6 +112,8 @@ impl<
                 self.node_id = self.mcts.add_node(ss);
             }
 
+            self.s = ss;
+
             let c = self.mcts.mdp.d_cost(&s, &a, &ss);
             debug!("cost: {}", c);
 
@@ -0,0 +1,30 @@
+/// Determines which decision node a sampled successor is routed to.
+pub enum NodeSharing<S> {
+    /// One decision node per distinct successor state.
+    ClosedLoop,
+    /// Successors are matched on the abstraction of their state, so nearly
+    /// identical states (e.g. beliefs in the same grid cell) share statistics.
+    Abstraction(Box<dyn Fn(&S) -> S>),
+    /// Every chance node has a single successor, so decision nodes correspond
+    /// to action sequences rather than states.
+    OpenLoop,
+}
+
+impl<S: PartialEq> NodeSharing<S> {
+    pub fn find_s<I: IntoIterator<Item = (usize, S)>>(&self, ss: &S, children: I) -> Option<usize> {
+        match self {
+            NodeSharing::ClosedLoop => children
+                .into_iter()
+                .find(|(_, s)| s == ss)
+                .map(|(id, _)| id),
+            NodeSharing::Abstraction(f) => {
+                let key = f(ss);
+                children
+                    .into_iter()
+                    .find(|(_, s)| f(s) == key)
+                    .map(|(id, _)| id)
+            }
+            NodeSharing::OpenLoop => children.into_iter().next().map(|(id, _)| id),
+        }
+    }
+}
@@ -23,14 +23,22 @@ where
 {
     fn run_episode(&mut self, rng: &mut ThreadRng) -> f32 {
         let mut sum = 0.0;
+        let mut trajectory = vec![];
         unsafe {
             let self_p = self as *const Self;
-            for (s, a, _ss, c) in self.into_iter_with(rng) {
+            for (s, a, ss, c) in self.into_iter_with(rng) {
                 println!("{:?}", a);
                 (*self_p).display(&s);
                 sum += c;
+                if trajectory.is_empty() {
+                    trajectory.push(s);
+                }
+                trajectory.push(ss);
             }
         }
+        if let Some(report) = &self.episode_report {
+            report(&trajectory);
+        }
         sum
     }
 }
@@ -14,3 +14,7 @@ pub trait SetMCTSParams {
 pub trait RunEpisode {
     fn run_episode(&mut self, rng: &mut ThreadRng) -> f32;
 }
+
+/// Called by `run_episode` with the states visited along the episode, the
+/// initial one included.
+pub type EpisodeReport<S> = Box<dyn Fn(&[S])>;
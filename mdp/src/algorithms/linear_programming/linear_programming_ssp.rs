@@ -0,0 +1,135 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::common::value_table::ValueTable;
+use crate::mdp_traits::*;
+
+use super::simplex::simplex;
+
+/// Solves an SSP as the linear program
+/// max sum_s V(s) s.t. V(s) <= C(s, a) + sum_s' T(s, a, s') V(s') for every available action.
+/// Each V(s) is split into a nonnegative and a nonpositive part so that negative costs are allowed.
+/// States from which no terminal state is reachable keep the initial value 1e+8.
+pub fn linear_programming_ssp<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    mdp: &M,
+) -> ValueTable<M::State> {
+    let dead_end_value = 1e+8;
+    let mut value_table = ValueTable::<M::State>::new(dead_end_value);
+    let can_reach_goal = states_reaching_terminal(mdp);
+
+    let mut variable_ids = HashMap::new();
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) {
+            value_table.set_value(s, 0.0);
+        } else if can_reach_goal.contains(s) {
+            let id = variable_ids.len();
+            variable_ids.insert(*s, id);
+        }
+    }
+
+    let n = variable_ids.len();
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for (s, id) in variable_ids.iter() {
+        for action in mdp.enumerate_actions() {
+            if !mdp.action_available(s, action) {
+                continue;
+            }
+            let mut row = vec![0.0; 2 * n];
+            row[*id] += 1.0;
+            row[n + *id] -= 1.0;
+            let mut rhs = mdp.cost(s, action) as f64;
+            for (ss, p) in mdp.p_mass(s, action) {
+                if let Some(ss_id) = variable_ids.get(&ss) {
+                    row[*ss_id] -= p as f64;
+                    row[n + *ss_id] += p as f64;
+                } else if !mdp.is_terminal(&ss) {
+                    rhs += p as f64 * dead_end_value as f64;
+                }
+            }
+            a.push(row);
+            b.push(rhs);
+        }
+    }
+
+    let c = [vec![1.0; n], vec![-1.0; n]].concat();
+    let x = simplex(a, b, c).expect("The SSP linear program is infeasible or unbounded");
+    for (s, id) in variable_ids.iter() {
+        value_table.set_value(s, (x[*id] - x[n + *id]) as f32);
+    }
+
+    value_table
+}
+
+fn states_reaching_terminal<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + IsTerminal,
+>(
+    mdp: &M,
+) -> HashSet<M::State> {
+    let mut predecessors = HashMap::<M::State, Vec<M::State>>::new();
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) {
+            continue;
+        }
+        for a in mdp.enumerate_actions() {
+            if !mdp.action_available(s, a) {
+                continue;
+            }
+            for (ss, p) in mdp.p_mass(s, a) {
+                if p > 0.0 {
+                    predecessors.entry(ss).or_insert_with(Vec::new).push(*s);
+                }
+            }
+        }
+    }
+
+    let mut stack = mdp
+        .enumerate_states()
+        .filter(|s| mdp.is_terminal(s))
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut reached = stack.iter().cloned().collect::<HashSet<_>>();
+    while let Some(s) = stack.pop() {
+        if let Some(ps) = predecessors.get(&s) {
+            for ps in ps {
+                if reached.insert(*ps) {
+                    stack.push(*ps);
+                }
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baker_grid::{BakerGridMDP, BakerGridPartialMDP, BakerGridState};
+    use crate::grid_world::GridWorldMDP;
+    use crate::value_iteration::value_iteration_ssp;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_linear_programming_grid_world() {
+        let mdp = GridWorldMDP::default();
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = linear_programming_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_linear_programming_baker() {
+        let obstacles = vec![(2, 4), (3, 4), (4, 4)];
+        let partial_mdp = BakerGridPartialMDP::new(5, 9, obstacles);
+        let mdp: BakerGridMDP = partial_mdp.build_from(&BakerGridState::new(0, 8));
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = linear_programming_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+}
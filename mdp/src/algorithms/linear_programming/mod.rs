@@ -0,0 +1,4 @@
+pub use self::linear_programming_ssp::linear_programming_ssp;
+
+mod linear_programming_ssp;
+mod simplex;
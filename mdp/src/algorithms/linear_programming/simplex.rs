@@ -0,0 +1,176 @@
+const EPS: f64 = 1e-9;
+
+/// Maximizes `c^T x` subject to `a x <= b` and `x >= 0` with the two-phase
+/// tableau simplex method. Rows with a negative `b_i` get an artificial
+/// variable that phase 1 drives to zero. Returns `None` if the problem is
+/// infeasible or unbounded.
+pub(crate) fn simplex(a: Vec<Vec<f64>>, b: Vec<f64>, c: Vec<f64>) -> Option<Vec<f64>> {
+    let m = a.len();
+    let n = c.len();
+    assert_eq!(b.len(), m);
+    let num_artificials = b.iter().filter(|b_i| **b_i < 0.0).count();
+
+    // Each row holds the n original variables, m slack variables, the
+    // artificial variables and the rhs.
+    let width = n + m + num_artificials + 1;
+    let mut tableau = vec![vec![0.0; width]; m + 1];
+    let mut basis = vec![0; m];
+    let mut next_artificial = n + m;
+    for (i, a_i) in a.into_iter().enumerate() {
+        assert_eq!(a_i.len(), n);
+        tableau[i][..n].copy_from_slice(&a_i);
+        tableau[i][n + i] = 1.0;
+        tableau[i][width - 1] = b[i];
+        if b[i] < 0.0 {
+            for v in tableau[i].iter_mut() {
+                *v = -*v;
+            }
+            tableau[i][next_artificial] = 1.0;
+            basis[i] = next_artificial;
+            next_artificial += 1;
+        } else {
+            basis[i] = n + i;
+        }
+    }
+
+    if num_artificials > 0 {
+        // Phase 1: maximize the negated sum of the artificial variables.
+        for v in tableau[m][(n + m)..(width - 1)].iter_mut() {
+            *v = 1.0;
+        }
+        price_out(&mut tableau, &basis);
+        optimize(&mut tableau, &mut basis, width - 1)?;
+        if tableau[m][width - 1] < -EPS {
+            return None;
+        }
+        // Artificial variables left in the basis are zero; swap them for any
+        // other column of their row. A row without one is redundant.
+        for i in 0..m {
+            if basis[i] >= n + m {
+                if let Some(j) = (0..(n + m)).find(|j| tableau[i][*j].abs() > EPS) {
+                    pivot(&mut tableau, &mut basis, i, j);
+                }
+            }
+        }
+    }
+
+    // Phase 2: the artificial variables never enter the basis again.
+    tableau[m] = vec![0.0; width];
+    for j in 0..n {
+        tableau[m][j] = -c[j];
+    }
+    price_out(&mut tableau, &basis);
+    optimize(&mut tableau, &mut basis, n + m)?;
+
+    let mut x = vec![0.0; n];
+    for (i, j) in basis.iter().enumerate() {
+        if *j < n {
+            x[*j] = tableau[i][width - 1];
+        }
+    }
+    Some(x)
+}
+
+/// Eliminates the basic variables from the objective row.
+fn price_out(tableau: &mut [Vec<f64>], basis: &[usize]) {
+    let m = basis.len();
+    for (i, j) in basis.iter().enumerate() {
+        let factor = tableau[m][*j];
+        if factor != 0.0 {
+            let row = tableau[i].clone();
+            for (v, r) in tableau[m].iter_mut().zip(row.iter()) {
+                *v -= factor * r;
+            }
+        }
+    }
+}
+
+/// Pivots until no column below `num_columns` improves the objective.
+/// Returns `None` if the objective is unbounded.
+fn optimize(tableau: &mut [Vec<f64>], basis: &mut [usize], num_columns: usize) -> Option<()> {
+    let m = basis.len();
+    let rhs = tableau[m].len() - 1;
+
+    // Bland's rule: the entering and leaving variables have the smallest index.
+    while let Some(entering) = (0..num_columns).find(|j| tableau[m][*j] < -EPS) {
+        let mut leaving: Option<(usize, f64)> = None;
+        for i in 0..m {
+            if tableau[i][entering] > EPS {
+                let ratio = tableau[i][rhs] / tableau[i][entering];
+                match leaving {
+                    Some((l, r))
+                        if r < ratio - EPS || ((ratio - r).abs() < EPS && basis[l] < basis[i]) => {}
+                    _ => leaving = Some((i, ratio)),
+                }
+            }
+        }
+        let (pivot_row, _) = leaving?;
+        pivot(tableau, basis, pivot_row, entering);
+    }
+    Some(())
+}
+
+fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize], pivot_row: usize, entering: usize) {
+    let pivot = tableau[pivot_row][entering];
+    for v in tableau[pivot_row].iter_mut() {
+        *v /= pivot;
+    }
+    let pivot_values = tableau[pivot_row].clone();
+    for (i, row) in tableau.iter_mut().enumerate() {
+        if i == pivot_row {
+            continue;
+        }
+        let factor = row[entering];
+        if factor != 0.0 {
+            for (v, p) in row.iter_mut().zip(pivot_values.iter()) {
+                *v -= factor * p;
+            }
+        }
+    }
+    basis[pivot_row] = entering;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_simplex() {
+        // max 3x + 5y s.t. x <= 4, 2y <= 12, 3x + 2y <= 18
+        let a = vec![vec![1.0, 0.0], vec![0.0, 2.0], vec![3.0, 2.0]];
+        let b = vec![4.0, 12.0, 18.0];
+        let c = vec![3.0, 5.0];
+        let x = simplex(a, b, c).unwrap();
+        assert_approx_eq!(x[0], 2.0);
+        assert_approx_eq!(x[1], 6.0);
+    }
+
+    #[test]
+    fn test_simplex_unbounded() {
+        let a = vec![vec![1.0, -1.0]];
+        let b = vec![1.0];
+        let c = vec![1.0, 1.0];
+        assert!(simplex(a, b, c).is_none());
+    }
+
+    #[test]
+    fn test_simplex_negative_rhs() {
+        // max -x - y s.t. x + y >= 2, x <= 3
+        let a = vec![vec![-1.0, -1.0], vec![1.0, 0.0]];
+        let b = vec![-2.0, 3.0];
+        let c = vec![-1.0, -1.0];
+        let x = simplex(a, b, c).unwrap();
+        assert_approx_eq!(x[0] + x[1], 2.0);
+        assert!(x[0] <= 3.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_simplex_infeasible() {
+        // x <= 1 and x >= 2
+        let a = vec![vec![1.0], vec![-1.0]];
+        let b = vec![1.0, -2.0];
+        let c = vec![1.0];
+        assert!(simplex(a, b, c).is_none());
+    }
+}
@@ -1,2 +1,4 @@
+pub mod linear_programming;
 pub mod policy_evaluation;
+pub mod policy_iteration;
 pub mod value_iteration;
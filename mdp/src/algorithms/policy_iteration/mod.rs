@@ -0,0 +1,3 @@
+pub use self::modified_policy_iteration_ssp::modified_policy_iteration_ssp;
+
+mod modified_policy_iteration_ssp;
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::common::value_table::ValueTable;
+use crate::mdp_traits::*;
+use crate::value_estimator::CostEstimator;
+
+/// Modified policy iteration: every greedy improvement is followed by
+/// `num_evaluation_steps` sweeps of partial evaluation of the greedy policy.
+pub fn modified_policy_iteration_ssp<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    mdp: &M,
+    num_evaluation_steps: usize,
+) -> ValueTable<M::State> {
+    let err = 1e-9;
+    let mut value_table = ValueTable::<M::State>::new(1e+8);
+    let mut policy = HashMap::new();
+    loop {
+        let residual = improve(&mut value_table, &mut policy, mdp);
+        if residual < err {
+            break;
+        }
+        for _i in 0..num_evaluation_steps {
+            evaluate(&mut value_table, &policy, mdp);
+        }
+    }
+    value_table
+}
+
+fn improve<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    vt: &mut ValueTable<M::State>,
+    policy: &mut HashMap<M::State, M::Action>,
+    mdp: &M,
+) -> f32 {
+    let mut max_residual = 0.0;
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) {
+            let residual = vt.get_value(s).abs();
+            if residual > max_residual {
+                max_residual = residual;
+            }
+            vt.set_value(s, 0.0);
+            continue;
+        }
+
+        let mut best = None;
+        for a in mdp.enumerate_actions() {
+            if !mdp.action_available(s, a) {
+                continue;
+            }
+            let qsa = vt.get_qsa_ssp(s, a, mdp);
+            match best {
+                Some((_, best_qsa)) if best_qsa <= qsa => {}
+                _ => best = Some((*a, qsa)),
+            }
+        }
+
+        if let Some((a, qsa)) = best {
+            policy.insert(*s, a);
+            if vt.get_value(s) > qsa {
+                let residual = vt.get_value(s) - qsa;
+                if residual > max_residual {
+                    max_residual = residual;
+                }
+                vt.set_value(s, qsa);
+            }
+        }
+    }
+
+    max_residual
+}
+
+fn evaluate<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    vt: &mut ValueTable<M::State>,
+    policy: &HashMap<M::State, M::Action>,
+    mdp: &M,
+) {
+    for s in mdp.enumerate_states() {
+        if let Some(a) = policy.get(s) {
+            let qsa = vt.get_qsa_ssp(s, a, mdp);
+            if vt.get_value(s) > qsa {
+                vt.set_value(s, qsa);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baker_grid::{BakerGridMDP, BakerGridPartialMDP, BakerGridState};
+    use crate::grid_world::GridWorldMDP;
+    use crate::value_iteration::value_iteration_ssp;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_modified_policy_iteration_grid_world() {
+        let mdp = GridWorldMDP::default();
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = modified_policy_iteration_ssp(&mdp, 5);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_modified_policy_iteration_baker() {
+        let obstacles = vec![(5, 8), (6, 8), (7, 8), (8, 8)];
+        let partial_mdp = BakerGridPartialMDP::new(9, 17, obstacles);
+        let mdp: BakerGridMDP = partial_mdp.build_from(&BakerGridState::new(0, 16));
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = modified_policy_iteration_ssp(&mdp, 10);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+}
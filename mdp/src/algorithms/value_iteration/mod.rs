@@ -1,7 +1,13 @@
+pub use self::prioritized_sweeping_ssp::prioritized_sweeping_ssp;
+pub use self::ssp_solver::SSPSolver;
+pub use self::topological_value_iteration_ssp::topological_value_iteration_ssp;
 pub use self::value_iteration::{soft_value_iteration, value_iteration};
 pub use self::value_iteration_ssp::{soft_value_iteration_ssp, value_iteration_ssp};
 pub use crate::common::value_table::ValueTable;
 
+mod prioritized_sweeping_ssp;
+mod ssp_solver;
+mod topological_value_iteration_ssp;
 mod value_iteration;
 mod value_iteration_ssp;
 // mod value_iteration_ssp_n_step;
@@ -0,0 +1,113 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use ordered_float::NotNan;
+
+use crate::common::value_table::ValueTable;
+use crate::mdp_traits::*;
+use crate::value_estimator::CostEstimator;
+
+use super::value_iteration_ssp::set_min_qsa;
+
+pub fn prioritized_sweeping_ssp<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    mdp: &M,
+) -> ValueTable<M::State> {
+    let err = 1e-9;
+    let mut value_table = ValueTable::<M::State>::new(1e+8);
+
+    let states: Vec<M::State> = mdp.enumerate_states().cloned().collect();
+    let state_ids: HashMap<M::State, usize> =
+        states.iter().enumerate().map(|(id, s)| (*s, id)).collect();
+    let mut predecessors = vec![HashSet::new(); states.len()];
+    for (id, s) in states.iter().enumerate() {
+        if mdp.is_terminal(s) {
+            value_table.set_value(s, 0.0);
+            continue;
+        }
+        for a in mdp.enumerate_actions() {
+            if !mdp.action_available(s, a) {
+                continue;
+            }
+            for (ss, p) in mdp.p_mass(s, a) {
+                if p > 0.0 {
+                    if let Some(ss_id) = state_ids.get(&ss) {
+                        predecessors[*ss_id].insert(id);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut priorities = vec![0.0; states.len()];
+    let mut queue = BinaryHeap::new();
+    for (id, s) in states.iter().enumerate() {
+        if mdp.is_terminal(s) {
+            continue;
+        }
+        let bellman_error = bellman_error(s, &value_table, mdp);
+        if bellman_error > err {
+            priorities[id] = bellman_error;
+            queue.push((NotNan::new(bellman_error).unwrap(), id));
+        }
+    }
+
+    while let Some((priority, id)) = queue.pop() {
+        // skip entries superseded by a later push
+        if priority.into_inner() != priorities[id] {
+            continue;
+        }
+        priorities[id] = 0.0;
+        set_min_qsa(&states[id], &mut value_table, mdp);
+
+        for pred_id in predecessors[id].iter() {
+            let pred = &states[*pred_id];
+            let bellman_error = bellman_error(pred, &value_table, mdp);
+            if bellman_error > err && bellman_error > priorities[*pred_id] {
+                priorities[*pred_id] = bellman_error;
+                queue.push((NotNan::new(bellman_error).unwrap(), *pred_id));
+            }
+        }
+    }
+
+    value_table
+}
+
+fn bellman_error<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost>(
+    s: &M::State,
+    vt: &ValueTable<M::State>,
+    mdp: &M,
+) -> f32 {
+    vt.get_value(s) - vt.get_value_ssp(s, mdp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baker_grid::{BakerGridMDP, BakerGridPartialMDP, BakerGridState};
+    use crate::grid_world::GridWorldMDP;
+    use crate::value_iteration::value_iteration_ssp;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_prioritized_sweeping_grid_world() {
+        let mdp = GridWorldMDP::default();
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = prioritized_sweeping_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_prioritized_sweeping_baker() {
+        let obstacles = vec![(5, 8), (6, 8), (7, 8), (8, 8)];
+        let partial_mdp = BakerGridPartialMDP::new(9, 17, obstacles);
+        let mdp: BakerGridMDP = partial_mdp.build_from(&BakerGridState::new(0, 16));
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = prioritized_sweeping_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+}
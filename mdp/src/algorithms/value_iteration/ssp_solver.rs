@@ -0,0 +1,32 @@
+use crate::common::value_table::ValueTable;
+use crate::linear_programming::linear_programming_ssp;
+use crate::mdp_traits::*;
+use crate::policy_iteration::modified_policy_iteration_ssp;
+
+use super::{prioritized_sweeping_ssp, topological_value_iteration_ssp, value_iteration_ssp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SSPSolver {
+    ValueIteration,
+    PrioritizedSweeping,
+    TopologicalValueIteration,
+    ModifiedPolicyIteration(usize),
+    LinearProgramming,
+}
+
+impl SSPSolver {
+    pub fn solve<
+        M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+    >(
+        &self,
+        mdp: &M,
+    ) -> ValueTable<M::State> {
+        match self {
+            SSPSolver::ValueIteration => value_iteration_ssp(mdp),
+            SSPSolver::PrioritizedSweeping => prioritized_sweeping_ssp(mdp),
+            SSPSolver::TopologicalValueIteration => topological_value_iteration_ssp(mdp),
+            SSPSolver::ModifiedPolicyIteration(m) => modified_policy_iteration_ssp(mdp, *m),
+            SSPSolver::LinearProgramming => linear_programming_ssp(mdp),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::DiGraph;
+
+use crate::common::value_table::ValueTable;
+use crate::mdp_traits::*;
+
+use super::value_iteration_ssp::set_min_qsa;
+
+pub fn topological_value_iteration_ssp<
+    M: ActionAvailability + ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerminal,
+>(
+    mdp: &M,
+) -> ValueTable<M::State> {
+    let err = 1e-9;
+    let mut value_table = ValueTable::<M::State>::new(1e+8);
+
+    let mut graph = DiGraph::<M::State, ()>::new();
+    let mut node_ids = HashMap::new();
+    for s in mdp.enumerate_states() {
+        node_ids.insert(*s, graph.add_node(*s));
+    }
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) {
+            continue;
+        }
+        for a in mdp.enumerate_actions() {
+            if !mdp.action_available(s, a) {
+                continue;
+            }
+            for (ss, p) in mdp.p_mass(s, a) {
+                if p > 0.0 {
+                    if let Some(ss_id) = node_ids.get(&ss) {
+                        graph.update_edge(node_ids[s], *ss_id, ());
+                    }
+                }
+            }
+        }
+    }
+
+    // tarjan_scc returns the components in reverse topological order,
+    // so every component is solved after all of its successors.
+    for scc in tarjan_scc(&graph) {
+        let states: Vec<M::State> = scc.iter().map(|id| graph[*id]).collect();
+        loop {
+            let residual = update_component(&mut value_table, mdp, &states);
+            if residual < err {
+                break;
+            }
+        }
+    }
+
+    value_table
+}
+
+fn update_component<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost + IsTerminal>(
+    vt: &mut ValueTable<M::State>,
+    mdp: &M,
+    states: &[M::State],
+) -> f32 {
+    let mut max_residual = 0.0;
+    for s in states {
+        let residual = if mdp.is_terminal(s) {
+            let residual = vt.get_value(s).abs();
+            vt.set_value(s, 0.0);
+            residual
+        } else {
+            set_min_qsa(s, vt, mdp)
+        };
+
+        if residual > max_residual {
+            max_residual = residual;
+        }
+    }
+
+    max_residual
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baker_grid::{BakerGridMDP, BakerGridPartialMDP, BakerGridState};
+    use crate::grid_world::GridWorldMDP;
+    use crate::value_iteration::value_iteration_ssp;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_topological_value_iteration_grid_world() {
+        let mdp = GridWorldMDP::default();
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = topological_value_iteration_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_topological_value_iteration_baker() {
+        let obstacles = vec![(5, 8), (6, 8), (7, 8), (8, 8)];
+        let partial_mdp = BakerGridPartialMDP::new(9, 17, obstacles);
+        let mdp: BakerGridMDP = partial_mdp.build_from(&BakerGridState::new(0, 16));
+        let expected = value_iteration_ssp(&mdp);
+        let value_table = topological_value_iteration_ssp(&mdp);
+        for s in mdp.enumerate_states() {
+            assert_approx_eq!(value_table.get_value(s), expected.get_value(s), 1e-3);
+        }
+    }
+}
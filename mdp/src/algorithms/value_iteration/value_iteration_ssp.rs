@@ -61,7 +61,7 @@ fn update_ssp<M: ActionEnumerable + StateEnumerable + PMass<f32> + Cost + IsTerm
     max_residual
 }
 
-fn set_min_qsa<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost>(
+pub(super) fn set_min_qsa<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost>(
     s: &M::State,
     vt: &mut ValueTable<M::State>,
     mdp: &M,
@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Grid2D {
     pub height: usize,
@@ -31,4 +34,196 @@ impl Grid2D {
             true
         }
     }
+
+    pub fn obstacles(&self) -> Vec<(usize, usize)> {
+        (0..self.height)
+            .flat_map(|i| (0..self.width).map(move |j| (i, j)))
+            .filter(|(i, j)| self.is_obstacled[*i][*j])
+            .collect()
+    }
+}
+
+/// Optional YAML header of a grid map. Cells are `(i, j)` = (row, column)
+/// and are added to the ones marked in the ASCII art.
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GridMapHeader {
+    pub start: Option<(usize, usize)>,
+    pub goals: Vec<(usize, usize)>,
+    pub dangerous: Vec<(usize, usize)>,
+    pub water: Vec<(usize, usize)>,
+    pub fire: Vec<(usize, usize)>,
+}
+
+/// A grid map shared by the grid domains.
+///
+/// The file is an optional YAML header followed by a `---` line and the ASCII
+/// art of the grid, one row per line:
+///
+/// ```text
+/// goals: [[0, 4]]
+/// ---
+/// ..G.G
+/// .#~~.
+/// S.^^.
+/// ```
+///
+/// `#`/`X`/`x` obstacle, `.`/` ` free, `S` start, `G` goal, `D` dangerous,
+/// `~` water and `^` fire. Goals in the ASCII art are ordered row by row and
+/// come before the ones listed in the header.
+///
+/// Baker, salome and grid-world grids are built from it. Race tracks keep
+/// their `.track` format since error and unsafe cells and the start line have
+/// no counterpart here.
+#[derive(PartialEq, Debug, Clone)]
+pub struct GridMap {
+    pub grid2d: Grid2D,
+    pub start: Option<(usize, usize)>,
+    pub goals: Vec<(usize, usize)>,
+    pub dangerous: Vec<(usize, usize)>,
+    pub water: Vec<(usize, usize)>,
+    pub fire: Vec<(usize, usize)>,
+}
+
+impl GridMap {
+    pub fn from_file(filename: &str) -> GridMap {
+        let data = fs::read_to_string(filename).expect("Unable to read file");
+        GridMap::parse(&data)
+    }
+
+    /// Loads `name` from the `data/maps` directory of this crate.
+    pub fn from_data(name: &str) -> GridMap {
+        GridMap::from_file(&format!(
+            "{}/data/maps/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+    }
+
+    pub fn parse(data: &str) -> GridMap {
+        let lines = data.lines().collect::<Vec<_>>();
+        let (header, rows) = match lines.iter().position(|l| l.trim_end() == "---") {
+            Some(k) => {
+                let header = lines[..k].join("\n");
+                let header = if header.trim().is_empty() {
+                    GridMapHeader::default()
+                } else {
+                    serde_yaml::from_str(&header).expect("Invalid yaml")
+                };
+                (header, &lines[(k + 1)..])
+            }
+            None => (GridMapHeader::default(), &lines[..]),
+        };
+        let rows = rows
+            .iter()
+            .filter(|l| !l.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        let height = rows.len();
+        let width = rows.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut is_obstacled = vec![vec![false; width]; height];
+        let mut map = GridMap {
+            grid2d: Grid2D::new(height, width, vec![]),
+            start: None,
+            goals: vec![],
+            dangerous: vec![],
+            water: vec![],
+            fire: vec![],
+        };
+        for (i, row) in rows.iter().enumerate() {
+            for (j, c) in row.chars().enumerate() {
+                match c {
+                    '#' | 'X' | 'x' => is_obstacled[i][j] = true,
+                    '.' | ' ' => (),
+                    'S' => map.start = Some((i, j)),
+                    'G' => map.goals.push((i, j)),
+                    'D' => map.dangerous.push((i, j)),
+                    '~' => map.water.push((i, j)),
+                    '^' => map.fire.push((i, j)),
+                    _ => panic!("unexpected character {}", c),
+                }
+            }
+        }
+        map.grid2d.is_obstacled = is_obstacled;
+
+        if header.start.is_some() {
+            map.start = header.start;
+        }
+        map.goals.extend(header.goals);
+        map.dangerous.extend(header.dangerous);
+        map.water.extend(header.water);
+        map.fire.extend(header.fire);
+
+        for &(i, j) in map
+            .start
+            .iter()
+            .chain(map.goals.iter())
+            .chain(map.dangerous.iter())
+            .chain(map.water.iter())
+            .chain(map.fire.iter())
+        {
+            assert!(
+                map.grid2d.is_valid_cordinate(i as i32, j as i32),
+                "({}, {}) is not a free cell",
+                i,
+                j
+            );
+        }
+
+        map
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid2d.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid2d.width
+    }
+
+    pub fn obstacles(&self) -> Vec<(usize, usize)> {
+        self.grid2d.obstacles()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_map_parse() {
+        let map = GridMap::parse(
+            "start: [2, 1]\n\
+             goals: [[2, 4]]\n\
+             ---\n\
+             ..G.G\n\
+             .#~~.\n\
+             S.^^.\n",
+        );
+        assert_eq!(map.height(), 3);
+        assert_eq!(map.width(), 5);
+        assert_eq!(map.obstacles(), vec![(1, 1)]);
+        assert_eq!(map.start, Some((2, 1)));
+        assert_eq!(map.goals, vec![(0, 2), (0, 4), (2, 4)]);
+        assert_eq!(map.water, vec![(1, 2), (1, 3)]);
+        assert_eq!(map.fire, vec![(2, 2), (2, 3)]);
+        assert!(!map.grid2d.is_valid_cordinate(1, 1));
+    }
+
+    #[test]
+    fn test_grid_map_without_header() {
+        let map = GridMap::parse("S.#\n..G\n");
+        assert_eq!(map.start, Some((0, 0)));
+        assert_eq!(map.goals, vec![(1, 2)]);
+        assert!(map.dangerous.is_empty());
+    }
+
+    #[test]
+    fn test_grid_map_from_data() {
+        let map = GridMap::from_data("water_fire.map");
+        assert_eq!(map.height(), 7);
+        assert_eq!(map.width(), 7);
+        assert_eq!(map.goals, vec![(0, 3)]);
+        assert_eq!(map.start, Some((6, 3)));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::heuristic::HeuristicWithMDP;
 use crate::mdp_traits::*;
-use crate::value_estimator::{CostEstimator, ValueEstimator};
+use crate::value_estimator::{CostEstimator, CostEstimatorMut, ValueEstimator};
 use core::fmt::Debug;
 use core::hash::Hash;
 use std::collections::HashMap;
@@ -67,6 +67,17 @@ impl<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost> CostEstimator
     }
 }
 
+impl<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost> CostEstimatorMut<M>
+    for ValueTable<M::State>
+{
+    fn get_value_ssp_mut(&mut self, s: &M::State, mdp: &mut M) -> f32 {
+        self.get_value_ssp(s, mdp)
+    }
+    fn get_qsa_ssp_mut(&mut self, s: &M::State, a: &M::Action, mdp: &mut M) -> f32 {
+        self.get_qsa_ssp(s, a, mdp)
+    }
+}
+
 impl<S: Eq + PartialEq + Debug + Clone + Hash> ValueTable<S> {
     pub fn get_greedy_action_ssp<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost>(
         &self,
@@ -0,0 +1,24 @@
+use crate::baker_grid::BakerGridState;
+use crate::mdp_traits::DisplayState;
+
+use super::BakerMultiGridMDP;
+
+impl<const N: usize> DisplayState<[BakerGridState; N]> for BakerMultiGridMDP<N> {
+    fn display(&self, s: &[BakerGridState; N]) {
+        for i in 0..self.grid2d.height {
+            for j in 0..self.grid2d.width {
+                let here = BakerGridState::new(i as i32, j as i32);
+                if self.grid2d.is_obstacled[i][j] {
+                    print!("X");
+                } else if let Some(k) = s.iter().position(|s_k| *s_k == here) {
+                    print!("{}", k);
+                } else if self.goal.contains(&here) {
+                    print!("G");
+                } else {
+                    print!(".");
+                }
+            }
+            println!();
+        }
+    }
+}
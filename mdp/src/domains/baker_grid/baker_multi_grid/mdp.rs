@@ -1,6 +1,7 @@
+use std::convert::TryInto;
 use std::slice::Iter;
 
-use itertools::iproduct;
+use itertools::{iproduct, Itertools};
 use strum::IntoEnumIterator;
 
 use crate::{
@@ -8,7 +9,8 @@ use crate::{
     common::grid2d::Grid2D,
     mdp_traits::{
         ActionAvailability, ActionEnumerable, CostFromDCost, DCost, ExplicitTransition,
-        GetNextState, InitialState, IsTerminal, PMass, StateEnumerable, StatesActions,
+        GetNextState, GetNextStateMutFromImmut, InitialState, IsTerminal, PMass, StateEnumerable,
+        StatesActions,
     },
 };
 
@@ -23,56 +25,35 @@ pub struct BakerMultiGridMDP<const N: usize> {
     pub cost_upon_dangerous: f32,
 }
 
-impl BakerMultiGridMDP<3> {
+impl<const N: usize> BakerMultiGridMDP<N> {
     //     #[new]
     pub fn new(
         height: usize,
         width: usize,
         obstacles: Vec<BakerGridState>,
-        initial_state: [BakerGridState; 3],
-        goal: [BakerGridState; 3],
-    ) -> BakerMultiGridMDP<3> {
+        initial_state: [BakerGridState; N],
+        goal: [BakerGridState; N],
+    ) -> BakerMultiGridMDP<N> {
         let mut is_obstacled = vec![vec![false; width]; height];
         let is_dangerous = vec![vec![false; width]; height];
         for s in obstacles.into_iter() {
             is_obstacled[s.i as usize][s.j as usize] = true;
         }
-        let all_states = iproduct!(
-            (0..height),
-            (0..width),
-            (0..height),
-            (0..width),
-            (0..height),
-            (0..width)
-        )
-        .filter(|(i0, j0, i1, j1, i2, j2)| {
-            !is_obstacled[*i0][*j0] || !is_obstacled[*i1][*j1] || !is_obstacled[*i2][*j2]
-        })
-        .map(|(i0, j0, i1, j1, i2, j2)| {
-            [
-                BakerGridState {
-                    i: i0 as i32,
-                    j: j0 as i32,
-                },
-                BakerGridState {
-                    i: i1 as i32,
-                    j: j1 as i32,
-                },
-                BakerGridState {
-                    i: i2 as i32,
-                    j: j2 as i32,
-                },
-            ]
-        })
-        .collect::<Vec<_>>();
-
-        let all_actions = iproduct!(
-            BakerGridAction::iter(),
-            BakerGridAction::iter(),
-            BakerGridAction::iter()
-        )
-        .map(|(a0, a1, a2)| [a0, a1, a2])
-        .collect::<Vec<_>>();
+        let free_cells = iproduct!((0..height), (0..width))
+            .filter(|(i, j)| !is_obstacled[*i][*j])
+            .map(|(i, j)| BakerGridState::new(i as i32, j as i32))
+            .collect::<Vec<_>>();
+        let all_states = (0..N)
+            .map(|_| free_cells.iter().cloned())
+            .multi_cartesian_product()
+            .map(to_array)
+            .collect::<Vec<_>>();
+
+        let all_actions = (0..N)
+            .map(|_| BakerGridAction::iter())
+            .multi_cartesian_product()
+            .map(to_array)
+            .collect::<Vec<_>>();
 
         BakerMultiGridMDP {
             grid2d: Grid2D::new(height, width, is_obstacled),
@@ -86,6 +67,10 @@ impl BakerMultiGridMDP<3> {
     }
 }
 
+fn to_array<T: std::fmt::Debug, const N: usize>(v: Vec<T>) -> [T; N] {
+    v.try_into().unwrap()
+}
+
 impl<const N: usize> StatesActions for BakerMultiGridMDP<N> {
     type State = [BakerGridState; N];
     type Action = [BakerGridAction; N];
@@ -206,6 +191,8 @@ impl<const N: usize> GetNextState for BakerMultiGridMDP<N> {
     }
 }
 
+impl<const N: usize> GetNextStateMutFromImmut for BakerMultiGridMDP<N> {}
+
 #[cfg(test)]
 mod tests {
     use rand::thread_rng;
@@ -216,6 +203,8 @@ mod tests {
     };
 
     use super::*;
+    use crate::baker_grid::BakerMultiGridPartialMDP;
+    use crate::mdp_traits::BuildFrom;
 
     #[test]
     fn test_baker_multi_grid_p_mass() {
@@ -272,4 +261,25 @@ mod tests {
             println!("{:?}", s);
         }
     }
+
+    #[test]
+    fn test_baker_multi_grid_two_agents() {
+        let partial_mdp = BakerMultiGridPartialMDP::new(
+            3,
+            4,
+            vec![(1, 1)],
+            [BakerGridState::new(0, 0), BakerGridState::new(2, 0)],
+        );
+        let mdp: BakerMultiGridMDP<2> =
+            partial_mdp.build_from([BakerGridState::new(2, 3), BakerGridState::new(0, 3)]);
+        assert_eq!(mdp.num_states(), 11 * 11);
+        assert_eq!(mdp.num_actions(), 9 * 9);
+
+        let vt = value_iteration_ssp(&mdp);
+        let mut rng = thread_rng();
+        let policy = TabularPolicy::from_value_table_ssp(&mdp, &vt);
+        let mut runner = EpisodeRunner::new(&mdp, &policy, mdp.initial_state());
+        let last = runner.into_iter_with(&mut rng).last().unwrap();
+        assert_eq!(last.2, mdp.goal);
+    }
 }
@@ -1,3 +1,4 @@
+mod display;
 mod mdp;
 mod partial_mdp;
 
@@ -1,13 +1,17 @@
 use crate::{baker_grid::BakerGridState, mdp_traits::BuildFrom};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
 use super::mdp::BakerMultiGridMDP;
 
-#[derive(PartialEq, Debug, Clone)]
+#[serde_as]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct BakerMultiGridPartialMDP<const N: usize> {
     pub(in crate::baker_grid) height: usize,
     pub(in crate::baker_grid) width: usize,
     pub(in crate::baker_grid) obstacles: Vec<BakerGridState>,
     pub(in crate::baker_grid) dangerous_coordinates: Vec<(usize, usize)>,
+    #[serde_as(as = "[_; N]")]
     pub(in crate::baker_grid) initial_state: [BakerGridState; N],
 }
 
@@ -32,8 +36,10 @@ impl<const N: usize> BakerMultiGridPartialMDP<N> {
     }
 }
 
-impl BuildFrom<[BakerGridState; 3], BakerMultiGridMDP<3>> for BakerMultiGridPartialMDP<3> {
-    fn build_from(&self, goal: [BakerGridState; 3]) -> BakerMultiGridMDP<3> {
+impl<const N: usize> BuildFrom<[BakerGridState; N], BakerMultiGridMDP<N>>
+    for BakerMultiGridPartialMDP<N>
+{
+    fn build_from(&self, goal: [BakerGridState; N]) -> BakerMultiGridMDP<N> {
         let mut baker_grid = BakerMultiGridMDP::new(
             self.height,
             self.width,
@@ -49,3 +55,11 @@ impl BuildFrom<[BakerGridState; 3], BakerMultiGridMDP<3>> for BakerMultiGridPart
         baker_grid
     }
 }
+
+impl<'a, const N: usize> BuildFrom<&'a [BakerGridState; N], BakerMultiGridMDP<N>>
+    for BakerMultiGridPartialMDP<N>
+{
+    fn build_from(&self, goal: &'a [BakerGridState; N]) -> BakerMultiGridMDP<N> {
+        self.build_from(*goal)
+    }
+}
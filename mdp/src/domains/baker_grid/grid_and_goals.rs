@@ -19,30 +19,40 @@ impl GridAndGoals {
     }
 
     fn print_if_goal(&self, i: usize, j: usize) {
+        print!("{}", self.goal_label(i, j));
+    }
+
+    fn goal_label(&self, i: usize, j: usize) -> &str {
         for (k, (gi, gj)) in self.goals.iter().enumerate() {
             if i == *gi && j == *gj {
-                print!("{}", self.labels[k]);
-                return;
+                return &self.labels[k];
             }
         }
-        print!(".");
+        "."
     }
-}
 
-impl DisplayState<BakerGridState> for GridAndGoals {
-    fn display(&self, s: &BakerGridState) {
+    /// The same picture as `display`, as a string.
+    pub fn format(&self, s: &BakerGridState) -> String {
+        let mut text = String::new();
         for i in 0..self.grid.height {
             for j in 0..self.grid.width {
                 if self.grid.is_obstacled[i][j] {
-                    print!("X");
+                    text.push('X');
                 } else if s.i == i as i32 && s.j == j as i32 {
-                    print!("O");
+                    text.push('O');
                 } else {
-                    self.print_if_goal(i, j);
+                    text.push_str(self.goal_label(i, j));
                 }
             }
-            println!();
+            text.push('\n');
         }
+        text
+    }
+}
+
+impl DisplayState<BakerGridState> for GridAndGoals {
+    fn display(&self, s: &BakerGridState) {
+        print!("{}", self.format(s));
     }
 }
 
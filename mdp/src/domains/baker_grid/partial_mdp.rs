@@ -1,4 +1,5 @@
 use crate::baker_grid::BakerGridState;
+use crate::common::grid2d::GridMap;
 use crate::mdp_traits::BuildFrom;
 
 use serde::Deserialize;
@@ -32,6 +33,21 @@ impl BakerGridPartialMDP {
         }
     }
 
+    pub fn from_grid_map(map: &GridMap) -> BakerGridPartialMDP {
+        let obstacles = map
+            .obstacles()
+            .into_iter()
+            .map(|(i, j)| (i as i32, j as i32))
+            .collect::<Vec<_>>();
+        let mut partial_mdp = BakerGridPartialMDP::new(map.height(), map.width(), obstacles);
+        if let Some((i, j)) = map.start {
+            partial_mdp.initial_state = BakerGridState::new(i as i32, j as i32);
+        }
+        partial_mdp.dangerous_coordinates = map.dangerous.clone();
+
+        partial_mdp
+    }
+
     pub fn set_prob_veering(mut self, prob_veering: f32) -> BakerGridPartialMDP {
         self.prob_veering = prob_veering;
 
@@ -64,3 +80,20 @@ impl<'a> BuildFrom<&'a BakerGridState, BakerGridMDP> for BakerGridPartialMDP {
         baker_grid
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdp_traits::InitialState;
+
+    #[test]
+    fn test_baker_partial_mdp_from_grid_map() {
+        let map = GridMap::from_data("baker_wall.map");
+        let partial_mdp = BakerGridPartialMDP::from_grid_map(&map);
+        assert_eq!(partial_mdp.obstacles.len(), 4);
+        assert_eq!(map.goals, vec![(0, 16), (8, 16), (0, 4)]);
+
+        let mdp = partial_mdp.build_from(&BakerGridState::new(0, 16));
+        assert_eq!(mdp.initial_state(), BakerGridState::new(8, 0));
+    }
+}
@@ -0,0 +1,5 @@
+use serde::{Deserialize, Serialize};
+
+/// Traverse the edge with the given index in `CanadianTravelerGraph::edges`.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
+pub struct CanadianTravelerAction(pub usize);
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Edge {
+    pub u: usize,
+    pub v: usize,
+    pub cost: f32,
+    #[serde(default)]
+    pub p_blocked: f32,
+}
+
+impl Edge {
+    pub fn new(u: usize, v: usize, cost: f32, p_blocked: f32) -> Edge {
+        Edge {
+            u,
+            v,
+            cost,
+            p_blocked,
+        }
+    }
+
+    pub fn is_incident(&self, n: usize) -> bool {
+        self.u == n || self.v == n
+    }
+
+    pub fn other_end(&self, n: usize) -> usize {
+        if self.u == n {
+            self.v
+        } else {
+            self.u
+        }
+    }
+}
+
+/// Undirected graph of a Canadian traveler instance, stored as YAML:
+///
+/// ```text
+/// num_nodes: 3
+/// start: 0
+/// goal: 2
+/// edges:
+///   - {u: 0, v: 1, cost: 1.0}
+///   - {u: 1, v: 2, cost: 1.0, p_blocked: 0.5}
+///   - {u: 0, v: 2, cost: 5.0}
+/// ```
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct CanadianTravelerGraph {
+    pub num_nodes: usize,
+    pub start: usize,
+    pub goal: usize,
+    pub edges: Vec<Edge>,
+}
+
+impl CanadianTravelerGraph {
+    pub fn from_file(filename: &str) -> CanadianTravelerGraph {
+        let data = fs::read_to_string(filename).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    /// Loads `name` from the `data/graphs` directory of this crate.
+    pub fn from_data(name: &str) -> CanadianTravelerGraph {
+        CanadianTravelerGraph::from_file(&format!(
+            "{}/data/graphs/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        ))
+    }
+}
@@ -0,0 +1,207 @@
+use core::slice::Iter;
+
+use crate::canadian_traveler::*;
+use crate::mdp_traits::*;
+
+/// Canadian traveler problem: the traveler has to reach the goal on a graph
+/// whose edges may be blocked, and learns the status of the edges incident to
+/// a node only when it arrives there. Edges incident to the start are
+/// revealed by trying them, which costs nothing when the edge is blocked.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CanadianTravelerMDP {
+    pub graph: CanadianTravelerGraph,
+    all_actions: Vec<CanadianTravelerAction>,
+}
+
+impl CanadianTravelerMDP {
+    pub fn new(graph: CanadianTravelerGraph) -> CanadianTravelerMDP {
+        assert!(graph.edges.len() <= 64, "at most 64 edges are supported");
+        let all_actions = (0..graph.edges.len())
+            .map(CanadianTravelerAction)
+            .collect::<Vec<_>>();
+
+        CanadianTravelerMDP { graph, all_actions }
+    }
+
+    pub fn from_file(filename: &str) -> CanadianTravelerMDP {
+        CanadianTravelerMDP::new(CanadianTravelerGraph::from_file(filename))
+    }
+
+    pub fn from_data(name: &str) -> CanadianTravelerMDP {
+        CanadianTravelerMDP::new(CanadianTravelerGraph::from_data(name))
+    }
+
+    fn arrive(&self, s: &CanadianTravelerState, n: usize) -> Vec<(CanadianTravelerState, f32)> {
+        let mut outcomes = vec![(
+            CanadianTravelerState {
+                location: n,
+                known: s.known,
+                blocked: s.blocked,
+            },
+            1.0,
+        )];
+        for (e, edge) in self.graph.edges.iter().enumerate() {
+            if !edge.is_incident(n) || s.edge_status(e) != EdgeStatus::Unknown {
+                continue;
+            }
+            outcomes = if edge.p_blocked <= 0.0 || edge.p_blocked >= 1.0 {
+                outcomes
+                    .into_iter()
+                    .map(|(ss, p)| (ss.reveal(e, edge.p_blocked >= 1.0), p))
+                    .collect()
+            } else {
+                outcomes
+                    .into_iter()
+                    .flat_map(|(ss, p)| {
+                        vec![
+                            (ss.reveal(e, true), p * edge.p_blocked),
+                            (ss.reveal(e, false), p * (1.0 - edge.p_blocked)),
+                        ]
+                    })
+                    .collect()
+            };
+        }
+
+        outcomes
+    }
+}
+
+impl Default for CanadianTravelerMDP {
+    fn default() -> Self {
+        CanadianTravelerMDP::new(CanadianTravelerGraph {
+            num_nodes: 4,
+            start: 0,
+            goal: 3,
+            edges: vec![
+                Edge::new(0, 1, 1.0, 0.0),
+                Edge::new(1, 3, 2.0, 0.5),
+                Edge::new(0, 2, 2.0, 0.0),
+                Edge::new(2, 3, 2.0, 0.5),
+                Edge::new(0, 3, 10.0, 0.0),
+            ],
+        })
+    }
+}
+
+impl StatesActions for CanadianTravelerMDP {
+    type State = CanadianTravelerState;
+    type Action = CanadianTravelerAction;
+}
+
+impl IsTerminal for CanadianTravelerMDP {
+    fn is_terminal(&self, s: &Self::State) -> bool {
+        s.location == self.graph.goal
+    }
+}
+
+impl ActionEnumerable for CanadianTravelerMDP {
+    fn enumerate_actions(&self) -> Iter<Self::Action> {
+        self.all_actions.iter()
+    }
+    fn num_actions(&self) -> usize {
+        self.all_actions.len()
+    }
+    fn id_to_action(&self, id: usize) -> &Self::Action {
+        &self.all_actions[id]
+    }
+}
+
+impl ActionAvailability for CanadianTravelerMDP {
+    fn action_available(&self, s: &Self::State, a: &Self::Action) -> bool {
+        self.graph.edges[a.0].is_incident(s.location) && s.edge_status(a.0) != EdgeStatus::Blocked
+    }
+}
+
+impl InitialState for CanadianTravelerMDP {
+    fn initial_state(&self) -> Self::State {
+        CanadianTravelerState::new(self.graph.start)
+    }
+}
+
+impl PMass<f32> for CanadianTravelerMDP {
+    type Distribution = Vec<(Self::State, f32)>;
+    fn p_mass(&self, s: &Self::State, a: &Self::Action) -> Vec<(Self::State, f32)> {
+        if self.is_terminal(s) || !self.action_available(s, a) {
+            return vec![(*s, 1.0)];
+        }
+        let edge = &self.graph.edges[a.0];
+        let next = edge.other_end(s.location);
+        match s.edge_status(a.0) {
+            EdgeStatus::Open => self.arrive(s, next),
+            EdgeStatus::Unknown if edge.p_blocked >= 1.0 => vec![(s.reveal(a.0, true), 1.0)],
+            EdgeStatus::Unknown => {
+                let mut outcomes = self
+                    .arrive(&s.reveal(a.0, false), next)
+                    .into_iter()
+                    .map(|(ss, p)| (ss, p * (1.0 - edge.p_blocked)))
+                    .collect::<Vec<_>>();
+                if edge.p_blocked > 0.0 {
+                    outcomes.push((s.reveal(a.0, true), edge.p_blocked));
+                }
+                outcomes
+            }
+            EdgeStatus::Blocked => vec![(*s, 1.0)],
+        }
+    }
+}
+
+impl PMassMutFrom<f32> for CanadianTravelerMDP {}
+
+impl ExplicitTransition for CanadianTravelerMDP {}
+
+impl DCost for CanadianTravelerMDP {
+    fn d_cost(&self, st: &Self::State, a: &Self::Action, stt: &Self::State) -> f32 {
+        if self.is_terminal(st) || st.location == stt.location {
+            0.0
+        } else {
+            self.graph.edges[a.0].cost
+        }
+    }
+}
+
+impl CostFromDCost for CanadianTravelerMDP {}
+
+impl GetNextStateFromPMass for CanadianTravelerMDP {}
+
+impl GetNextStateMutFromImmut for CanadianTravelerMDP {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_canadian_traveler_p_mass() {
+        let mdp = CanadianTravelerMDP::default();
+
+        // Arriving at node 1 reveals the edge between node 1 and the goal.
+        let outcomes = mdp.p_mass(&mdp.initial_state(), &CanadianTravelerAction(0));
+        assert_eq!(outcomes.len(), 2);
+        for (s, p) in outcomes.iter() {
+            assert_eq!(s.location, 1);
+            assert_eq!(s.edge_status(0), EdgeStatus::Open);
+            assert_ne!(s.edge_status(1), EdgeStatus::Unknown);
+            assert_approx_eq!(*p, 0.5);
+        }
+
+        let s = outcomes
+            .into_iter()
+            .find(|(s, _)| s.edge_status(1) == EdgeStatus::Blocked)
+            .unwrap()
+            .0;
+        assert!(!mdp.action_available(&s, &CanadianTravelerAction(1)));
+        assert_approx_eq!(mdp.cost(&s, &CanadianTravelerAction(0)), 1.0);
+
+        // Edges that are never blocked are revealed as open on arrival.
+        let outcomes = mdp.p_mass(&s, &CanadianTravelerAction(0));
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].0.location, 0);
+        assert_eq!(outcomes[0].0.edge_status(4), EdgeStatus::Open);
+    }
+
+    #[test]
+    fn test_canadian_traveler_from_data() {
+        let mdp = CanadianTravelerMDP::from_data("small.yaml");
+        assert_eq!(mdp, CanadianTravelerMDP::default());
+    }
+}
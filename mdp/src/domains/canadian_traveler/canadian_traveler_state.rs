@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
+pub enum EdgeStatus {
+    Unknown,
+    Open,
+    Blocked,
+}
+
+/// The location of the traveler and what it knows about the edges. Edge `e`
+/// is known when bit `e` of `known` is set, and blocked when bit `e` of
+/// `blocked` is also set.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
+pub struct CanadianTravelerState {
+    pub location: usize,
+    pub(super) known: u64,
+    pub(super) blocked: u64,
+}
+
+impl CanadianTravelerState {
+    pub fn new(location: usize) -> CanadianTravelerState {
+        CanadianTravelerState {
+            location,
+            known: 0,
+            blocked: 0,
+        }
+    }
+
+    pub fn edge_status(&self, e: usize) -> EdgeStatus {
+        if self.known & (1 << e) == 0 {
+            EdgeStatus::Unknown
+        } else if self.blocked & (1 << e) == 0 {
+            EdgeStatus::Open
+        } else {
+            EdgeStatus::Blocked
+        }
+    }
+
+    pub(super) fn reveal(&self, e: usize, is_blocked: bool) -> Self {
+        CanadianTravelerState {
+            location: self.location,
+            known: self.known | (1 << e),
+            blocked: if is_blocked {
+                self.blocked | (1 << e)
+            } else {
+                self.blocked
+            },
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod canadian_traveler_action;
+mod canadian_traveler_graph;
+mod canadian_traveler_mdp;
+mod canadian_traveler_state;
+
+pub use self::canadian_traveler_action::CanadianTravelerAction;
+pub use self::canadian_traveler_graph::{CanadianTravelerGraph, Edge};
+pub use self::canadian_traveler_mdp::CanadianTravelerMDP;
+pub use self::canadian_traveler_state::{CanadianTravelerState, EdgeStatus};
@@ -0,0 +1,36 @@
+use crate::mdp_traits::DisplayState;
+
+use super::Direction::*;
+use super::{Direction, GridTurningMDP, GridTurningState};
+
+fn arrow(d: Direction) -> char {
+    match d {
+        North => '↑',
+        NorthEast => '↗',
+        East => '→',
+        SouthEast => '↘',
+        South => '↓',
+        SouthWest => '↙',
+        West => '←',
+        NorthWest => '↖',
+    }
+}
+
+impl DisplayState<GridTurningState> for GridTurningMDP {
+    fn display(&self, s: &GridTurningState) {
+        for i in 0..self.height {
+            for j in 0..self.width {
+                if s.i == i as i32 && s.j == j as i32 {
+                    print!("{}", arrow(s.direction));
+                } else if self.goal.i == i as i32 && self.goal.j == j as i32 {
+                    print!("G");
+                } else if self.is_obstacled[i][j] {
+                    print!("X");
+                } else {
+                    print!(".");
+                }
+            }
+            println!();
+        }
+    }
+}
@@ -220,6 +220,10 @@ impl PMass<f32> for GridTurningMDP {
 
 impl ExplicitTransition for GridTurningMDP {}
 
+impl GetNextStateFromPMass for GridTurningMDP {}
+
+impl GetNextStateMutFromImmut for GridTurningMDP {}
+
 fn distance(s: &GridTurningState, ss: &GridTurningState) -> f32 {
     (((s.i - ss.i) * (s.i - ss.i) + (s.j - ss.j) * (s.j - ss.j)) as f32).sqrt()
 }
@@ -47,3 +47,9 @@ impl BuildFrom<Coordinate2, GridTurningMDP> for GridTurningPartialMDP {
         mdp
     }
 }
+
+impl<'a> BuildFrom<&'a Coordinate2, GridTurningMDP> for GridTurningPartialMDP {
+    fn build_from(&self, goal: &'a Coordinate2) -> GridTurningMDP {
+        self.build_from(*goal)
+    }
+}
@@ -1,9 +1,11 @@
 mod direction;
+mod display;
 mod grid_turning_action;
 mod grid_turning_mdp;
 mod grid_turning_pair;
 mod grid_turning_partial_mdp;
 mod grid_turning_state;
+mod render_to;
 
 pub use self::direction::*;
 pub use self::grid_turning_action::GridTurningAction;
@@ -0,0 +1,144 @@
+use crate::mdp_traits::RenderTo;
+use tiny_skia::*;
+
+use super::direction::{get_di, get_dj};
+use super::{GridTurningMDP, GridTurningState};
+
+static GRID_SIZE: f32 = 40.0;
+
+fn to_pixel(i: usize) -> f32 {
+    (i as f32) * GRID_SIZE
+}
+
+impl GridTurningMDP {
+    fn cell_path(&self, i: usize, j: usize) -> Path {
+        PathBuilder::from_rect(
+            Rect::from_xywh(to_pixel(j), to_pixel(i), GRID_SIZE, GRID_SIZE).unwrap(),
+        )
+    }
+
+    pub fn draw_cells(&self, pixmap: &mut Pixmap) {
+        pixmap.fill(Color::WHITE);
+
+        for i in 0..self.height {
+            for j in 0..self.width {
+                let mut paint = Paint::default();
+                if self.is_obstacled[i][j] {
+                    paint.set_color_rgba8(170, 170, 170, 220);
+                } else if self.goal.i == i as i32 && self.goal.j == j as i32 {
+                    paint.set_color_rgba8(80, 180, 80, 200);
+                } else {
+                    continue;
+                }
+                pixmap.fill_path(
+                    &self.cell_path(i, j),
+                    &paint,
+                    FillRule::Winding,
+                    Transform::identity(),
+                    None,
+                );
+            }
+        }
+    }
+
+    pub fn draw_grids(&self, pixmap: &mut Pixmap) {
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(0, 0, 0, 200);
+
+        let stroke = Stroke {
+            width: 1.0,
+            ..Stroke::default()
+        };
+
+        for i in 0..=self.height {
+            let mut pb = PathBuilder::new();
+            pb.move_to(0.0, to_pixel(i));
+            pb.line_to(to_pixel(self.width), to_pixel(i));
+            let path = pb.finish().unwrap();
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+
+        for j in 0..=self.width {
+            let mut pb = PathBuilder::new();
+            pb.move_to(to_pixel(j), 0.0);
+            pb.line_to(to_pixel(j), to_pixel(self.height));
+            let path = pb.finish().unwrap();
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    // The heading is drawn as a triangle whose tip points to the cell the
+    // agent reaches when it goes straight.
+    fn draw_agent(&self, s: &GridTurningState, pixmap: &mut Pixmap) {
+        let cx = to_pixel(s.j as usize) + GRID_SIZE / 2.0;
+        let cy = to_pixel(s.i as usize) + GRID_SIZE / 2.0;
+        let (di, dj) = (get_di(s.direction) as f32, get_dj(s.direction) as f32);
+        let norm = (di * di + dj * dj).sqrt();
+        let (ux, uy) = (dj / norm, di / norm);
+        let (tip, back, half_width) = (15.0, 10.0, 9.0);
+
+        let mut pb = PathBuilder::new();
+        pb.move_to(cx + ux * tip, cy + uy * tip);
+        pb.line_to(
+            cx - ux * back - uy * half_width,
+            cy - uy * back + ux * half_width,
+        );
+        pb.line_to(
+            cx - ux * back + uy * half_width,
+            cy - uy * back - ux * half_width,
+        );
+        pb.close();
+        let path = pb.finish().unwrap();
+
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(200, 0, 0, 200);
+        pixmap.fill_path(
+            &path,
+            &paint,
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+    }
+}
+
+impl RenderTo for GridTurningMDP {
+    fn render_to(&self, s: &GridTurningState, path: &str) {
+        let mut pixmap = Pixmap::new(
+            (self.width as f32 * GRID_SIZE) as u32,
+            (self.height as f32 * GRID_SIZE) as u32,
+        )
+        .unwrap();
+
+        self.draw_cells(&mut pixmap);
+        self.draw_grids(&mut pixmap);
+        self.draw_agent(s, &mut pixmap);
+
+        pixmap.save_png(path).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::coordinate2::Coordinate2;
+    use crate::grid_turning::Direction::*;
+
+    #[test]
+    fn test_grid_turning_render_to() {
+        let mdp = GridTurningMDP::new(
+            5,
+            5,
+            vec![Coordinate2::new(4, 2), Coordinate2::new(3, 2)],
+            GridTurningState::new(4, 0, North),
+            Coordinate2::new(0, 4),
+            0.0,
+        );
+        let path = std::env::temp_dir().join("mdp_test_grid_turning.png");
+        mdp.render_to(
+            &GridTurningState::new(2, 1, NorthEast),
+            path.to_str().unwrap(),
+        );
+        assert!(path.exists());
+    }
+}
@@ -1,61 +1,53 @@
+use crate::common::grid2d::GridMap;
 use crate::grid_world::grid_status::GridStatus;
 use crate::grid_world::GridWorldAction::*;
 use crate::grid_world::GridWorldMDP;
 use crate::grid_world::GridWorldState;
 use itertools::iproduct;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
+use std::fs;
 
 impl GridWorldMDP {
+    /// Reads the `.gw` format: the width and the height on the first two
+    /// lines followed by the grid with `@` for watery cells.
     pub fn from_file(filename: &str) -> GridWorldMDP {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-        let wl = lines.next().unwrap().unwrap();
-        let w = wl.trim().parse::<i64>().unwrap();
-        let h = lines.next().unwrap().unwrap().parse::<i64>().unwrap();
-        let mut grids = vec![];
+        let data = fs::read_to_string(filename).expect("Unable to read file");
+        let grid = data
+            .lines()
+            .skip(2)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .replace('@', "~");
+        GridWorldMDP::from_grid_map(&GridMap::parse(&grid))
+    }
 
-        for line in lines {
-            let mut row = vec![];
-            if let Ok(ip) = line {
-                //                 println!("{}", ip);
-                for c in ip.chars() {
-                    let s = match c {
-                        'x' => GridStatus::Wall,
-                        'G' => GridStatus::Goal,
-                        'S' => GridStatus::Start,
-                        '.' => GridStatus::Blank,
-                        '@' => GridStatus::Watery,
-                        x => panic!("unexpected character{}", x),
-                    };
-                    row.push(s);
-                }
-            }
-            //             println!("{}", row.len());
-            grids.push(row);
+    /// Grid world has a single kind of costly cell, so water, fire and
+    /// dangerous cells all become watery.
+    pub fn from_grid_map(map: &GridMap) -> GridWorldMDP {
+        let (h, w) = (map.height(), map.width());
+        let mut grids = vec![vec![GridStatus::Blank; w]; h];
+        for (i, j) in map.obstacles() {
+            grids[i][j] = GridStatus::Wall;
         }
-
-        let mut initial_state = GridWorldState::new(0, 0);
-        for (y, row) in grids.iter().enumerate() {
-            for (x, s) in row.iter().enumerate() {
-                match s {
-                    GridStatus::Start => initial_state = GridWorldState::new(x as i64, y as i64),
-                    _ => (),
-                }
-            }
+        for &(i, j) in map.water.iter().chain(&map.fire).chain(&map.dangerous) {
+            grids[i][j] = GridStatus::Watery;
+        }
+        for &(i, j) in map.goals.iter() {
+            grids[i][j] = GridStatus::Goal;
+        }
+        if let Some((i, j)) = map.start {
+            grids[i][j] = GridStatus::Start;
         }
+        let (i, j) = map.start.unwrap_or((0, 0));
 
         let mut grid = GridWorldMDP {
-            h: h,
-            w: w,
-            initial_state: initial_state,
+            h: h as i64,
+            w: w as i64,
+            initial_state: GridWorldState::new(j as i64, i as i64),
             all_states: vec![],
             all_actions: [AttemptUp, AttemptRight, AttemptDown, AttemptLeft],
-            grids: grids,
+            grids,
         };
-        grid.all_states = iproduct!((0..w), (0..h))
+        grid.all_states = iproduct!((0..grid.w), (0..grid.h))
             .filter(|(x, y)| grid.is_valid_cordinate(*x, *y))
             .map(|(x, y)| GridWorldState { x, y })
             .collect();
@@ -78,5 +70,21 @@ mod tests {
         let mdp = GridWorldMDP::from_file("data/gws/map10.gw");
         assert_eq!(mdp.h, 11);
         assert_eq!(mdp.w, 70);
+        assert!(mdp.is_watery(&GridWorldState::new(1, 0)));
+    }
+
+    #[test]
+    fn test_grid_world_from_grid_map() {
+        let map = GridMap::from_data("water_fire.map");
+        let mdp = GridWorldMDP::from_grid_map(&map);
+        assert_eq!(mdp.h, 7);
+        assert_eq!(mdp.w, 7);
+        assert_eq!(mdp.initial_state, GridWorldState::new(3, 6));
+        assert_eq!(mdp.grids[0][3], GridStatus::Goal);
+        assert_eq!(mdp.grids[1][4], GridStatus::Watery);
+        for &(i, j) in map.fire.iter() {
+            assert!(mdp.is_watery(&GridWorldState::new(j as i64, i as i64)));
+        }
+        assert_eq!(mdp.all_states.len(), 7 * 7 - 7);
     }
 }
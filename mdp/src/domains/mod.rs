@@ -1,6 +1,6 @@
 pub mod baker_grid;
 pub mod blocks_world;
-//pub mod canadian_traveler;
+pub mod canadian_traveler;
 pub mod grid_turning;
 pub mod grid_world;
 pub mod race_track;
@@ -0,0 +1,35 @@
+use crate::mdp_traits::DisplayState;
+
+use super::{RaceTrackMDP, RaceTrackState};
+
+impl DisplayState<RaceTrackState> for RaceTrackMDP {
+    fn display(&self, s: &RaceTrackState) {
+        for y in (0..self.h).rev() {
+            for x in 0..self.w {
+                let is_agent = match s {
+                    RaceTrackState::Dummy => false,
+                    RaceTrackState::Wrapper(inner) => inner.x == x && inner.y == y,
+                };
+                if is_agent {
+                    print!("O");
+                } else if self.is_wall(x, y) {
+                    print!("X");
+                } else if self.is_goal(x, y) {
+                    print!("G");
+                } else if self.is_start(x, y) {
+                    print!("S");
+                } else if self.is_pothole(x, y) {
+                    print!("o");
+                } else if self.is_unsafe(x, y) {
+                    print!(".");
+                } else {
+                    print!(" ");
+                }
+            }
+            println!();
+        }
+        if let RaceTrackState::Wrapper(inner) = s {
+            println!("velocity: ({}, {})", inner.dx, inner.dy);
+        }
+    }
+}
@@ -1,8 +1,12 @@
+mod display;
 mod race_track_action;
 mod race_track_mdp;
+mod race_track_partial_mdp;
 mod race_track_state;
+mod render_to;
 mod to_var_name;
 
 pub use race_track_action::{get_ddx, get_ddy, RaceTrackAction};
 pub use race_track_mdp::RaceTrackMDP;
+pub use race_track_partial_mdp::{FinishSegment, RaceTrackPartialMDP};
 pub use race_track_state::RaceTrackState;
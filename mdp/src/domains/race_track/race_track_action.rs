@@ -1,9 +1,11 @@
+use crate::into_inner::Inner;
 use crate::mdp_traits::ToVarName;
+use mdp_derive::Inner;
 
 use self::RaceTrackAction::*;
 
 #[allow(dead_code)]
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Inner)]
 pub enum RaceTrackAction {
     North,
     South,
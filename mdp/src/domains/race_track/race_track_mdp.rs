@@ -121,6 +121,44 @@ impl RaceTrackMDP {
         self
     }
 
+    /// Replaces the finish cells of the track with `goals`.
+    pub fn set_goals(mut self, goals: &[(usize, usize)]) -> RaceTrackMDP {
+        for row in self.track.iter_mut() {
+            for status in row.iter_mut() {
+                if *status == Goal {
+                    *status = Blank;
+                }
+            }
+        }
+        self.goals = HashSet::new();
+        for (x, y) in goals {
+            assert!(!self.is_wall(*x, *y));
+            self.track[self.h - y - 1][*x] = Goal;
+            self.goals.insert((*x, *y));
+        }
+        self
+    }
+
+    pub fn set_potholes(mut self, potholes: &[(usize, usize)]) -> RaceTrackMDP {
+        for (x, y) in potholes {
+            assert!(!self.is_wall(*x, *y));
+            self.track[self.h - y - 1][*x] = Pothole;
+        }
+        self
+    }
+
+    pub fn is_goal(&self, x: usize, y: usize) -> bool {
+        self.goals.contains(&(x, y))
+    }
+
+    pub fn is_start(&self, x: usize, y: usize) -> bool {
+        self.starts.contains(&(x, y))
+    }
+
+    pub fn is_pothole(&self, x: usize, y: usize) -> bool {
+        self.get_status(x, y) == Pothole
+    }
+
     fn get_status(&self, x: usize, y: usize) -> TrackStatus {
         //         println!("{} {}", x, y);
         assert!(self.within_bound(x, y));
@@ -176,6 +214,30 @@ impl RaceTrackMDP {
     }
 }
 
+impl RaceTrackMDP {
+    // After a crash the car moves one cell in the direction of the action.
+    // Unavailable actions keep the car in place so that callers which do not
+    // check the availability (e.g. OAMDPs) never leave the track.
+    fn recover(
+        &self,
+        s: &RaceTrackState,
+        inner: &RaceTrackStateInner,
+        a: &RaceTrackAction,
+    ) -> RaceTrackState {
+        if !self.action_available(s, a) {
+            return Wrapper(RaceTrackStateInner::new(inner.x, inner.y, 0, 0));
+        }
+        let ddx = get_ddx(*a);
+        let ddy = get_ddy(*a);
+        Wrapper(RaceTrackStateInner::new(
+            next(inner.x, ddx),
+            next(inner.y, ddy),
+            ddx,
+            ddy,
+        ))
+    }
+}
+
 fn next(x: usize, dx: i32) -> usize {
     //     println!("x:{} dx:{}", x, dx);
     assert!(x as i32 + dx >= 0);
@@ -279,32 +341,7 @@ impl PMass<f32> for RaceTrackMDP {
             }
             Wrapper(inner) => match self.get_status(inner.x, inner.y) {
                 Goal => vec![(*s, 1.0)],
-                Wall => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    vec![(
-                        Wrapper(RaceTrackStateInner::new(
-                            next(inner.x, ddx),
-                            next(inner.y, ddy),
-                            ddx,
-                            ddy,
-                        )),
-                        1.0,
-                    )]
-                }
-                Pothole => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    vec![(
-                        Wrapper(RaceTrackStateInner::new(
-                            next(inner.x, ddx),
-                            next(inner.y, ddy),
-                            ddx,
-                            ddy,
-                        )),
-                        1.0,
-                    )]
-                }
+                Wall | Pothole => vec![(self.recover(s, inner, a), 1.0)],
                 _ => {
                     let mut outcomes = vec![];
                     add_outcome(
@@ -345,32 +382,7 @@ impl PMass<f64> for RaceTrackMDP {
             }
             Wrapper(inner) => match self.get_status(inner.x, inner.y) {
                 Goal => vec![(*s, 1.0)],
-                Wall => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    vec![(
-                        Wrapper(RaceTrackStateInner::new(
-                            next(inner.x, ddx),
-                            next(inner.y, ddy),
-                            ddx,
-                            ddy,
-                        )),
-                        1.0,
-                    )]
-                }
-                Pothole => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    vec![(
-                        Wrapper(RaceTrackStateInner::new(
-                            next(inner.x, ddx),
-                            next(inner.y, ddy),
-                            ddx,
-                            ddy,
-                        )),
-                        1.0,
-                    )]
-                }
+                Wall | Pothole => vec![(self.recover(s, inner, a), 1.0)],
                 _ => {
                     let mut outcomes = vec![];
                     add_outcome_64(
@@ -446,26 +458,7 @@ impl PreferredSuccessor for RaceTrackMDP {
             }
             Wrapper(inner) => match self.get_status(inner.x, inner.y) {
                 Goal => *s,
-                Wall => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    Wrapper(RaceTrackStateInner::new(
-                        next(inner.x, ddx),
-                        next(inner.y, ddy),
-                        ddx,
-                        ddy,
-                    ))
-                }
-                Pothole => {
-                    let ddx = get_ddx(*a);
-                    let ddy = get_ddy(*a);
-                    Wrapper(RaceTrackStateInner::new(
-                        next(inner.x, ddx),
-                        next(inner.y, ddy),
-                        ddx,
-                        ddy,
-                    ))
-                }
+                Wall | Pothole => self.recover(s, inner, a),
                 _ => Wrapper(self.success(&inner, a)),
             },
         }
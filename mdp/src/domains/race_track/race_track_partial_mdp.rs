@@ -0,0 +1,72 @@
+use crate::mdp_traits::BuildFrom;
+use crate::race_track::RaceTrackMDP;
+use crate::state_enumerable_wrapper::StateEnumerableWrapper;
+use serde::{Deserialize, Serialize};
+
+/// A straight line of finish cells from `from` to `to` (both inclusive).
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FinishSegment {
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+}
+
+impl FinishSegment {
+    pub fn new(from: (usize, usize), to: (usize, usize)) -> FinishSegment {
+        assert!(from.0 == to.0 || from.1 == to.1);
+        FinishSegment { from, to }
+    }
+
+    pub fn cells(&self) -> Vec<(usize, usize)> {
+        let (x0, x1) = (self.from.0.min(self.to.0), self.from.0.max(self.to.0));
+        let (y0, y1) = (self.from.1.min(self.to.1), self.from.1.max(self.to.1));
+        (x0..=x1)
+            .flat_map(|x| (y0..=y1).map(move |y| (x, y)))
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct RaceTrackPartialMDP {
+    mdp: RaceTrackMDP,
+}
+
+impl RaceTrackPartialMDP {
+    pub fn new(mdp: RaceTrackMDP) -> RaceTrackPartialMDP {
+        RaceTrackPartialMDP { mdp }
+    }
+}
+
+impl<'a> BuildFrom<&'a FinishSegment, RaceTrackMDP> for RaceTrackPartialMDP {
+    fn build_from(&self, segment: &'a FinishSegment) -> RaceTrackMDP {
+        self.mdp.clone().set_goals(&segment.cells())
+    }
+}
+
+impl<'a> BuildFrom<&'a FinishSegment, StateEnumerableWrapper<RaceTrackMDP>>
+    for RaceTrackPartialMDP
+{
+    fn build_from(&self, segment: &'a FinishSegment) -> StateEnumerableWrapper<RaceTrackMDP> {
+        let mdp: RaceTrackMDP = self.build_from(segment);
+        StateEnumerableWrapper::new(mdp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mdp_traits::{IsTerminal, StateEnumerable};
+    use crate::race_track::RaceTrackState;
+
+    #[test]
+    fn test_race_track_partial_mdp() {
+        let partial_mdp =
+            RaceTrackPartialMDP::new(RaceTrackMDP::from_file("data/tracks/small.track"));
+        let segment = FinishSegment::new((4, 4), (5, 4));
+        assert_eq!(segment.cells(), vec![(4, 4), (5, 4)]);
+
+        let mdp: StateEnumerableWrapper<RaceTrackMDP> = partial_mdp.build_from(&segment);
+        assert!(mdp.is_terminal(&RaceTrackState::new(5, 4, 0, 0)));
+        assert!(!mdp.is_terminal(&RaceTrackState::new(4, 3, 0, 0)));
+        assert!(mdp.num_states() > 1);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::mdp_traits::RenderTo;
+use tiny_skia::*;
+
+use super::{RaceTrackMDP, RaceTrackState};
+
+static GRID_SIZE: f32 = 40.0;
+
+fn to_pixel(i: usize) -> f32 {
+    (i as f32) * GRID_SIZE
+}
+
+impl RaceTrackMDP {
+    fn cell_path(&self, x: usize, y: usize) -> Path {
+        let i = self.h - y - 1;
+        PathBuilder::from_rect(
+            Rect::from_xywh(to_pixel(x), to_pixel(i), GRID_SIZE, GRID_SIZE).unwrap(),
+        )
+    }
+
+    pub fn draw_track(&self, pixmap: &mut Pixmap) {
+        pixmap.fill(Color::WHITE);
+
+        for y in 0..self.h {
+            for x in 0..self.w {
+                let mut paint = Paint::default();
+                if self.is_wall(x, y) {
+                    paint.set_color_rgba8(170, 170, 170, 220);
+                } else if self.is_goal(x, y) {
+                    paint.set_color_rgba8(80, 180, 80, 200);
+                } else if self.is_start(x, y) {
+                    paint.set_color_rgba8(80, 120, 220, 200);
+                } else if self.is_pothole(x, y) {
+                    paint.set_color_rgba8(60, 40, 20, 220);
+                } else if self.is_unsafe(x, y) {
+                    paint.set_color_rgba8(240, 200, 60, 200);
+                } else {
+                    continue;
+                }
+                pixmap.fill_path(
+                    &self.cell_path(x, y),
+                    &paint,
+                    FillRule::Winding,
+                    Transform::identity(),
+                    None,
+                );
+            }
+        }
+    }
+
+    pub fn draw_grids(&self, pixmap: &mut Pixmap) {
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(0, 0, 0, 200);
+
+        let stroke = Stroke {
+            width: 1.0,
+            ..Stroke::default()
+        };
+
+        for i in 0..=self.h {
+            let mut pb = PathBuilder::new();
+            pb.move_to(0.0, to_pixel(i));
+            pb.line_to(to_pixel(self.w), to_pixel(i));
+            let path = pb.finish().unwrap();
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+
+        for j in 0..=self.w {
+            let mut pb = PathBuilder::new();
+            pb.move_to(to_pixel(j), 0.0);
+            pb.line_to(to_pixel(j), to_pixel(self.h));
+            let path = pb.finish().unwrap();
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    fn draw_car(&self, x: usize, y: usize, dx: i32, dy: i32, pixmap: &mut Pixmap) {
+        let cx = to_pixel(x) + GRID_SIZE / 2.0;
+        let cy = to_pixel(self.h - y - 1) + GRID_SIZE / 2.0;
+
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(200, 0, 0, 200);
+
+        let path = PathBuilder::from_circle(cx, cy, 10.0).unwrap();
+        pixmap.fill_path(
+            &path,
+            &paint,
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+
+        // The velocity is drawn as a line to the cell the car would reach
+        // when it keeps its current velocity.
+        if dx != 0 || dy != 0 {
+            let stroke = Stroke {
+                width: 3.0,
+                ..Stroke::default()
+            };
+            let mut pb = PathBuilder::new();
+            pb.move_to(cx, cy);
+            pb.line_to(cx + dx as f32 * GRID_SIZE, cy - dy as f32 * GRID_SIZE);
+            let path = pb.finish().unwrap();
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+}
+
+impl RenderTo for RaceTrackMDP {
+    fn render_to(&self, s: &RaceTrackState, path: &str) {
+        let mut pixmap = Pixmap::new((self.w * 40) as u32, (self.h * 40) as u32).unwrap();
+
+        self.draw_track(&mut pixmap);
+        self.draw_grids(&mut pixmap);
+        if let RaceTrackState::Wrapper(inner) = s {
+            self.draw_car(inner.x, inner.y, inner.dx, inner.dy, &mut pixmap);
+        }
+
+        pixmap.save_png(path).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_race_track_render_to() {
+        let mdp = RaceTrackMDP::from_file("data/tracks/small.track").set_potholes(&[(3, 8)]);
+        let s = RaceTrackState::new(4, 5, 1, 2);
+        mdp.render_to(&s, "race_track.png");
+    }
+}
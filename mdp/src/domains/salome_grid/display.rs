@@ -0,0 +1,26 @@
+use crate::mdp_traits::DisplayState;
+
+use super::mdp_water_fire::GridStatus;
+use super::{SalomeGridState, SalomeGridWaterFireMDP};
+
+impl DisplayState<SalomeGridState> for SalomeGridWaterFireMDP {
+    fn display(&self, s: &SalomeGridState) {
+        for i in 0..self.height() {
+            for j in 0..self.width() {
+                if s.i == i as i32 && s.j == j as i32 {
+                    print!("O");
+                } else if self.mdp.goal.i == i as i32 && self.mdp.goal.j == j as i32 {
+                    print!("G");
+                } else {
+                    match self.grid_status[i][j] {
+                        GridStatus::Obstacled => print!("X"),
+                        GridStatus::Water => print!("~"),
+                        GridStatus::Fire => print!("^"),
+                        GridStatus::Normal => print!("."),
+                    }
+                }
+            }
+            println!();
+        }
+    }
+}
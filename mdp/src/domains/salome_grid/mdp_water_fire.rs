@@ -3,16 +3,17 @@ use super::{
     SalomeGridAction, SalomeGridMDP, SalomeGridState,
 };
 use crate::mdp_traits::*;
+use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum AgentType {
     WaterResistent,
     FireResistent,
 }
 
 #[derive(PartialEq, Debug, Clone)]
-enum GridStatus {
+pub(super) enum GridStatus {
     Water,
     Fire,
     Normal,
@@ -22,7 +23,7 @@ enum GridStatus {
 #[derive(PartialEq, Debug, Clone)]
 pub struct SalomeGridWaterFireMDP {
     pub mdp: SalomeGridMDP,
-    grid_status: Vec<Vec<GridStatus>>,
+    pub(super) grid_status: Vec<Vec<GridStatus>>,
     agent_type: AgentType,
 }
 
@@ -69,6 +70,10 @@ impl SalomeGridWaterFireMDP {
         self.mdp.initial_state = initial_state;
         self
     }
+
+    pub fn agent_type(&self) -> AgentType {
+        self.agent_type
+    }
 }
 
 impl ActionAvailability for SalomeGridWaterFireMDP {}
@@ -179,6 +184,8 @@ impl GetNextState for SalomeGridWaterFireMDP {
     }
 }
 
+impl GetNextStateMutFromImmut for SalomeGridWaterFireMDP {}
+
 #[cfg(test)]
 mod tests {
     use rand::thread_rng;
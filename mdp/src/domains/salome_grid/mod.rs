@@ -1,4 +1,5 @@
 mod action;
+mod display;
 mod example;
 mod example_water_fire;
 mod mdp;
@@ -8,6 +9,6 @@ mod state;
 
 pub use action::SalomeGridAction;
 pub use mdp::SalomeGridMDP;
-pub use mdp_water_fire::AgentType;
+pub use mdp_water_fire::{AgentType, SalomeGridWaterFireMDP};
 pub use partial_mdp::SalomeGridWaterFirePartialMDP;
 pub use state::SalomeGridState;
@@ -1,4 +1,7 @@
-use crate::{common::coordinate2::Coordinate2, mdp_traits::BuildFrom};
+use crate::{
+    common::{coordinate2::Coordinate2, grid2d::GridMap},
+    mdp_traits::BuildFrom,
+};
 
 use super::{
     mdp_water_fire::{AgentType, SalomeGridWaterFireMDP},
@@ -37,6 +40,27 @@ impl SalomeGridWaterFirePartialMDP {
         }
     }
 
+    /// The first goal of the map is the goal of the agent.
+    pub fn from_grid_map(map: &GridMap) -> Result<SalomeGridWaterFirePartialMDP, String> {
+        let to_states = |cells: &[(usize, usize)]| {
+            cells
+                .iter()
+                .map(|&(i, j)| SalomeGridState::new(i as i32, j as i32))
+                .collect::<Vec<_>>()
+        };
+        let (gi, gj) = *map.goals.first().ok_or("the map has no goal")?;
+        let (si, sj) = map.start.ok_or("the map has no start")?;
+        Ok(SalomeGridWaterFirePartialMDP::new(
+            map.height(),
+            map.width(),
+            to_states(&map.obstacles()),
+            SalomeGridState::new(gi as i32, gj as i32),
+            to_states(&map.water),
+            to_states(&map.fire),
+            SalomeGridState::new(si as i32, sj as i32),
+        ))
+    }
+
     pub fn example_water_fire() -> SalomeGridWaterFirePartialMDP {
         let obstacles = vec![
             SalomeGridState::new(1, 3),
@@ -87,6 +111,12 @@ impl SalomeGridWaterFirePartialMDP {
     }
 }
 
+impl<'a> BuildFrom<&'a AgentType, SalomeGridWaterFireMDP> for SalomeGridWaterFirePartialMDP {
+    fn build_from(&self, from: &'a AgentType) -> SalomeGridWaterFireMDP {
+        self.build_from(*from)
+    }
+}
+
 impl BuildFrom<AgentType, SalomeGridWaterFireMDP> for SalomeGridWaterFirePartialMDP {
     fn build_from(&self, from: AgentType) -> SalomeGridWaterFireMDP {
         SalomeGridWaterFireMDP::new(
@@ -101,3 +131,37 @@ impl BuildFrom<AgentType, SalomeGridWaterFireMDP> for SalomeGridWaterFirePartial
         .set_initial_state(self.initial_state)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_salome_partial_mdp_from_grid_map() {
+        let map = GridMap::from_data("water_fire.map");
+        let partial_mdp = SalomeGridWaterFirePartialMDP::from_grid_map(&map).unwrap();
+        let example = SalomeGridWaterFirePartialMDP::example_water_fire();
+        assert_eq!(partial_mdp.goal, example.goal);
+        assert_eq!(partial_mdp.initial_state, example.initial_state);
+        let sorted = |v: &Vec<SalomeGridState>| {
+            let mut v = v.clone();
+            v.sort_by_key(|s| (s.i, s.j));
+            v
+        };
+        assert_eq!(sorted(&partial_mdp.obstacles), sorted(&example.obstacles));
+        assert_eq!(
+            sorted(&partial_mdp.water_cells),
+            sorted(&example.water_cells)
+        );
+        assert_eq!(sorted(&partial_mdp.fire_cells), sorted(&example.fire_cells));
+    }
+
+    #[test]
+    fn test_salome_partial_mdp_from_grid_map_without_goal() {
+        let map = GridMap::parse("S..\n.#.\n");
+        assert_eq!(
+            SalomeGridWaterFirePartialMDP::from_grid_map(&map),
+            Err("the map has no goal".to_string())
+        );
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
 pub struct Coordinate {
     pub i: i64,
     pub j: i64,
@@ -12,6 +12,7 @@ use crate::search_rescue::{
     Coordinate, ObstacleCompatibility, SearchRescueAction, SearchRescueParameter,
     SearchRescuePartialMDP, SearchRescueState,
 };
+use crate::state_enumerable_wrapper::StateEnumerableWrapper;
 use core::slice::Iter;
 
 #[derive(PartialEq, Debug, Clone)]
@@ -34,6 +35,7 @@ impl SearchRescueMDP {
         base_coordinate: Coordinate,
         obstacle_compatibility: ObstacleCompatibility,
     ) -> SearchRescueMDP {
+        assert!(obstacles.len() <= 4);
         let mut is_obstacled = vec![vec![None; width]; height];
         for (id, (i, j)) in obstacles.into_iter().enumerate() {
             is_obstacled[i][j] = Some(id);
@@ -224,13 +226,27 @@ impl<'a> BuildFrom<&'a SearchRescueParameter, SearchRescueMDP> for SearchRescueP
             self.height,
             self.width,
             self.obstacles.clone(),
-            self.victim_coordinate,
+            parameter
+                .victim_coordinate
+                .unwrap_or(self.victim_coordinate),
             parameter.base_coordinate,
             parameter.obstacle_compatibility,
         )
     }
 }
 
+impl<'a> BuildFrom<&'a SearchRescueParameter, StateEnumerableWrapper<SearchRescueMDP>>
+    for SearchRescuePartialMDP
+{
+    fn build_from(
+        &self,
+        parameter: &'a SearchRescueParameter,
+    ) -> StateEnumerableWrapper<SearchRescueMDP> {
+        let mdp: SearchRescueMDP = self.build_from(parameter);
+        StateEnumerableWrapper::new(mdp)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rand::thread_rng;
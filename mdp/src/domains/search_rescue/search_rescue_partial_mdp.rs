@@ -1,16 +1,19 @@
 use crate::search_rescue::Coordinate;
+use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, EnumIter)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, EnumIter, Serialize, Deserialize)]
 pub enum ObstacleCompatibility {
     Low,
     High,
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SearchRescueParameter {
     pub(in crate::search_rescue) base_coordinate: Coordinate,
     pub(in crate::search_rescue) obstacle_compatibility: ObstacleCompatibility,
+    #[serde(default)]
+    pub(in crate::search_rescue) victim_coordinate: Option<Coordinate>,
 }
 
 impl SearchRescueParameter {
@@ -21,11 +24,17 @@ impl SearchRescueParameter {
         SearchRescueParameter {
             base_coordinate: base_coordinate,
             obstacle_compatibility: obstacle_compatibility,
+            victim_coordinate: None,
         }
     }
+
+    pub fn set_victim_coordinate(mut self, victim_coordinate: Coordinate) -> Self {
+        self.victim_coordinate = Some(victim_coordinate);
+        self
+    }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct SearchRescuePartialMDP {
     pub(in crate::search_rescue) height: usize,
     pub(in crate::search_rescue) width: usize,
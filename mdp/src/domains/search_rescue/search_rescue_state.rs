@@ -41,4 +41,16 @@ impl SearchRescueState {
             obstacles_status: obstacles_status,
         }
     }
+
+    pub fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+
+    pub fn victim_status(&self) -> VictimStatus {
+        self.victim_status
+    }
+
+    pub fn obstacles_status(&self) -> [ObstacleStatus; 4] {
+        self.obstacles_status
+    }
 }
@@ -1,5 +1,7 @@
 use crate::simple_av::SimpleAVState;
+use serde::{Deserialize, Serialize};
 
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct SimpleAVPartialMDP {
     pub(in crate::domains::simple_av) miny: usize,
     pub(in crate::domains::simple_av) maxy: usize,
@@ -24,4 +26,4 @@ impl SimpleAVPartialMDP {
             start: start,
         }
     }
-}
\ No newline at end of file
+}
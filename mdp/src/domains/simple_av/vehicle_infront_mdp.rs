@@ -1,8 +1,8 @@
 use crate::common::av1d_map::AV1dRange;
 use crate::mdp_traits::{
     ActionAvailability, ActionEnumerable, BuildFrom, Cost, DCost, ExplicitTransition,
-    GetNextStateFromPMass, InitialState, IsTerminal, PMass, PreferredSuccessor, StateEnumerable,
-    StatesActions,
+    GetNextStateFromPMass, GetNextStateMutFromImmut, InitialState, IsTerminal, PMass, PMassMutFrom,
+    PreferredSuccessor, StateEnumerable, StatesActions,
 };
 use crate::simple_av::vehicle_configuration::VehicleConfiguration;
 use crate::simple_av::SimpleAVAction::*;
@@ -96,6 +96,7 @@ impl SimpleAVVehicleInFrontMDP {
 }
 
 impl GetNextStateFromPMass for SimpleAVVehicleInFrontMDP {}
+impl GetNextStateMutFromImmut for SimpleAVVehicleInFrontMDP {}
 
 impl ActionAvailability for SimpleAVVehicleInFrontMDP {}
 
@@ -210,6 +211,8 @@ impl PMass<f32> for SimpleAVVehicleInFrontMDP {
 
 impl ExplicitTransition for SimpleAVVehicleInFrontMDP {}
 
+impl PMassMutFrom<f32> for SimpleAVVehicleInFrontMDP {}
+
 impl Cost for SimpleAVVehicleInFrontMDP {
     fn cost(&self, s: &Self::State, a: &Self::Action) -> f32 {
         self.p_mass(s, a)
@@ -231,6 +234,12 @@ impl BuildFrom<SimpleAVParameter, SimpleAVVehicleInFrontMDP> for SimpleAVPartial
     }
 }
 
+impl<'a> BuildFrom<&'a SimpleAVParameter, SimpleAVVehicleInFrontMDP> for SimpleAVPartialMDP {
+    fn build_from(&self, parameter: &'a SimpleAVParameter) -> SimpleAVVehicleInFrontMDP {
+        self.build_from(*parameter)
+    }
+}
+
 impl BuildFrom<SimpleAVParameter, SimpleAVVehicleInFrontMDP> for SimpleAVVehicleInFrontPartialMDP {
     fn build_from(&self, parameter: SimpleAVParameter) -> SimpleAVVehicleInFrontMDP {
         SimpleAVVehicleInFrontMDP::new(
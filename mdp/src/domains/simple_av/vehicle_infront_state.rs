@@ -1,8 +1,9 @@
 use crate::into_inner::InnerMost;
 use crate::simple_av::vehicle_configuration::VehicleConfiguration;
 use mdp_derive::InnerMost;
+use serde::{Deserialize, Serialize};
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, InnerMost)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize, InnerMost)]
 pub struct SimpleAVVehicleInFrontState {
     pub(in crate::simple_av) ego_vehicle: VehicleConfiguration,
     pub(in crate::simple_av) vehicle_in_front: VehicleConfiguration,
@@ -18,4 +19,12 @@ impl SimpleAVVehicleInFrontState {
             vehicle_in_front,
         }
     }
+
+    pub fn ego_vehicle(&self) -> VehicleConfiguration {
+        self.ego_vehicle
+    }
+
+    pub fn vehicle_in_front(&self) -> VehicleConfiguration {
+        self.vehicle_in_front
+    }
 }
@@ -0,0 +1,32 @@
+use crate::mdp_traits::DisplayState;
+
+use super::{Lane, SimpleAVLaneChangeMDP, SimpleAVLaneChangeState};
+
+fn to_column(lane: &Lane) -> usize {
+    match lane {
+        Lane::LeftLeft => 0,
+        Lane::LeftCenter => 1,
+        Lane::LeftRight => 2,
+        Lane::RightLeft => 3,
+        Lane::RightCenter => 4,
+        Lane::RightRight => 5,
+    }
+}
+
+impl DisplayState<SimpleAVLaneChangeState> for SimpleAVLaneChangeMDP {
+    fn display(&self, s: &SimpleAVLaneChangeState) {
+        println!("{:?}", s);
+        for i in (0..=self.maxy).rev() {
+            let mut row = ['.', '.', '.', '|', ' ', ' ', ' '];
+            // The other vehicle drives in the left lane.
+            if s.other_vehicle.y == i {
+                row[1] = 'O';
+            }
+            if s.ego_vehicle.y == i {
+                let j = to_column(&s.ego_vehicle.lane);
+                row[if j < 3 { j } else { j + 1 }] = 'E';
+            }
+            println!("{}", row.iter().collect::<String>());
+        }
+    }
+}
@@ -12,9 +12,8 @@ use crate::strum::IntoEnumIterator;
 use core::slice::Iter;
 use itertools::iproduct;
 
-
 pub struct SimpleAVLaneChangeMDP {
-    maxy: usize,
+    pub(in crate::domains::simple_av_lane_change) maxy: usize,
     mindy: i32,
     maxdy: i32,
     all_states: Vec<SimpleAVLaneChangeState>,
@@ -82,6 +81,7 @@ impl IsTerminal for SimpleAVLaneChangeMDP {
 }
 
 impl GetNextStateFromPMass for SimpleAVLaneChangeMDP {}
+impl GetNextStateMutFromImmut for SimpleAVLaneChangeMDP {}
 
 impl ActionEnumerable for SimpleAVLaneChangeMDP {
     fn enumerate_actions(&self) -> Iter<Self::Action> {
@@ -1,4 +1,5 @@
 mod action;
+mod display;
 mod lane;
 mod mdp;
 mod parameter;
@@ -12,4 +13,4 @@ pub use lane::Lane;
 pub use parameter::SimpleAVLaneChangeParameter;
 pub use partial_mdp::SimpleAVLaneChangePartialMDP;
 pub use state::SimpleAVLaneChangeState;
-pub use vehicle_configuration_lane::VehicleConfigurationLane;
\ No newline at end of file
+pub use vehicle_configuration_lane::VehicleConfigurationLane;
@@ -5,7 +5,9 @@ use crate::simple_av_lane_change::mdp::SimpleAVLaneChangeMDP;
 use crate::simple_av_lane_change::parameter::SimpleAVLaneChangeParameter;
 use crate::simple_av_lane_change::state::SimpleAVLaneChangeState;
 use crate::simple_av_lane_change::vehicle_configuration_lane::VehicleConfigurationLane;
+use serde::{Deserialize, Serialize};
 
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct SimpleAVLaneChangePartialMDP {
     pub(in crate::domains::simple_av_lane_change) maxy: usize,
     pub(in crate::domains::simple_av_lane_change) mindy: i32,
@@ -27,6 +29,12 @@ impl SimpleAVLaneChangePartialMDP {
             start: start,
         }
     }
+
+    pub fn set_start_state(mut self, start: SimpleAVLaneChangeState) -> Self {
+        self.start = start;
+
+        self
+    }
 }
 
 impl BuildFrom<SimpleAVLaneChangeParameter, SimpleAVLaneChangeMDP>
@@ -36,3 +44,11 @@ impl BuildFrom<SimpleAVLaneChangeParameter, SimpleAVLaneChangeMDP>
         SimpleAVLaneChangeMDP::new(self.maxy, self.mindy, self.maxdy, self.start, parameter)
     }
 }
+
+impl<'a> BuildFrom<&'a SimpleAVLaneChangeParameter, SimpleAVLaneChangeMDP>
+    for SimpleAVLaneChangePartialMDP
+{
+    fn build_from(&self, parameter: &'a SimpleAVLaneChangeParameter) -> SimpleAVLaneChangeMDP {
+        self.build_from(*parameter)
+    }
+}
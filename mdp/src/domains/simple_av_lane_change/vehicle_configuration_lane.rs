@@ -1,16 +1,14 @@
 use crate::simple_av::succ::*;
-use crate::simple_av_lane_change::lane::Lane;
 use crate::simple_av_lane_change::action::action_to_ddy;
-use crate::simple_av_lane_change::action::{
-    SimpleAVLaneChangeAction, Steering,
-};
+use crate::simple_av_lane_change::action::{SimpleAVLaneChangeAction, Steering};
+use crate::simple_av_lane_change::lane::Lane;
 use serde::{Deserialize, Serialize};
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
 pub struct VehicleConfigurationLane {
     pub y: usize,
     pub dy: i32,
-    pub(in crate) lane: Lane,
+    pub(crate) lane: Lane,
 }
 
 impl VehicleConfigurationLane {
@@ -18,7 +16,11 @@ impl VehicleConfigurationLane {
         VehicleConfigurationLane { y, dy, lane }
     }
 
-    pub(in crate) fn next(
+    pub fn lane(&self) -> Lane {
+        self.lane
+    }
+
+    pub(crate) fn next(
         self,
         a: &SimpleAVLaneChangeAction,
         maxy: usize,
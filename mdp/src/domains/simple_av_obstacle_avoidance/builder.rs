@@ -5,7 +5,9 @@ use crate::simple_av_obstacle_avoidance::mdp::ObstacleAvoidanceMDP;
 use crate::simple_av_obstacle_avoidance::parameter::ObstacleAvoidanceParameter;
 use crate::simple_av_obstacle_avoidance::state::ObstacleAvoidanceState;
 use crate::simple_av_obstacle_avoidance::vehicle_configuration_lane::VehicleConfigurationLane;
+use serde::{Deserialize, Serialize};
 
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct ObstacleAvoidanceBuilder {
     pub(in crate::domains::simple_av_obstacle_avoidance) maxy: usize,
     pub(in crate::domains::simple_av_obstacle_avoidance) mindy: i32,
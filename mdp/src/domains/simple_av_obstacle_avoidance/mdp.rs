@@ -2,7 +2,8 @@ use self::ObstacleAvoidanceParameter::*;
 use crate::common::av1d_map::AV1dRange;
 use crate::mdp_traits::{
     ActionAvailability, ActionEnumerable, Cost, DCost, ExplicitTransition, GetNextStateFromPMass,
-    GetNextStateMutFromImmut, InitialState, IsTerminal, PMass, StateEnumerable, StatesActions,
+    GetNextStateMutFromImmut, InitialState, IsTerminal, PMass, PMassMutFrom, StateEnumerable,
+    StatesActions,
 };
 use crate::simple_av::vehicle_configuration::VehicleConfiguration;
 use crate::simple_av::SimpleAVAction::*;
@@ -247,6 +248,7 @@ impl ActionAvailability for ObstacleAvoidanceMDP {}
 
 impl GetNextStateFromPMass for ObstacleAvoidanceMDP {}
 impl GetNextStateMutFromImmut for ObstacleAvoidanceMDP {}
+impl PMassMutFrom<f32> for ObstacleAvoidanceMDP {}
 
 // #[cfg(test)]
 // mod tests {
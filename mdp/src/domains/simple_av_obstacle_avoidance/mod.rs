@@ -1,12 +1,12 @@
 mod lane;
 // mod head_light_policy;
 mod action;
+mod builder;
 mod display;
 mod mdp;
-mod state;
-// mod render_to;
-mod builder;
 mod parameter;
+mod render_to;
+mod state;
 mod vehicle_configuration_lane;
 
 pub use action::ObstacleAvoidanceAction;
@@ -1,7 +1,7 @@
 use crate::mdp_traits::RenderTo;
 
-use super::{ObstacleAvoidanceState, SimpleAVObstacleAvoidanceMDP};
+use super::{ObstacleAvoidanceMDP, ObstacleAvoidanceState};
 
-impl RenderTo for SimpleAVObstacleAvoidanceMDP {
+impl RenderTo for ObstacleAvoidanceMDP {
     fn render_to(&self, _s: &ObstacleAvoidanceState, _path: &str) {}
 }
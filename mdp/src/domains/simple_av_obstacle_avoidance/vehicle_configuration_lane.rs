@@ -16,6 +16,10 @@ impl VehicleConfigurationLane {
         VehicleConfigurationLane { y, dy, lane }
     }
 
+    pub fn lane(&self) -> Lane {
+        self.lane
+    }
+
     pub(crate) fn next(
         self,
         a: &ObstacleAvoidanceAction,
@@ -1,8 +1,9 @@
 use crate::into_inner::Inner;
+use serde::{Deserialize, Serialize};
 
 use self::SpellingAction::*;
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
 pub enum SpellingAction {
     North,
     South,
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, EnumIter)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, EnumIter, Serialize, Deserialize)]
 pub enum Letter {
     A,
     M,
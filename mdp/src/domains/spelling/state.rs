@@ -1,9 +1,13 @@
 use super::letter::Letter;
 use crate::{common::coordinate2::Coordinate2, mdp_traits::ToVarName};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+#[serde_as]
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
 pub struct SpellingState<const N: usize> {
     pub coord: Coordinate2,
+    #[serde_as(as = "[_; N]")]
     pub(crate) letters: [Letter; N],
 }
 
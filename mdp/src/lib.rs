@@ -22,7 +22,9 @@ pub mod policy;
 pub mod value_estimator;
 mod wrapper;
 
+pub use algorithms::linear_programming;
 pub use algorithms::policy_evaluation;
+pub use algorithms::policy_iteration;
 pub use algorithms::value_iteration;
 pub use common::arena;
 pub use common::state_queue;
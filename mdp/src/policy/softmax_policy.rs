@@ -1,54 +1,154 @@
 use crate::mdp_traits::*;
-use crate::policy::policy_traits::GetActionProbability;
-use crate::value_estimator::{CostEstimator, ValueEstimator};
+use crate::policy::policy_traits::{GetAction, GetActionMut, GetActionProbability};
+use crate::value_estimator::{CostEstimator, CostEstimatorMut, ValueEstimator};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::common::value_table::ValueTable;
-use crate::value_iteration::value_iteration_ssp;
+use crate::value_iteration::SSPSolver;
 
 use super::policy_traits::GetActionProbabilityMut;
 
+/// How the inverse temperature `beta` evolves with the number of steps taken
+/// by a softmax policy. Annealing raises `beta` towards `final_beta`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TemperatureSchedule {
+    #[default]
+    Constant,
+    Linear {
+        final_beta: f32,
+        steps: usize,
+    },
+    Exponential {
+        rate: f32,
+        final_beta: f32,
+    },
+}
+
+impl TemperatureSchedule {
+    pub fn beta_at(&self, beta: f32, step: usize) -> f32 {
+        match *self {
+            TemperatureSchedule::Constant => beta,
+            TemperatureSchedule::Linear { final_beta, steps } => {
+                if steps == 0 {
+                    final_beta
+                } else {
+                    let ratio = step.min(steps) as f32 / steps as f32;
+                    beta + (final_beta - beta) * ratio
+                }
+            }
+            TemperatureSchedule::Exponential { rate, final_beta } => {
+                let b = beta * rate.powi(step as i32);
+                if rate >= 1.0 {
+                    b.min(final_beta)
+                } else {
+                    b.max(final_beta)
+                }
+            }
+        }
+    }
+}
+
+/// Boltzmann probability of the action with cost-to-go `qsa` among actions
+/// with cost-to-go `qs`.
+pub fn boltzmann_probability(beta: f32, qsa: f32, qs: &[f32]) -> f32 {
+    let min_qsa = qs.iter().cloned().fold(f32::INFINITY, f32::min);
+    if !min_qsa.is_finite() {
+        return 1.0 / qs.len() as f32;
+    }
+    let result = (-beta * (qsa - min_qsa)).exp()
+        / qs.iter()
+            .map(|q| (-beta * (q - min_qsa)).exp())
+            .sum::<f32>();
+    if result == 0.0 {
+        1e-6
+    } else {
+        result
+    }
+}
+
 pub struct SoftmaxPolicyBuilder {
     beta: f32,
+    solver: SSPSolver,
+    schedule: TemperatureSchedule,
 }
 
 impl SoftmaxPolicyBuilder {
     pub fn new(beta: f32) -> SoftmaxPolicyBuilder {
-        SoftmaxPolicyBuilder { beta: beta }
+        SoftmaxPolicyBuilder {
+            beta,
+            solver: SSPSolver::ValueIteration,
+            schedule: TemperatureSchedule::Constant,
+        }
+    }
+
+    pub fn set_solver(mut self, solver: SSPSolver) -> SoftmaxPolicyBuilder {
+        self.solver = solver;
+        self
+    }
+
+    pub fn set_schedule(mut self, schedule: TemperatureSchedule) -> SoftmaxPolicyBuilder {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Wraps an already computed cost estimator (e.g. an RTDP or MCTS
+    /// solution) instead of solving the MDP with `solver`.
+    pub fn build_with<V>(&self, vt: V) -> SoftmaxPolicy<V> {
+        SoftmaxPolicy::new(self.beta, vt).set_schedule(self.schedule)
     }
 }
 
 pub struct SoftmaxPolicy<V> {
     pub beta: f32,
     pub vt: V,
+    schedule: TemperatureSchedule,
+    step: usize,
 }
 
 impl<V> SoftmaxPolicy<V> {
     pub fn new(beta: f32, vt: V) -> SoftmaxPolicy<V> {
-        SoftmaxPolicy { beta, vt }
+        SoftmaxPolicy {
+            beta,
+            vt,
+            schedule: TemperatureSchedule::Constant,
+            step: 0,
+        }
+    }
+
+    pub fn set_schedule(mut self, schedule: TemperatureSchedule) -> SoftmaxPolicy<V> {
+        self.schedule = schedule;
+        self
+    }
+
+    pub fn current_beta(&self) -> f32 {
+        self.schedule.beta_at(self.beta, self.step)
+    }
+
+    pub fn set_step(&mut self, step: usize) {
+        self.step = step;
+    }
+
+    pub fn advance(&mut self) {
+        self.step += 1;
     }
 }
 
-impl<M: ActionAvailability + StateEnumerable + ActionEnumerable + PMass<f32> + Cost>
-    GetActionProbability<M::Action, M> for SoftmaxPolicy<ValueTable<M::State>>
+impl<M: ActionAvailability + ActionEnumerable, V: CostEstimator<M>>
+    GetActionProbability<M::Action, M> for SoftmaxPolicy<V>
 {
     fn get_action_probability(&self, s: &M::State, a: &M::Action, mdp: &M) -> f32 {
-        let min_qsa = self.vt.get_value_ssp(s, mdp);
-        let result = ((self.beta * (-1.0) * (self.vt.get_qsa_ssp(s, a, mdp) - min_qsa)).exp())
-            / (mdp
-                .enumerate_actions()
-                .filter(|a| mdp.action_available(s, a))
-                .map(|at| (self.beta * (-1.0) * (self.vt.get_qsa_ssp(s, at, mdp) - min_qsa)).exp())
-                .sum::<f32>());
-        if result == 0.0 {
-            1e-6
-        } else {
-            result
-        }
+        let qs = mdp
+            .enumerate_actions()
+            .filter(|a| mdp.action_available(s, a))
+            .map(|at| self.vt.get_qsa_ssp(s, at, mdp))
+            .collect::<Vec<_>>();
+        boltzmann_probability(self.current_beta(), self.vt.get_qsa_ssp(s, a, mdp), &qs)
     }
 }
 
-impl<M: ActionAvailability + StateEnumerable + ActionEnumerable + PMass<f32> + Cost>
-    GetActionProbabilityMut<M::Action, M> for SoftmaxPolicy<ValueTable<M::State>>
+impl<M: ActionAvailability + ActionEnumerable, V: CostEstimatorMut<M>>
+    GetActionProbabilityMut<M::Action, M> for SoftmaxPolicy<V>
 {
     fn get_action_probability_mut(
         &mut self,
@@ -56,7 +156,60 @@ impl<M: ActionAvailability + StateEnumerable + ActionEnumerable + PMass<f32> + C
         a: &M::Action,
         mdp: &mut M,
     ) -> f32 {
-        self.get_action_probability(s, a, mdp)
+        let mut qs = vec![];
+        for a_id in 0..mdp.num_actions() {
+            let at = *mdp.id_to_action(a_id);
+            if mdp.action_available(s, &at) {
+                qs.push(self.vt.get_qsa_ssp_mut(s, &at, mdp));
+            }
+        }
+        let qsa = self.vt.get_qsa_ssp_mut(s, a, mdp);
+        boltzmann_probability(self.current_beta(), qsa, &qs)
+    }
+}
+
+impl<M: ActionAvailability + ActionEnumerable, V: CostEstimator<M>> GetAction<M::State, M>
+    for SoftmaxPolicy<V>
+{
+    fn get_action(&self, s: &M::State, mdp: &M, rng: &mut ThreadRng) -> Option<M::Action> {
+        let actions = mdp
+            .enumerate_actions()
+            .filter(|a| mdp.action_available(s, a))
+            .map(|a| (*a, self.get_action_probability(s, a, mdp)))
+            .collect::<Vec<_>>();
+        actions
+            .choose_weighted(rng, |(_, p)| *p)
+            .ok()
+            .map(|(a, _)| *a)
+    }
+}
+
+/// The schedule advances by one step per sampled action and restarts whenever
+/// the policy is queried at the initial state.
+impl<M: ActionAvailability + ActionEnumerable + InitialState, V: CostEstimatorMut<M>>
+    GetActionMut<M::State, M> for SoftmaxPolicy<V>
+{
+    fn get_action_mut(
+        &mut self,
+        s: &M::State,
+        mdp: &mut M,
+        rng: &mut ThreadRng,
+    ) -> Option<M::Action> {
+        if *s == mdp.initial_state() {
+            self.step = 0;
+        }
+        let mut actions = vec![];
+        for a_id in 0..mdp.num_actions() {
+            let a = *mdp.id_to_action(a_id);
+            if mdp.action_available(s, &a) {
+                actions.push((a, self.get_action_probability_mut(s, &a, mdp)));
+            }
+        }
+        self.advance();
+        actions
+            .choose_weighted(rng, |(_, p)| *p)
+            .ok()
+            .map(|(a, _)| *a)
     }
 }
 
@@ -66,14 +219,12 @@ impl<
     > BuildFrom<&'a M, SoftmaxPolicy<ValueTable<M::State>>> for SoftmaxPolicyBuilder
 {
     fn build_from(&self, mdp: &'a M) -> SoftmaxPolicy<ValueTable<M::State>> {
-        let vt = value_iteration_ssp(mdp);
-        SoftmaxPolicy::new(self.beta, vt)
+        let vt = self.solver.solve(mdp);
+        self.build_with(vt)
     }
 }
 
-impl<M: ActionAvailability + StateEnumerable + ActionEnumerable + PMass<f32> + Cost>
-    CostEstimator<M> for SoftmaxPolicy<ValueTable<M::State>>
-{
+impl<M: StatesActions, V: CostEstimator<M>> CostEstimator<M> for SoftmaxPolicy<V> {
     fn get_qsa_ssp(&self, s: &M::State, a: &M::Action, mdp: &M) -> f32 {
         self.vt.get_qsa_ssp(s, a, mdp)
     }
@@ -128,6 +279,7 @@ mod tests {
     use super::*;
     use crate::grid_world::GridWorldAction::*;
     use crate::grid_world::{GridWorldMDP, GridWorldState};
+    use crate::value_estimator::QValueTable;
     use crate::value_iteration::value_iteration_ssp;
     use assert_approx_eq::assert_approx_eq;
 
@@ -158,4 +310,64 @@ mod tests {
             err
         );
     }
+
+    #[test]
+    fn test_temperature_schedule() {
+        let linear = TemperatureSchedule::Linear {
+            final_beta: 3.0,
+            steps: 4,
+        };
+        assert_approx_eq!(1.0, linear.beta_at(1.0, 0));
+        assert_approx_eq!(2.0, linear.beta_at(1.0, 2));
+        assert_approx_eq!(3.0, linear.beta_at(1.0, 10));
+
+        let exponential = TemperatureSchedule::Exponential {
+            rate: 2.0,
+            final_beta: 5.0,
+        };
+        assert_approx_eq!(4.0, exponential.beta_at(1.0, 2));
+        assert_approx_eq!(5.0, exponential.beta_at(1.0, 3));
+    }
+
+    #[test]
+    fn test_softmax_over_cost_estimator() {
+        let mut mdp = GridWorldMDP::default();
+        let value_table = value_iteration_ssp(&mdp);
+        let q_value_table = QValueTable::from_value_table_ssp(&mdp, &value_table);
+        let s = GridWorldState::new(0, 0);
+
+        let mut softmax_policy = SoftmaxPolicyBuilder::new(1.0)
+            .set_schedule(TemperatureSchedule::Linear {
+                final_beta: 10.0,
+                steps: 1,
+            })
+            .build_with(q_value_table);
+        assert_approx_eq!(
+            0.40183,
+            softmax_policy.get_action_probability_mut(&s, &AttemptRight, &mut mdp),
+            1e-3
+        );
+
+        softmax_policy.advance();
+        assert!(softmax_policy.get_action_probability(&s, &AttemptRight, &mdp) > 0.9);
+    }
+
+    #[test]
+    fn test_softmax_schedule_restarts_each_episode() {
+        let mut mdp = GridWorldMDP::default();
+        let value_table = value_iteration_ssp(&mdp);
+        let mut softmax_policy = SoftmaxPolicyBuilder::new(1.0)
+            .set_schedule(TemperatureSchedule::Linear {
+                final_beta: 10.0,
+                steps: 4,
+            })
+            .build_with(value_table);
+        let mut rng = thread_rng();
+        let s0 = mdp.initial_state();
+
+        for _ in 0..10 {
+            softmax_policy.get_action_mut(&s0, &mut mdp, &mut rng);
+            assert_eq!(softmax_policy.current_beta(), 1.0 + 9.0 / 4.0);
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::mdp_traits::*;
-use crate::value_estimator::{CostEstimator, ValueEstimator};
+use crate::value_estimator::{CostEstimator, CostEstimatorMut, ValueEstimator};
 use crate::value_iteration::ValueTable;
 use core::fmt::Debug;
 use core::hash::Hash;
@@ -109,6 +109,17 @@ impl<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost> CostEstimator
     }
 }
 
+impl<M: ActionAvailability + ActionEnumerable + PMass<f32> + Cost> CostEstimatorMut<M>
+    for QValueTable<M::State, M::Action>
+{
+    fn get_value_ssp_mut(&mut self, s: &M::State, mdp: &mut M) -> f32 {
+        self.get_value_ssp(s, mdp)
+    }
+    fn get_qsa_ssp_mut(&mut self, s: &M::State, a: &M::Action, mdp: &mut M) -> f32 {
+        self.get_qsa_ssp(s, a, mdp)
+    }
+}
+
 impl<
         S: Eq + PartialEq + Debug + Clone + Hash,
         A: Eq + PartialEq + Debug + Clone + Hash,
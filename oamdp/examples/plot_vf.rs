@@ -19,16 +19,16 @@ use rand::thread_rng;
 
 fn main() {
     let color_map = HashMap::from([
-        (Some(BakerGridAction::NorthWest), "blue".to_string()),
-        (Some(BakerGridAction::SouthWest), "black".to_string()),
-        (Some(BakerGridAction::SouthEast), "cyan".to_string()),
-        (Some(BakerGridAction::NorthEast), "purple".to_string()),
-        (Some(BakerGridAction::North), "orange".to_string()),
-        (Some(BakerGridAction::West), "red".to_string()),
-        (Some(BakerGridAction::South), "grey".to_string()),
-        (Some(BakerGridAction::East), "olive".to_string()),
-        (Some(BakerGridAction::Stay), "pink".to_string()),
-        (None, "green".to_string()),
+        (Some(BakerGridAction::NorthWest), (0, 0, 255)),
+        (Some(BakerGridAction::SouthWest), (0, 0, 0)),
+        (Some(BakerGridAction::SouthEast), (0, 255, 255)),
+        (Some(BakerGridAction::NorthEast), (128, 0, 128)),
+        (Some(BakerGridAction::North), (255, 165, 0)),
+        (Some(BakerGridAction::West), (255, 0, 0)),
+        (Some(BakerGridAction::South), (128, 128, 128)),
+        (Some(BakerGridAction::East), (128, 128, 0)),
+        (Some(BakerGridAction::Stay), (255, 192, 203)),
+        (None, (0, 128, 0)),
     ]);
     let pair = baker_factory("Tiny2");
     let partial_mdp = pair.0;
@@ -58,7 +58,8 @@ fn main() {
                     0.0,
                     20.0,
                     &color_map,
-                );
+                )
+                .unwrap();
             } else {
                 RegularGridBeliefPoints::<AssocBeliefPointN<BakerGridAction, 2>, 2>::new(
                     n_bin_per_dim,
@@ -69,7 +70,8 @@ fn main() {
                     0.0,
                     20.0,
                     &color_map,
-                );
+                )
+                .unwrap();
             }
         }
     }
@@ -24,16 +24,16 @@ fn main() {
     println!("{:?}", goals);
 
     let color_map = HashMap::from([
-        (Some(BakerGridAction::NorthWest), "blue".to_string()),
-        (Some(BakerGridAction::SouthWest), "black".to_string()),
-        (Some(BakerGridAction::SouthEast), "cyan".to_string()),
-        (Some(BakerGridAction::NorthEast), "purple".to_string()),
-        (Some(BakerGridAction::North), "orange".to_string()),
-        (Some(BakerGridAction::West), "red".to_string()),
-        (Some(BakerGridAction::South), "grey".to_string()),
-        (Some(BakerGridAction::East), "olive".to_string()),
-        (Some(BakerGridAction::Stay), "pink".to_string()),
-        (None, "green".to_string()),
+        (Some(BakerGridAction::NorthWest), (0, 0, 255)),
+        (Some(BakerGridAction::SouthWest), (0, 0, 0)),
+        (Some(BakerGridAction::SouthEast), (0, 255, 255)),
+        (Some(BakerGridAction::NorthEast), (128, 0, 128)),
+        (Some(BakerGridAction::North), (255, 165, 0)),
+        (Some(BakerGridAction::West), (255, 0, 0)),
+        (Some(BakerGridAction::South), (128, 128, 128)),
+        (Some(BakerGridAction::East), (128, 128, 0)),
+        (Some(BakerGridAction::Stay), (255, 192, 203)),
+        (None, (0, 128, 0)),
     ]);
 
     let softmax_policy = SoftmaxPolicyBuilder::new(0.3);
@@ -65,7 +65,8 @@ fn main() {
                     0.0,
                     20.0,
                     &color_map,
-                );
+                )
+                .unwrap();
             } else {
                 RegularGridBeliefPoints::<AssocBeliefPointN<BakerGridAction, 2>, 2>::new(
                     n_bin_per_dim,
@@ -76,7 +77,8 @@ fn main() {
                     0.0,
                     20.0,
                     &color_map,
-                );
+                )
+                .unwrap();
             }
         }
     }
@@ -0,0 +1,194 @@
+use brtdp::{UpperBoundWithMDPMut, BRTDP};
+use mdp::heuristic::HeuristicWithMDPMut;
+use mdp::into_inner::Inner;
+use mdp::mdp_traits::*;
+use mdp::policy::policy_traits::GetActionMut;
+use mdp::value_iteration::ValueTable;
+use rand::rngs::ThreadRng;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::oamdp::{oamdp::OAMDP, BeliefState};
+use crate::oamdp_d::{VState, OAMDPD};
+use crate::traits::BeliefOverGoal;
+
+#[allow(non_camel_case_types)]
+pub struct BRTDP_OAMDP<
+    OM,
+    M: StatesActions,
+    A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+    H,
+    U,
+    const N: usize,
+> {
+    pub brtdp: BRTDP<VState<M::State, N>, H, U>,
+    pub oamdp_d: OAMDPD<OM, M, A, N>,
+    pub(crate) max_t: Option<usize>,
+}
+
+impl<
+        OM,
+        M: StatesActions,
+        A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+        H,
+        U,
+        const N: usize,
+    > BRTDP_OAMDP<OM, M, A, H, U, N>
+{
+    pub fn new(oamdp: OAMDP<OM, M, A, N>, h: H, u: U, k: usize) -> BRTDP_OAMDP<OM, M, A, H, U, N> {
+        let mut brtdp = BRTDP::new(h, u);
+        brtdp.ub = ValueTable::new(1e+8);
+        BRTDP_OAMDP {
+            brtdp,
+            oamdp_d: OAMDPD::new(oamdp, k),
+            max_t: None,
+        }
+    }
+
+    pub fn set_max_horizon(mut self, max_t: usize) -> Self {
+        self.max_t = Some(max_t);
+        self
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.brtdp.lb.value_table.len()
+    }
+
+    pub fn num_domain_states(&self) -> usize {
+        let mut hash_set = HashSet::new();
+        for vs in self.brtdp.lb.value_table.keys() {
+            hash_set.insert(vs.inner());
+        }
+        hash_set.len()
+    }
+}
+
+impl<
+        OM,
+        M: StatesActions,
+        A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+        H,
+        U,
+        const N: usize,
+    > BRTDP_OAMDP<OM, M, A, H, U, N>
+where
+    OAMDPD<OM, M, A, N>: InitialState
+        + StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability,
+    H: HeuristicWithMDPMut<OAMDPD<OM, M, A, N>>,
+    U: UpperBoundWithMDPMut<OAMDPD<OM, M, A, N>>,
+{
+    pub fn solve(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.brtdp.solve(&mut self.oamdp_d, rng, num_trials)
+    }
+
+    pub fn root_bounds(&mut self) -> (f32, f32) {
+        let s = self.oamdp_d.initial_state();
+        (
+            self.brtdp.get_lb_mut(&s, &mut self.oamdp_d),
+            self.brtdp.get_ub_mut(&s, &mut self.oamdp_d),
+        )
+    }
+
+    pub fn root_gap(&mut self) -> f32 {
+        let s = self.oamdp_d.initial_state();
+        self.brtdp.gap_mut(&s, &mut self.oamdp_d)
+    }
+}
+
+impl<
+        OM,
+        M: StatesActions,
+        A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+        H,
+        U,
+        const N: usize,
+    > BRTDP_OAMDP<OM, M, A, H, U, N>
+where
+    OAMDPD<OM, M, A, N>: StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + ActionEnumerable
+        + ActionAvailability,
+    H: HeuristicWithMDPMut<OAMDPD<OM, M, A, N>>,
+    U: UpperBoundWithMDPMut<OAMDPD<OM, M, A, N>>,
+{
+    pub(crate) fn best_action_mut(
+        &mut self,
+        bs: &BeliefState<M::State, N>,
+        rng: &mut ThreadRng,
+    ) -> Option<A> {
+        let pairs = self
+            .oamdp_d
+            .translator
+            .get_corner_and_lambdas(&bs.get_belief_over_goal());
+
+        if let Ok(pair) = pairs.choose_weighted(rng, |(_v, w)| *w) {
+            let vs = VState::new(bs.inner(), pair.0);
+            self.brtdp.best_ub_action_mut(&vs, &mut self.oamdp_d)
+        } else {
+            panic!("{:?}", pairs);
+        }
+    }
+}
+
+impl<
+        OM,
+        M: StatesActions,
+        A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+        H,
+        U,
+        const N: usize,
+    > GetActionMut<BeliefState<M::State, N>, OAMDP<OM, M, A, N>> for BRTDP_OAMDP<OM, M, A, H, U, N>
+where
+    OAMDPD<OM, M, A, N>: StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>,
+    H: HeuristicWithMDPMut<OAMDPD<OM, M, A, N>>,
+    U: UpperBoundWithMDPMut<OAMDPD<OM, M, A, N>>,
+{
+    fn get_action_mut(
+        &mut self,
+        bs: &BeliefState<M::State, N>,
+        _oamdp: &mut OAMDP<OM, M, A, N>,
+        rng: &mut ThreadRng,
+    ) -> Option<A> {
+        self.best_action_mut(bs, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::brtdp::DomainPolicyUpperBound;
+    use crate::domains::baker_grid::BakerOAMDPBuilder;
+    use mdp::heuristic::ZeroHeuristic;
+    use rand::thread_rng;
+
+    #[test]
+    fn test_brtdp_oamdp_bounds() {
+        let oamdp = BakerOAMDPBuilder::<3>::new(101).set_horizon(13).build().mdp;
+        let u = DomainPolicyUpperBound::new(&oamdp);
+        let mut brtdp = BRTDP_OAMDP::new(oamdp, ZeroHeuristic {}, u, 2).set_max_horizon(13);
+        let mut rng = thread_rng();
+
+        let (lb, ub) = brtdp.root_bounds();
+        assert!(lb <= ub);
+        let initial_gap = brtdp.root_gap();
+
+        brtdp.solve(20, &mut rng);
+        let (lb, ub) = brtdp.root_bounds();
+        assert!(lb <= ub + 1e-3);
+        assert!(brtdp.root_gap() <= initial_gap + 1e-3);
+    }
+}
@@ -0,0 +1,137 @@
+use brtdp::UpperBoundWithMDPMut;
+use mdp::into_inner::Inner;
+use mdp::mdp_traits::*;
+use mdp::value_estimator::CostEstimator;
+use mdp::value_iteration::{value_iteration_ssp, ValueTable};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::belief_cost_function::Objective;
+use crate::oamdp::oamdp::OAMDP;
+
+/// Upper bound of an OAMDP value given by following the optimal policy of the
+/// underlying domain while paying the maximum belief cost at every step.
+/// It assumes that every domain action can be taken without a communication cost.
+#[derive(Debug)]
+pub struct DomainPolicyUpperBound<S: Eq + PartialEq + Debug + Clone + Hash> {
+    vt: ValueTable<S>,
+}
+
+impl<S: Eq + PartialEq + Debug + Clone + Hash + Copy> DomainPolicyUpperBound<S> {
+    pub fn new<OM, M, A: Eq + Debug + Hash + Copy, const N: usize>(
+        oamdp: &OAMDP<OM, M, A, N>,
+    ) -> DomainPolicyUpperBound<S>
+    where
+        M: StatesActions<State = S>
+            + StateEnumerable
+            + ActionEnumerable
+            + ActionAvailability
+            + PMass<f32>
+            + Cost
+            + IsTerminal,
+    {
+        let max_b_cost = oamdp.distance_measure.max_b_cost();
+        let (c, d) = match oamdp.objective {
+            Objective::BeliefCostOnly => (1.0, 0.0),
+            Objective::LinearCombination(c, d) => (c, d),
+        };
+        let vt = policy_upper_bound(&oamdp.mdp, c * max_b_cost, d);
+
+        DomainPolicyUpperBound { vt }
+    }
+}
+
+fn policy_upper_bound<
+    M: StateEnumerable + ActionEnumerable + ActionAvailability + PMass<f32> + Cost + IsTerminal,
+>(
+    mdp: &M,
+    b_cost: f32,
+    scale: f32,
+) -> ValueTable<M::State> {
+    let err = 1e-3;
+    let dead_end_value = 1e+8;
+    let domain_vt = value_iteration_ssp(mdp);
+    let mut policy = Vec::new();
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) || domain_vt.get_value(s) >= dead_end_value {
+            continue;
+        }
+        let mut best = None;
+        for a in mdp.enumerate_actions() {
+            if !mdp.action_available(s, a) {
+                continue;
+            }
+            let qsa = domain_vt.get_qsa_ssp(s, a, mdp);
+            match best {
+                Some((_, best_qsa)) if best_qsa <= qsa => {}
+                _ => best = Some((*a, qsa)),
+            }
+        }
+        if let Some((a, _)) = best {
+            policy.push((*s, a));
+        }
+    }
+
+    // Evaluating the policy from above keeps every iterate an upper bound of
+    // its value, so stopping at any residual is safe.
+    let mut vt = ValueTable::new(dead_end_value);
+    for s in mdp.enumerate_states() {
+        if mdp.is_terminal(s) {
+            vt.set_value(s, 0.0);
+        }
+    }
+    loop {
+        let mut max_residual: f32 = 0.0;
+        for (s, a) in &policy {
+            let mut value = b_cost + scale * mdp.cost(s, a);
+            for (ss, p) in mdp.p_mass(s, a) {
+                value += p * vt.get_value(&ss);
+            }
+            let value = value.min(dead_end_value);
+            max_residual = max_residual.max((vt.get_value(s) - value).abs());
+            vt.set_value(s, value);
+        }
+        if max_residual < err {
+            break;
+        }
+    }
+
+    vt
+}
+
+impl<S: Eq + PartialEq + Debug + Clone + Hash + Copy, M: StatesActions + IsTerminal>
+    UpperBoundWithMDPMut<M> for DomainPolicyUpperBound<S>
+where
+    M::State: Inner<Result = S>,
+{
+    fn u_with_mut(&mut self, s: &M::State, mdp: &mut M) -> f32 {
+        if mdp.is_terminal(s) {
+            0.0
+        } else {
+            self.vt.get_value(&s.inner())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::rtdp::{RTDPTrait, RTDP_OAMDP};
+    use crate::domains::baker_grid::BakerOAMDPBuilder;
+    use mdp::heuristic::ZeroHeuristic;
+    use rand::thread_rng;
+
+    #[test]
+    fn test_domain_policy_upper_bound_above_lrtdp_value() {
+        let oamdp = BakerOAMDPBuilder::<3>::new(101).build().mdp;
+        let mut u = DomainPolicyUpperBound::new(&oamdp);
+        let mut rtdp = RTDP_OAMDP::new(oamdp, ZeroHeuristic {}, 2);
+        rtdp.lrtdp(0, &mut thread_rng());
+
+        assert!(!rtdp.rtdp.vt.value_table.is_empty());
+        for (vs, v) in rtdp.rtdp.vt.value_table.iter() {
+            let ub = u.u_with_mut(vs, &mut rtdp.oamdp_d);
+            assert!(ub + 1e-3 >= *v, "{:?}: {} < {}", vs, ub, v);
+        }
+    }
+}
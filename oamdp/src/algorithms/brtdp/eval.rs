@@ -0,0 +1,58 @@
+use brtdp::UpperBoundWithMDPMut;
+use mdp::heuristic::HeuristicWithMDPMut;
+use mdp::into_inner::Inner;
+use mdp::mdp_traits::*;
+use rand::rngs::ThreadRng;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::oamdp::oamdp::OAMDP;
+use crate::oamdp::BeliefState;
+use crate::oamdp_d::{VState, OAMDPD};
+
+use super::BRTDP_OAMDP;
+
+impl<
+        OM,
+        M: StatesActions,
+        A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+        H,
+        U,
+        const N: usize,
+    > Eval for BRTDP_OAMDP<OM, M, A, H, U, N>
+where
+    M: IsTerminal,
+    OAMDPD<OM, M, A, N>: StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + InitialState
+        + GetNextStateMut
+        + Cost,
+    H: HeuristicWithMDPMut<OAMDPD<OM, M, A, N>>,
+    U: UpperBoundWithMDPMut<OAMDPD<OM, M, A, N>>,
+{
+    fn eval(&mut self, rng: &mut ThreadRng) -> f32 {
+        let mut cumulative_cost = 0.0;
+        let mut bs = self.oamdp_d.oamdp.initial_state();
+        let mut t = 0;
+        while !self.oamdp_d.oamdp.mdp.is_terminal(&bs.inner()) {
+            if let Some(max_t) = self.max_t {
+                if t >= max_t {
+                    break;
+                }
+            }
+            match self.best_action_mut(&bs, rng) {
+                Some(a) => {
+                    cumulative_cost += self.oamdp_d.oamdp.cost(&bs, &a);
+                    bs = self.oamdp_d.oamdp.get_next_state_mut(&bs, &a, rng);
+                }
+                None => break,
+            }
+            t += 1;
+        }
+        cumulative_cost
+    }
+}
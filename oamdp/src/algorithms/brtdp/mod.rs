@@ -0,0 +1,6 @@
+mod brtdp_oamdp;
+mod domain_policy_upper_bound;
+mod eval;
+
+pub use self::brtdp_oamdp::BRTDP_OAMDP;
+pub use self::domain_policy_upper_bound::DomainPolicyUpperBound;
@@ -40,6 +40,7 @@ where
         println!("starting {}th", t);
         let residual = one_iteration(oamdp, &mut vf);
         println!("iteration {} residual {}", t, residual);
+        vf.residuals.push(residual);
         if residual < 0.001 {
             break;
         }
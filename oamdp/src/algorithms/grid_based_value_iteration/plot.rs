@@ -1,62 +1,124 @@
 use crate::algorithms::assoc_belief_point::AssocBeliefPointN;
 use crate::algorithms::belief_point::BeliefPoint;
+use crate::algorithms::grid_value_function_ssp::GridValueFunctionSSP;
 use crate::algorithms::regular_grid_belief_points::RegularGridBeliefPoints;
+use crate::plotting::{LineChart, Rgb, Series, SimplexHeatmap};
 use core::fmt::Debug;
-use gnuplot::*;
+use ordered_float::NotNan;
 use std::collections::HashMap;
 use std::hash::Hash;
-use std::path::Path;
+use std::io;
+
+type ActionSeries<A> = (Option<A>, Vec<(f32, f32)>);
 
 impl<A: Copy + Debug + Hash + Eq> RegularGridBeliefPoints<AssocBeliefPointN<A, 2>, 2> {
+    /// Value against the belief in the first goal, one series per greedy
+    /// action, sorted by action.
+    fn points_per_action(&self) -> Vec<ActionSeries<A>> {
+        let mut per_action = HashMap::<Option<A>, Vec<(f32, f32)>>::new();
+        for b in self.grid.values() {
+            per_action
+                .entry(b.assoc)
+                .or_default()
+                .push((b.inner()[0].into_inner(), b.assoc_value().into_inner()));
+        }
+        let mut per_action = per_action.into_iter().collect::<Vec<_>>();
+        per_action.sort_by_key(|(a, _)| format!("{:?}", a));
+        for (_, points) in per_action.iter_mut() {
+            points.sort_by(|p, q| p.partial_cmp(q).unwrap());
+        }
+        per_action
+    }
+
+    /// Scatter plot of the value function with one color per greedy action.
+    /// Actions missing from `color_map` fall back to the default palette.
     pub fn plot(
         &self,
         filename: &str,
         x_label: &str,
-        y_min: f64,
-        y_max: f64,
-        color_map: &HashMap<Option<A>, String>,
-    ) {
-        let mut per_action_x = HashMap::<Option<A>, Vec<f32>>::new();
-        let mut per_action_y = HashMap::<Option<A>, Vec<f32>>::new();
-        //         let mut x = vec![];
-        //         let mut y = vec![];
-        for b in self.grid.values() {
-            if let Some(x) = per_action_x.get_mut(&b.assoc) {
-                x.push(b.inner()[0].into_inner());
-            } else {
-                per_action_x.insert(b.assoc, vec![b.inner()[0].into_inner()]);
-            }
-
-            if let Some(y) = per_action_y.get_mut(&b.assoc) {
-                y.push(b.assoc_value().into_inner());
-            } else {
-                per_action_y.insert(b.assoc, vec![b.assoc_value().into_inner()]);
-            }
-        }
+        y_min: f32,
+        y_max: f32,
+        color_map: &HashMap<Option<A>, Rgb>,
+    ) -> io::Result<()> {
+        self.points_per_action()
+            .into_iter()
+            .fold(
+                LineChart::new()
+                    .set_x_range(0.0, 1.0)
+                    .set_y_range(y_min, y_max)
+                    .set_x_label(x_label)
+                    .set_y_label("Value"),
+                |chart, (a, points)| {
+                    let series = Series::new(&format!("{:?}", a), points).set_scatter();
+                    chart.add_series(match color_map.get(&a) {
+                        Some(c) => series.set_color(*c),
+                        None => series,
+                    })
+                },
+            )
+            .save(filename)
+    }
 
-        let mut fg = Figure::new();
-        let axes2d = fg
-            .axes2d()
-            .set_x_range(Fix(0.0), Fix(1.0))
-            .set_y_range(Fix(y_min), Fix(y_max))
-            .set_x_ticks(Some((Auto, 0)), &[], &[Font("Arial", 18.0)])
-            .set_y_ticks(Some((Auto, 0)), &[], &[Font("Arial", 18.0)])
-            .set_x_label(x_label, &[Font("", 18.0)])
-            .set_y_label("Value", &[Font("", 18.0)]);
-
-        for a in per_action_x.keys() {
-            let x = per_action_x.get(a).unwrap();
-            let y = per_action_y.get(a).unwrap();
-            if let Some(c) = color_map.get(a) {
-                axes2d.points(x, y, &[Caption(&format!("{:?}", a)), Color(c)]);
-            } else {
-                panic!("{:?}", a);
-            }
-        }
-        //         axes2d.points(&x, &y, &[]);
+    /// The chart drawn by `plot` with the default palette and an automatic
+    /// value range.
+    pub fn value_chart(&self, x_label: &str) -> LineChart {
+        self.points_per_action().into_iter().fold(
+            LineChart::new()
+                .set_x_range(0.0, 1.0)
+                .set_x_label(x_label)
+                .set_y_label("Value"),
+            |chart, (a, points)| {
+                chart.add_series(Series::new(&format!("{:?}", a), points).set_scatter())
+            },
+        )
+    }
+}
+
+impl<S: Copy + Hash + Eq + Debug, A: Copy + Debug + Hash + Eq>
+    GridValueFunctionSSP<S, AssocBeliefPointN<A, 3>, 3>
+{
+    /// Interpolated value over the belief simplex at domain state `s`.
+    pub fn save_simplex_heatmap(
+        &self,
+        s: &S,
+        heatmap: &SimplexHeatmap,
+        path: &str,
+    ) -> io::Result<()> {
+        let grid = self.table.get(s).expect("Unknown domain state");
+        heatmap.save(path, |b| {
+            grid.get_value_convex_interpolation(&b.map(|p| NotNan::new(p).unwrap()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::baker_grid::BakerGridState;
+    use mdp::mdp_traits::Build;
+
+    use crate::algorithms::grid_based_value_iteration::grid_based_value_iteration_ssp;
+    use crate::domains::baker_grid::BakerOAMDPBuilder;
+    use crate::plotting::{convergence_chart, SimplexHeatmap};
+
+    #[test]
+    fn test_plot_grid_value_function() {
+        let mut oamdp = BakerOAMDPBuilder::<3>::new(101).build().mdp;
+        let vf = grid_based_value_iteration_ssp(&mut oamdp, 4);
+        assert!(!vf.residuals.is_empty());
+
+        let dir = std::env::temp_dir();
+        let heatmap = SimplexHeatmap::new()
+            .set_vertex_labels(["A", "B", "C"])
+            .set_resolution(8);
+        let path = dir.join("oamdp_test_simplex_heatmap.svg");
+        vf.save_simplex_heatmap(&BakerGridState::new(0, 0), &heatmap, path.to_str().unwrap())
+            .unwrap();
+        assert!(std::fs::read_to_string(path).unwrap().contains("<polygon"));
 
-        let fpath = Path::new(filename);
-        fg.set_terminal("png", &*fpath.to_string_lossy());
-        fg.show().unwrap();
+        let path = dir.join("oamdp_test_convergence.png");
+        convergence_chart(&vf.residuals)
+            .save(path.to_str().unwrap())
+            .unwrap();
+        assert!(path.exists());
     }
 }
@@ -6,6 +6,7 @@ use crate::traits::BeliefOverGoal;
 use mdp::into_inner::Inner;
 use mdp::mdp_traits::*;
 use mdp::policy::policy_traits::GetAction;
+use mdp::value_estimator::{CostEstimator, CostEstimatorMut};
 use ordered_float::NotNan;
 use rand::seq::SliceRandom;
 use std::collections::HashMap;
@@ -26,6 +27,8 @@ pub struct GridValueFunctionSSP<
     const N: usize,
 > {
     pub table: HashMap<S, RegularGridBeliefPoints<B, N>>,
+    /// Bellman residual of each sweep, for convergence plots.
+    pub residuals: Vec<f32>,
     policy_type: PolicyType,
 }
 
@@ -35,6 +38,7 @@ impl<S: Copy + Hash + Eq, B: BeliefPoint<N> + Clone + Copy + Debug, const N: usi
     pub fn new(table: HashMap<S, RegularGridBeliefPoints<B, N>>) -> Self {
         Self {
             table,
+            residuals: vec![],
             policy_type: PolicyType::OneStepLookAhead,
         }
     }
@@ -219,3 +223,76 @@ where
         }
     }
 }
+
+impl<OM, S, A, M, const N: usize> CostEstimator<OAMDP<OM, M, A, N>>
+    for GridValueFunctionSSP<S, AssocBeliefPointN<A, N>, N>
+where
+    S: Copy + Hash + Eq + Debug,
+    A: Eq + PartialEq + Hash + Debug + Clone + Copy,
+    M: StatesActions<State = S>,
+    OAMDP<OM, M, A, N>:
+        StatesActions<State = BeliefState<S, N>, Action = A> + PMass<f32> + Cost + ActionEnumerable,
+{
+    fn get_qsa_ssp(&self, s: &BeliefState<S, N>, a: &A, mdp: &OAMDP<OM, M, A, N>) -> f32 {
+        self.qsa_ssp(&s.inner(), &s.get_belief_over_goal(), a, mdp)
+    }
+
+    fn get_value_ssp(&self, s: &BeliefState<S, N>, _mdp: &OAMDP<OM, M, A, N>) -> f32 {
+        self.get_value(s)
+    }
+}
+
+impl<OM, S, A, M, const N: usize> CostEstimatorMut<OAMDP<OM, M, A, N>>
+    for GridValueFunctionSSP<S, AssocBeliefPointN<A, N>, N>
+where
+    S: Copy + Hash + Eq + Debug,
+    A: Eq + PartialEq + Hash + Debug + Clone + Copy,
+    M: StatesActions<State = S>,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<S, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + ActionEnumerable,
+{
+    fn get_qsa_ssp_mut(
+        &mut self,
+        s: &BeliefState<S, N>,
+        a: &A,
+        mdp: &mut OAMDP<OM, M, A, N>,
+    ) -> f32 {
+        self.qsa_ssp_mut(&s.inner(), &s.get_belief_over_goal(), a, mdp)
+    }
+
+    fn get_value_ssp_mut(&mut self, s: &BeliefState<S, N>, _mdp: &mut OAMDP<OM, M, A, N>) -> f32 {
+        self.get_value(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::grid_based_value_iteration::grid_based_value_iteration_ssp;
+    use crate::domains::baker_grid::BakerOAMDPBuilder;
+    use assert_approx_eq::assert_approx_eq;
+    use mdp::policy::policy_traits::GetActionProbability;
+    use mdp::policy::softmax_policy::SoftmaxPolicy;
+
+    #[test]
+    fn test_softmax_policy_on_grid_value_function() {
+        let mut oamdp = BakerOAMDPBuilder::<3>::new(101).build().mdp;
+        let vf = grid_based_value_iteration_ssp(&mut oamdp, 4);
+        let s = oamdp.initial_state();
+        let best = vf.get_action(&s, &oamdp, &mut rand::thread_rng()).unwrap();
+
+        let policy = SoftmaxPolicy::new(1.0, vf);
+        let probs = oamdp
+            .enumerate_actions()
+            .map(|a| (*a, policy.get_action_probability(&s, a, &oamdp)))
+            .collect::<Vec<_>>();
+        assert_approx_eq!(probs.iter().map(|(_, p)| p).sum::<f32>(), 1.0, 1e-3);
+        let (most_likely, _) = probs
+            .iter()
+            .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap())
+            .unwrap();
+        assert_eq!(*most_likely, best);
+    }
+}
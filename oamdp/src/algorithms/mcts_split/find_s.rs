@@ -9,12 +9,13 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSMA<M, P> {
         let s_node = self.arena.get_node(id);
         let m_node = &s_node.children[m_id];
         let a_node = &m_node.children[a_id];
-        for s_id in a_node.children.iter() {
-            if self.arena.get_node(*s_id).assoc == s {
-                return Some(*s_id);
-            }
-        }
-        None
+        self.node_sharing.find_s(
+            &s,
+            a_node
+                .children
+                .iter()
+                .map(|s_id| (*s_id, self.arena.get_node(*s_id).assoc)),
+        )
     }
 }
 
@@ -24,11 +25,12 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSAM<M, P> {
         let a_node = &s_node.children[a_id];
         let m_node = &a_node.children[m_id];
 
-        for s_id in m_node.children.iter() {
-            if self.arena.get_node(*s_id).assoc == s {
-                return Some(*s_id);
-            }
-        }
-        None
+        self.node_sharing.find_s(
+            &s,
+            m_node
+                .children
+                .iter()
+                .map(|s_id| (*s_id, self.arena.get_node(*s_id).assoc)),
+        )
     }
 }
@@ -1,4 +1,6 @@
-use mcts::{BackupOperator, Budget, MCTSTrait, RunEpisode, SetMCTSParams};
+use mcts::{
+    BackupOperator, Budget, EpisodeReport, MCTSTrait, NodeSharing, RunEpisode, SetMCTSParams,
+};
 use mdp::{
     arena::Arena,
     episode_runner::monte_carlo_evaluation,
@@ -23,6 +25,8 @@ pub struct MCTSAM<M: StatesActions + DomainAction + Message, P> {
     pub(crate) budget: Budget,
     pub(crate) backup_operator: BackupOperator,
     pub(crate) lookahead: Option<usize>,
+    pub(crate) node_sharing: NodeSharing<M::State>,
+    pub(crate) episode_report: Option<EpisodeReport<M::State>>,
 }
 
 impl<M: StatesActions + DomainAction + Message, P> SetMCTSParams for MCTSAM<M, P> {
@@ -67,6 +71,8 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSAM<M, P> {
             budget: Budget::NumIterations(1000),
             backup_operator: BackupOperator::MonteCarlo,
             lookahead: None,
+            node_sharing: NodeSharing::ClosedLoop,
+            episode_report: None,
         };
 
         mcts.add_state_node(mcts.mdp.initial_state());
@@ -93,6 +99,16 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSAM<M, P> {
         self
     }
 
+    pub fn set_node_sharing(mut self, node_sharing: NodeSharing<M::State>) -> MCTSAM<M, P> {
+        self.node_sharing = node_sharing;
+        self
+    }
+
+    pub fn set_episode_report(mut self, episode_report: EpisodeReport<M::State>) -> MCTSAM<M, P> {
+        self.episode_report = Some(episode_report);
+        self
+    }
+
     pub(crate) fn add_state_node(&mut self, s: M::State) -> usize {
         let next_id = self.arena.next_id();
 
@@ -144,13 +160,22 @@ where
     }
 
     fn expand_action_node(&mut self, s_id: usize, a_id: usize) {
+        let s = self.arena.get_node(s_id).assoc;
+        let a = self.arena.get_node(s_id).children[a_id].assoc;
         for m in self.mdp.enumerate_message() {
-            self.arena.get_node_mut(s_id).children[a_id].add_child(*m, 0.0);
+            if self.mdp.action_available(&s, &M::Action::from((a, *m))) {
+                self.arena.get_node_mut(s_id).children[a_id].add_child(*m, 0.0);
+            }
         }
     }
 
-    pub(crate) fn expand_recursive_state(&mut self, s_id: usize, rng: &mut ThreadRng) -> f32 {
-        if self.mdp.is_terminal(&self.arena.get_node(s_id).assoc) {
+    pub(crate) fn expand_recursive_state(
+        &mut self,
+        s_id: usize,
+        s: M::State,
+        rng: &mut ThreadRng,
+    ) -> f32 {
+        if self.mdp.is_terminal(&s) {
             let s_node = self.arena.get_node_mut(s_id);
             s_node.num_visited += 1;
 
@@ -161,7 +186,7 @@ where
             let cost = if self.num_rollouts > 0 {
                 let mut runner = self
                     .base_line_policy
-                    .into_eval_mut(self.arena.get_node(s_id).assoc, &mut self.mdp)
+                    .into_eval_mut(s, &mut self.mdp)
                     .set_max_horizon(self.lookahead);
                 let cost = monte_carlo_evaluation(&mut runner, rng, self.num_rollouts);
                 cost
@@ -182,7 +207,7 @@ where
                 .expect("no action node selected");
             unsafe {
                 let self_p = self as *mut Self;
-                let nv = (*self_p).expand_recursive_action(s_id, a_id, rng);
+                let nv = (*self_p).expand_recursive_action(s_id, s, a_id, rng);
                 (*self_p).update_state_node(s_id, a_id, nv);
                 nv
             }
@@ -192,6 +217,7 @@ where
     pub(crate) fn expand_recursive_action(
         &mut self,
         s_id: usize,
+        s: M::State,
         a_id: usize,
         rng: &mut ThreadRng,
     ) -> f32 {
@@ -199,10 +225,9 @@ where
             self.expand_action_node(s_id, a_id);
 
             let cost = if self.num_rollouts > 0 {
-                let s = &self.arena.get_node(s_id).assoc;
                 let mut joint_a = self
                     .base_line_policy
-                    .get_action_mut(s, &mut self.mdp, rng)
+                    .get_action_mut(&s, &mut self.mdp, rng)
                     .unwrap();
                 joint_a.set(self.arena.get_node(s_id).children[a_id].assoc);
                 let ss = self.mdp.get_next_state_mut(&s, &joint_a, rng);
@@ -227,7 +252,6 @@ where
             let m_id = self.arena.get_node(s_id).children[a_id]
                 .best_and_node_ucb(self.c)
                 .expect("no action node selected");
-            let s = self.arena.get_node(s_id).assoc;
             let message = self.arena.get_node(s_id).children[a_id].children[m_id].a;
             let a = M::Action::from((self.arena.get_node(s_id).children[a_id].assoc, message));
             let ss = self.mdp.get_next_state_mut(&s, &a, rng);
@@ -236,7 +260,7 @@ where
                 let self_p = self as *mut Self;
                 if let Some(ss_id) = (*self_p).find_s(s_id, a_id, m_id, ss) {
                     let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                    let future_r = (*self_p).expand_recursive_state(ss_id, rng);
+                    let future_r = (*self_p).expand_recursive_state(ss_id, ss, rng);
                     (*self_p).update_action_node(s_id, a_id, m_id, r, future_r);
                     r + future_r
                 } else {
@@ -247,7 +271,7 @@ where
                         .push(ss_id);
 
                     let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                    let future_r = (*self_p).expand_recursive_state(ss_id, rng);
+                    let future_r = (*self_p).expand_recursive_state(ss_id, ss, rng);
                     (*self_p).update_action_node(s_id, a_id, m_id, r, future_r);
                     r + future_r
                 }
@@ -261,6 +285,7 @@ mod tests {
 
     use mdp::baker_grid::BakerGridAction::*;
     use mdp::mdp_traits::Build;
+    use mdp::mdp_traits::InitialState;
     use mdp::policy::random_from_candidates_policy::RandomFromCandidatesPolicy;
     use rand::thread_rng;
 
@@ -286,19 +311,20 @@ mod tests {
 
         let mut rng = thread_rng();
         let mut mcts = MCTSAM::new(oamdp, policy);
-        mcts.expand_recursive_state(0, &mut rng);
+        let s = mcts.mdp.initial_state();
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         mcts.dump();
     }
 }
@@ -1,4 +1,6 @@
-use mcts::{BackupOperator, Budget, MCTSTrait, RunEpisode, SetMCTSParams};
+use mcts::{
+    BackupOperator, Budget, EpisodeReport, MCTSTrait, NodeSharing, RunEpisode, SetMCTSParams,
+};
 use mdp::episode_runner::monte_carlo_evaluation;
 use mdp::mdp_traits::*;
 use mdp::{arena::Arena, policy::policy_traits::GetActionMut};
@@ -17,6 +19,8 @@ pub struct MCTSMA<M: StatesActions + DomainAction + Message, P> {
     pub(crate) budget: Budget,
     pub(crate) backup_operator: BackupOperator,
     pub(crate) lookahead: Option<usize>,
+    pub(crate) node_sharing: NodeSharing<M::State>,
+    pub(crate) episode_report: Option<EpisodeReport<M::State>>,
 }
 
 impl<M: StatesActions + DomainAction + Message, P> SetMCTSParams for MCTSMA<M, P> {
@@ -61,6 +65,8 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSMA<M, P> {
             budget: Budget::NumIterations(1000),
             backup_operator: BackupOperator::MonteCarlo,
             lookahead: None,
+            node_sharing: NodeSharing::ClosedLoop,
+            episode_report: None,
         };
 
         mcts.add_state_node(mcts.mdp.initial_state());
@@ -87,6 +93,16 @@ impl<M: StatesActions + InitialState + DomainAction + Message, P> MCTSMA<M, P> {
         self
     }
 
+    pub fn set_node_sharing(mut self, node_sharing: NodeSharing<M::State>) -> MCTSMA<M, P> {
+        self.node_sharing = node_sharing;
+        self
+    }
+
+    pub fn set_episode_report(mut self, episode_report: EpisodeReport<M::State>) -> MCTSMA<M, P> {
+        self.episode_report = Some(episode_report);
+        self
+    }
+
     pub(crate) fn add_state_node(&mut self, s: M::State) -> usize {
         let next_id = self.arena.next_id();
 
@@ -131,20 +147,40 @@ where
     M::Action: From<(M::DomainAction, M::Message)> + Set<M::Message>,
 {
     fn expand_state_node(&mut self, id: usize) {
-        for (m_id, m) in self.mdp.enumerate_message().enumerate() {
-            let child = IntermediateNode::new(*m, m_id, id);
+        let s = self.arena.get_node(id).assoc;
+        let messages = self
+            .mdp
+            .enumerate_message()
+            .filter(|m| {
+                self.mdp
+                    .enumerate_domain_actions()
+                    .any(|a| self.mdp.action_available(&s, &M::Action::from((*a, **m))))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for (m_id, m) in messages.into_iter().enumerate() {
+            let child = IntermediateNode::new(m, m_id, id);
             self.arena.get_node_mut(id).add_child(child);
         }
     }
 
     fn expand_message_node(&mut self, s_id: usize, m_id: usize) {
+        let s = self.arena.get_node(s_id).assoc;
+        let m = self.arena.get_node(s_id).children[m_id].assoc;
         for a in self.mdp.enumerate_domain_actions() {
-            self.arena.get_node_mut(s_id).children[m_id].add_child(*a, 0.0);
+            if self.mdp.action_available(&s, &M::Action::from((*a, m))) {
+                self.arena.get_node_mut(s_id).children[m_id].add_child(*a, 0.0);
+            }
         }
     }
 
-    pub(crate) fn expand_recursive_state(&mut self, s_id: usize, rng: &mut ThreadRng) -> f32 {
-        if self.mdp.is_terminal(&self.arena.get_node(s_id).assoc) {
+    pub(crate) fn expand_recursive_state(
+        &mut self,
+        s_id: usize,
+        s: M::State,
+        rng: &mut ThreadRng,
+    ) -> f32 {
+        if self.mdp.is_terminal(&s) {
             let s_node = self.arena.get_node_mut(s_id);
             s_node.num_visited += 1;
 
@@ -156,7 +192,7 @@ where
             let cost = if self.num_rollouts > 0 {
                 let mut runner = self
                     .base_line_policy
-                    .into_eval_mut(self.arena.get_node(s_id).assoc, &mut self.mdp)
+                    .into_eval_mut(s, &mut self.mdp)
                     .set_max_horizon(self.lookahead);
                 let cost = monte_carlo_evaluation(&mut runner, rng, self.num_rollouts);
                 cost
@@ -177,7 +213,7 @@ where
                 .expect("no message node selected");
             unsafe {
                 let self_p = self as *mut Self;
-                let nv = (*self_p).expand_recursive_message(s_id, m_id, rng);
+                let nv = (*self_p).expand_recursive_message(s_id, s, m_id, rng);
                 (*self_p).update_state_node(s_id, m_id, nv);
                 nv
             }
@@ -187,6 +223,7 @@ where
     pub(crate) fn expand_recursive_message(
         &mut self,
         s_id: usize,
+        s: M::State,
         m_id: usize,
         rng: &mut ThreadRng,
     ) -> f32 {
@@ -194,10 +231,9 @@ where
             self.expand_message_node(s_id, m_id);
 
             let cost = if self.num_rollouts > 0 {
-                let s = &self.arena.get_node(s_id).assoc;
                 let mut joint_a = self
                     .base_line_policy
-                    .get_action_mut(s, &mut self.mdp, rng)
+                    .get_action_mut(&s, &mut self.mdp, rng)
                     .unwrap();
                 joint_a.set(self.arena.get_node(s_id).children[m_id].assoc);
                 let ss = self.mdp.get_next_state_mut(&s, &joint_a, rng);
@@ -222,7 +258,6 @@ where
             let a_id = self.arena.get_node(s_id).children[m_id]
                 .best_and_node_ucb(self.c)
                 .expect("no action node selected");
-            let s = self.arena.get_node(s_id).assoc;
             let domain_a = self.arena.get_node(s_id).children[m_id].children[a_id].a;
             let a = M::Action::from((domain_a, self.arena.get_node(s_id).children[m_id].assoc));
             let ss = self.mdp.get_next_state_mut(&s, &a, rng);
@@ -231,7 +266,7 @@ where
                 let self_p = self as *mut Self;
                 if let Some(ss_id) = (*self_p).find_s(s_id, m_id, a_id, ss) {
                     let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                    let future_r = (*self_p).expand_recursive_state(ss_id, rng);
+                    let future_r = (*self_p).expand_recursive_state(ss_id, ss, rng);
                     assert!(r <= 0.0);
                     assert!(future_r <= 0.0);
 
@@ -246,7 +281,7 @@ where
                         .push(ss_id);
 
                     let r = (-1.0) * (*self_p).mdp.cost(&s, &a);
-                    let future_r = (*self_p).expand_recursive_state(ss_id, rng);
+                    let future_r = (*self_p).expand_recursive_state(ss_id, ss, rng);
                     assert!(r <= 0.0);
                     assert!(future_r <= 0.0);
 
@@ -263,6 +298,7 @@ mod tests {
 
     use mdp::baker_grid::BakerGridAction::*;
     use mdp::mdp_traits::Build;
+    use mdp::mdp_traits::InitialState;
     use mdp::mdp_traits::StatesActions;
     use mdp::policy::random_from_candidates_policy::RandomFromCandidatesPolicy;
     use rand::thread_rng;
@@ -333,22 +369,23 @@ mod tests {
 
         let mut rng = thread_rng();
         let mut mcts = MCTSMA::new(oamdp, policy);
-        mcts.expand_recursive_state(0, &mut rng);
+        let s = mcts.mdp.initial_state();
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
     }
@@ -369,22 +406,23 @@ mod tests {
 
         let mut rng = thread_rng();
         let mut mcts = MCTSMA::new(oamdp, policy).set_backup_operator(mcts::BackupOperator::Max);
-        mcts.expand_recursive_state(0, &mut rng);
+        let s = mcts.mdp.initial_state();
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         assert_eq!(mcts.node_count(), 1);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
 
-        mcts.expand_recursive_state(0, &mut rng);
+        mcts.expand_recursive_state(0, s, &mut rng);
         mcts.dump();
         assert!(mcts.is_visit_count_consistent_state_node(0));
     }
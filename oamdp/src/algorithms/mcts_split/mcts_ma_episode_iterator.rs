@@ -17,6 +17,7 @@ use super::MCTSMA;
 pub struct MCTSMAEpisodeIterator<'a, M: StatesActions + DomainAction + Message, P> {
     mcts: &'a mut MCTSMA<M, P>,
     node_id: usize,
+    s: M::State,
     rng: &'a mut rand::rngs::ThreadRng,
 }
 
@@ -27,9 +28,11 @@ impl<'a, M: StatesActions + InitialState + DomainAction + Message, P>
         mcts: &'a mut MCTSMA<M, P>,
         rng: &'a mut rand::rngs::ThreadRng,
     ) -> Self {
+        let s = mcts.mdp.initial_state();
         MCTSMAEpisodeIterator {
             mcts,
             node_id: 0,
+            s,
             rng,
         }
     }
@@ -57,7 +60,7 @@ where
     type Item = (M::State, M::Action, M::State, f32);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let s = self.mcts.arena.get_node(self.node_id).assoc;
+        let s = self.s;
         info!("state: {:?}", s);
 
         if self.mcts.mdp.is_terminal(&s) {
@@ -80,7 +83,7 @@ where
                 }
             }
             num_iterations += 1;
-            self.mcts.expand_recursive_state(self.node_id, self.rng);
+            self.mcts.expand_recursive_state(self.node_id, s, self.rng);
         }
         debug!("num iterations: {:?}", num_iterations);
 
@@ -118,6 +121,8 @@ where
             self.node_id = self.mcts.add_state_node(ss);
         }
 
+        self.s = ss;
+
         let c = self.mcts.mdp.d_cost(&s, &a, &ss);
         debug!("c: {}", c);
 
@@ -32,14 +32,22 @@ where
 {
     fn run_episode(&mut self, rng: &mut ThreadRng) -> f32 {
         let mut sum = 0.0;
+        let mut trajectory = vec![];
         unsafe {
             let self_p = self as *const Self;
-            for (_s, a, ss, c) in self.into_iter_with(rng) {
+            for (s, a, ss, c) in self.into_iter_with(rng) {
                 sum += c;
                 println!("{:?}", a);
                 (*self_p).display(&ss);
+                if trajectory.is_empty() {
+                    trajectory.push(s);
+                }
+                trajectory.push(ss);
             }
         }
+        if let Some(report) = &self.episode_report {
+            report(&trajectory);
+        }
         sum
     }
 }
@@ -64,14 +72,22 @@ where
 {
     fn run_episode(&mut self, rng: &mut ThreadRng) -> f32 {
         let mut sum = 0.0;
+        let mut trajectory = vec![];
         unsafe {
             let self_p = self as *const Self;
-            for (_s, a, ss, c) in self.into_iter_with(rng) {
+            for (s, a, ss, c) in self.into_iter_with(rng) {
                 sum += c;
                 println!("{:?}", a);
                 (*self_p).display(&ss);
+                if trajectory.is_empty() {
+                    trajectory.push(s);
+                }
+                trajectory.push(ss);
             }
         }
+        if let Some(report) = &self.episode_report {
+            report(&trajectory);
+        }
         sum
     }
 }
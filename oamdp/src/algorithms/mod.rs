@@ -6,6 +6,7 @@ mod grid_value_function_ssp;
 pub mod regular_grid_belief_points;
 mod update_at_s;
 
+pub mod brtdp;
 pub mod grid_based_value_iteration;
 pub mod rtdp;
 pub use assoc_belief_point::*;
@@ -4,8 +4,12 @@ mod get_action;
 mod episode_iterator;
 mod eval;
 mod iter_with;
+mod rtdp_message_budget;
+mod rtdp_message_channel;
 mod rtdp_oamdp;
 mod traits;
 
+pub use self::rtdp_message_budget::RTDPMessageBudget;
+pub use self::rtdp_message_channel::RTDPMessageChannel;
 pub use self::rtdp_oamdp::RTDP_OAMDP;
 pub use traits::{RTDPNumStates, RTDPTrait, RTDPTraitAll};
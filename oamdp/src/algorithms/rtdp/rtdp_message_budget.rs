@@ -0,0 +1,285 @@
+use mdp::{
+    heuristic::HeuristicWithMDPMut,
+    into_inner::Inner,
+    mdp_traits::{
+        ActionAvailability, ActionEnumerable, Cost, DisplayState, Eval, GetNextStateMut,
+        InitialState, IsTerminal, PMassMut, StatesActions,
+    },
+    value_estimator::CostEstimatorMut,
+};
+use rand::rngs::ThreadRng;
+use rtdp::rtdp::RTDP;
+use std::hash::Hash;
+use std::{collections::HashSet, fmt::Debug};
+
+use crate::{
+    message_budget::{MessageBudgetState, MessageBudgetWrapper},
+    oamdp::{oamdp::OAMDP, BeliefState},
+    oamdp_d::{VState, OAMDPD},
+    traits::{BeliefOverGoal, Message, ProbSassGivenTheta, SilentMessage},
+};
+
+use super::traits::{RTDPNumStates, RTDPRootValue, RTDPTrait, RTDPTraitAll, RunEpisode};
+
+/// RTDP over the discretized OAMDP with a cap on the number of messages.
+pub struct RTDPMessageBudget<
+    OM,
+    M: StatesActions,
+    A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+    H,
+    const N: usize,
+> {
+    pub rtdp: RTDP<MessageBudgetState<VState<M::State, N>>, H>,
+    pub mdp: MessageBudgetWrapper<OAMDPD<OM, M, A, N>>,
+    pub(crate) max_t: Option<usize>,
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPMessageBudget<OM, M, A, H, N>
+{
+    pub fn new(
+        oamdp: OAMDP<OM, M, A, N>,
+        h: H,
+        k: usize,
+        max_messages: usize,
+    ) -> RTDPMessageBudget<OM, M, A, H, N> {
+        RTDPMessageBudget {
+            rtdp: RTDP::new(h),
+            mdp: MessageBudgetWrapper::new(OAMDPD::new(oamdp, k), max_messages),
+            max_t: None,
+        }
+    }
+
+    pub fn set_max_horizon(mut self, max_t: usize) -> Self {
+        self.max_t = Some(max_t);
+        self
+    }
+
+    pub fn set_upper_bound(mut self, ub: f32) -> Self {
+        self.rtdp = self.rtdp.set_upper_bound(ub);
+        self
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPMessageBudget<OM, M, A, H, N>
+where
+    A: Inner<Result = M::Action> + Into<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageBudgetWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = MessageBudgetState<VState<M::State, N>>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + ActionEnumerable
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageBudgetWrapper<OAMDPD<OM, M, A, N>>>,
+    for<'b> &'b mut OM: ProbSassGivenTheta<M::State, A>,
+{
+    /// Follows the greedy policy from the initial state and returns the
+    /// cumulative cost. The belief is tracked exactly while the policy is
+    /// looked up at a sampled corner of the discretized belief.
+    fn simulate(&mut self, rng: &mut ThreadRng, verbose: bool) -> f32 {
+        let mut bs = self.mdp.mdp.oamdp.initial_state();
+        let mut vs = MessageBudgetState::new(
+            self.mdp.mdp.random_transition_to_v_state(&bs, rng),
+            self.mdp.max_messages(),
+        );
+        let mut cumulative_cost = 0.0;
+        let mut t = 0;
+
+        while !self.mdp.is_terminal(&vs) && self.max_t.is_none_or(|max_t| t < max_t) {
+            let a = match self.rtdp.best_action_mut(&vs, &mut self.mdp) {
+                Some(a) => a,
+                None => break,
+            };
+            if verbose {
+                println!("{:?}", a);
+                println!("Remaining messages: {}", vs.remaining);
+                self.mdp.mdp.oamdp.display(&bs);
+            }
+
+            cumulative_cost += self.mdp.mdp.oamdp.cost(&bs, &a);
+            let next_bs = self.mdp.mdp.oamdp.get_next_state_mut(&bs, &a, rng);
+            let b = self.mdp.mdp.to_belief_state(&vs.s).get_belief_over_goal();
+            let b_sas =
+                self.mdp
+                    .mdp
+                    .oamdp
+                    .get_new_belief_mut(&b, &bs.inner(), &a, &next_bs.inner());
+            let remaining = self.mdp.remaining_after(&vs, &a);
+
+            vs = MessageBudgetState::new(
+                self.mdp
+                    .mdp
+                    .random_transition_to_v_state(&BeliefState::new(next_bs.inner(), b_sas), rng),
+                remaining,
+            );
+            bs = next_bs;
+            t += 1;
+        }
+
+        cumulative_cost
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPNumStates for RTDPMessageBudget<OM, M, A, H, N>
+{
+    fn num_states(&self) -> usize {
+        self.rtdp.num_states()
+    }
+
+    fn num_domain_states(&self) -> usize {
+        let mut hash_set = HashSet::new();
+        for s in self.rtdp.vt.value_table.keys() {
+            hash_set.insert(s.inner());
+        }
+        hash_set.len()
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPTrait for RTDPMessageBudget<OM, M, A, H, N>
+where
+    MessageBudgetWrapper<OAMDPD<OM, M, A, N>>: InitialState
+        + StatesActions<State = MessageBudgetState<VState<M::State, N>>>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability,
+    H: HeuristicWithMDPMut<MessageBudgetWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.solve(&mut self.mdp, rng, num_trials)
+    }
+
+    fn lrtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.lrtdp(&mut self.mdp, num_trials, rng, 1e-3)
+    }
+
+    fn frtdp(&mut self, num_trials: usize) {
+        self.rtdp.frtdp(&mut self.mdp, num_trials, 1e-3)
+    }
+
+    fn vpi_rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.vpi_rtdp(&mut self.mdp, num_trials, rng, 1e-3)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPRootValue for RTDPMessageBudget<OM, M, A, H, N>
+where
+    MessageBudgetWrapper<OAMDPD<OM, M, A, N>>: InitialState
+        + StatesActions<State = MessageBudgetState<VState<M::State, N>>>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal,
+    H: HeuristicWithMDPMut<MessageBudgetWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn root_value(&mut self) -> f32 {
+        self.rtdp
+            .get_value_ssp_mut(&self.mdp.initial_state(), &mut self.mdp)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RunEpisode for RTDPMessageBudget<OM, M, A, H, N>
+where
+    A: Inner<Result = M::Action> + Into<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageBudgetWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = MessageBudgetState<VState<M::State, N>>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + ActionEnumerable
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageBudgetWrapper<OAMDPD<OM, M, A, N>>>,
+    for<'b> &'b mut OM: ProbSassGivenTheta<M::State, A>,
+{
+    fn run_episode(&mut self, rng: &mut ThreadRng) {
+        self.simulate(rng, true);
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize> Eval
+    for RTDPMessageBudget<OM, M, A, H, N>
+where
+    A: Inner<Result = M::Action> + Into<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageBudgetWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = MessageBudgetState<VState<M::State, N>>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + ActionEnumerable
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageBudgetWrapper<OAMDPD<OM, M, A, N>>>,
+    for<'b> &'b mut OM: ProbSassGivenTheta<M::State, A>,
+{
+    fn eval(&mut self, rng: &mut ThreadRng) -> f32 {
+        self.simulate(rng, false)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPTraitAll for RTDPMessageBudget<OM, M, A, H, N>
+where
+    Self: RTDPTrait + RTDPNumStates + RTDPRootValue + RunEpisode + Eval,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::mdp_traits::{Build, GetNextStateMut, InitialState, IsTerminal};
+    use rand::thread_rng;
+
+    use crate::algorithms::rtdp::RTDPTrait;
+    use crate::domains::baker_grid::BakerCOAMDPBuilder;
+
+    use super::RTDPMessageBudget;
+
+    #[test]
+    fn test_rtdp_respects_message_budget() {
+        let mut rng = thread_rng();
+        let builder = BakerCOAMDPBuilder::new(501);
+        let max_messages = builder.max_messages().unwrap();
+        let oamdp = builder.build().mdp;
+        let mut rtdp =
+            RTDPMessageBudget::new(oamdp, ZeroHeuristic {}, 4, max_messages).set_max_horizon(13);
+        rtdp.rtdp(100, &mut rng);
+
+        let mut s = rtdp.mdp.initial_state();
+        let mut num_messages = 0;
+        for _ in 0..30 {
+            if rtdp.mdp.is_terminal(&s) {
+                break;
+            }
+            let a = rtdp.rtdp.best_action_mut(&s, &mut rtdp.mdp).unwrap();
+            if rtdp.mdp.sends_message(&a) {
+                num_messages += 1;
+            }
+            s = rtdp.mdp.get_next_state_mut(&s, &a, &mut rng);
+        }
+        assert!(num_messages <= max_messages);
+    }
+}
@@ -0,0 +1,280 @@
+use mdp::{
+    heuristic::HeuristicWithMDPMut,
+    into_inner::Inner,
+    mdp_traits::{
+        ActionAvailability, ActionEnumerable, Cost, DisplayState, Eval, GetNextStateMut,
+        InitialState, IsTerminal, PMassMut, StatesActions,
+    },
+    value_estimator::CostEstimatorMut,
+};
+use rand::rngs::ThreadRng;
+use rtdp::rtdp::RTDP;
+use std::hash::Hash;
+use std::{collections::HashSet, fmt::Debug};
+
+use crate::{
+    message_channel::{
+        MessageChannel, MessageChannelState, MessageChannelWrapper, MAX_CHANNEL_DELAY,
+    },
+    oamdp::{oamdp::OAMDP, BeliefState},
+    oamdp_d::{VState, OAMDPD},
+    traits::{EnumerateMessage, Message, Set, SilentMessage},
+};
+
+use super::traits::{RTDPNumStates, RTDPRootValue, RTDPTrait, RTDPTraitAll, RunEpisode};
+
+type ChannelState<OM, M, A, const N: usize> = MessageChannelState<
+    VState<<M as StatesActions>::State, N>,
+    <OAMDPD<OM, M, A, N> as Message>::Message,
+>;
+
+/// RTDP over the discretized OAMDP whose messages go through an unreliable
+/// channel.
+pub struct RTDPMessageChannel<
+    OM,
+    M: StatesActions,
+    A: PartialEq + Eq + Copy + Clone + Debug + Hash,
+    H,
+    const N: usize,
+> where
+    OAMDPD<OM, M, A, N>: Message,
+{
+    pub rtdp: RTDP<ChannelState<OM, M, A, N>, H>,
+    pub mdp: MessageChannelWrapper<OAMDPD<OM, M, A, N>>,
+    pub(crate) max_t: Option<usize>,
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPMessageChannel<OM, M, A, H, N>
+where
+    OAMDPD<OM, M, A, N>: EnumerateMessage + SilentMessage,
+{
+    pub fn new(
+        oamdp: OAMDP<OM, M, A, N>,
+        h: H,
+        k: usize,
+        channel: MessageChannel,
+    ) -> RTDPMessageChannel<OM, M, A, H, N> {
+        RTDPMessageChannel {
+            rtdp: RTDP::new(h),
+            mdp: MessageChannelWrapper::new(OAMDPD::new(oamdp, k), channel),
+            max_t: None,
+        }
+    }
+
+    pub fn set_max_horizon(mut self, max_t: usize) -> Self {
+        self.max_t = Some(max_t);
+        self
+    }
+
+    pub fn set_upper_bound(mut self, ub: f32) -> Self {
+        self.rtdp = self.rtdp.set_upper_bound(ub);
+        self
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPMessageChannel<OM, M, A, H, N>
+where
+    A: Into<<OAMDPD<OM, M, A, N> as Message>::Message>
+        + Set<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageChannelWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = ChannelState<OM, M, A, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageChannelWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    /// Follows the greedy policy from the initial state and returns the
+    /// cumulative cost. The observer's belief is tracked exactly from the
+    /// messages that arrive while the policy is looked up at a sampled corner
+    /// of the discretized belief.
+    fn simulate(&mut self, rng: &mut ThreadRng, verbose: bool) -> f32 {
+        let mut bs = self.mdp.mdp.oamdp.initial_state();
+        let mut vs = MessageChannelState::new(
+            self.mdp.mdp.random_transition_to_v_state(&bs, rng),
+            [None; MAX_CHANNEL_DELAY],
+        );
+        let mut cumulative_cost = 0.0;
+        let mut t = 0;
+
+        while !self.mdp.is_terminal(&vs) && self.max_t.is_none_or(|max_t| t < max_t) {
+            let a = match self.rtdp.best_action_mut(&vs, &mut self.mdp) {
+                Some(a) => a,
+                None => break,
+            };
+            if verbose {
+                println!("{:?}", a);
+                println!("In flight: {:?}", &vs.in_flight[..self.mdp.channel().delay]);
+                self.mdp.mdp.oamdp.display(&bs);
+            }
+
+            cumulative_cost += self.mdp.mdp.oamdp.cost(&bs, &a);
+            let (observed, in_flight) = self.mdp.sample_observation(&vs, &a, rng);
+            bs = self.mdp.mdp.oamdp.get_next_state_mut(&bs, &observed, rng);
+            vs = MessageChannelState::new(
+                self.mdp.mdp.random_transition_to_v_state(&bs, rng),
+                in_flight,
+            );
+            t += 1;
+        }
+
+        cumulative_cost
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPNumStates for RTDPMessageChannel<OM, M, A, H, N>
+where
+    OAMDPD<OM, M, A, N>: Message,
+{
+    fn num_states(&self) -> usize {
+        self.rtdp.num_states()
+    }
+
+    fn num_domain_states(&self) -> usize {
+        let mut hash_set = HashSet::new();
+        for s in self.rtdp.vt.value_table.keys() {
+            hash_set.insert(s.inner());
+        }
+        hash_set.len()
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPTrait for RTDPMessageChannel<OM, M, A, H, N>
+where
+    OAMDPD<OM, M, A, N>: Message,
+    MessageChannelWrapper<OAMDPD<OM, M, A, N>>: InitialState
+        + StatesActions<State = ChannelState<OM, M, A, N>>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability,
+    H: HeuristicWithMDPMut<MessageChannelWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.solve(&mut self.mdp, rng, num_trials)
+    }
+
+    fn lrtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.lrtdp(&mut self.mdp, num_trials, rng, 1e-3)
+    }
+
+    fn frtdp(&mut self, num_trials: usize) {
+        self.rtdp.frtdp(&mut self.mdp, num_trials, 1e-3)
+    }
+
+    fn vpi_rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.vpi_rtdp(&mut self.mdp, num_trials, rng, 1e-3)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPRootValue for RTDPMessageChannel<OM, M, A, H, N>
+where
+    OAMDPD<OM, M, A, N>: Message,
+    MessageChannelWrapper<OAMDPD<OM, M, A, N>>: InitialState
+        + StatesActions<State = ChannelState<OM, M, A, N>>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal,
+    H: HeuristicWithMDPMut<MessageChannelWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn root_value(&mut self) -> f32 {
+        self.rtdp
+            .get_value_ssp_mut(&self.mdp.initial_state(), &mut self.mdp)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RunEpisode for RTDPMessageChannel<OM, M, A, H, N>
+where
+    A: Into<<OAMDPD<OM, M, A, N> as Message>::Message>
+        + Set<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageChannelWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = ChannelState<OM, M, A, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageChannelWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn run_episode(&mut self, rng: &mut ThreadRng) {
+        self.simulate(rng, true);
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize> Eval
+    for RTDPMessageChannel<OM, M, A, H, N>
+where
+    A: Into<<OAMDPD<OM, M, A, N> as Message>::Message>
+        + Set<<OAMDPD<OM, M, A, N> as Message>::Message>,
+    OAMDPD<OM, M, A, N>: SilentMessage,
+    MessageChannelWrapper<OAMDPD<OM, M, A, N>>: StatesActions<State = ChannelState<OM, M, A, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + ActionEnumerable
+        + ActionAvailability,
+    OAMDP<OM, M, A, N>: StatesActions<State = BeliefState<M::State, N>, Action = A>
+        + GetNextStateMut
+        + Cost
+        + InitialState
+        + DisplayState<BeliefState<M::State, N>>,
+    H: HeuristicWithMDPMut<MessageChannelWrapper<OAMDPD<OM, M, A, N>>>,
+{
+    fn eval(&mut self, rng: &mut ThreadRng) -> f32 {
+        self.simulate(rng, false)
+    }
+}
+
+impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
+    RTDPTraitAll for RTDPMessageChannel<OM, M, A, H, N>
+where
+    OAMDPD<OM, M, A, N>: Message,
+    Self: RTDPTrait + RTDPNumStates + RTDPRootValue + RunEpisode + Eval,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::mdp_traits::{Build, Eval};
+    use rand::thread_rng;
+
+    use crate::algorithms::rtdp::traits::RTDPRootValue;
+    use crate::algorithms::rtdp::RTDPTrait;
+    use crate::domains::baker_grid::BakerCOAMDPBuilder;
+
+    use super::RTDPMessageChannel;
+
+    #[test]
+    fn test_rtdp_over_lossy_channel() {
+        let mut rng = thread_rng();
+        let builder = BakerCOAMDPBuilder::new(502);
+        let channel = builder.channel().unwrap();
+        let oamdp = builder.build().mdp;
+        let mut rtdp =
+            RTDPMessageChannel::new(oamdp, ZeroHeuristic {}, 4, channel).set_max_horizon(13);
+        rtdp.rtdp(100, &mut rng);
+
+        assert!(rtdp.root_value() > 0.0);
+        assert!(rtdp.eval(&mut rng) > 0.0);
+    }
+}
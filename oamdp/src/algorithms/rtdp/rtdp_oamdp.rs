@@ -20,6 +20,7 @@ use crate::{
         grid_value_function_ssp::GridValueFunctionSSP,
         regular_grid_belief_points::RegularGridBeliefPoints, AssocBeliefPointN,
     },
+    deception::DeceptionMetrics,
     oamdp::{oamdp::OAMDP, BeliefState},
     oamdp_d::{VState, OAMDPD},
     traits::{BeliefOverGoal, ProbSassGivenTheta},
@@ -41,6 +42,7 @@ pub struct RTDP_OAMDP<
     pub rtdp: RTDP<VState<M::State, N>, H>,
     pub oamdp_d: OAMDPD<OM, M, A, N>,
     pub(crate) max_t: Option<usize>,
+    pub(crate) belief_threshold: f32,
 }
 
 impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
@@ -51,6 +53,7 @@ impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, c
             rtdp: RTDP::new(h),
             oamdp_d: OAMDPD::new(oamdp, k),
             max_t: None,
+            belief_threshold: 0.9,
         }
     }
 
@@ -58,6 +61,16 @@ impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, c
         self.max_t = Some(max_t);
         self
     }
+
+    pub fn set_upper_bound(mut self, ub: f32) -> Self {
+        self.rtdp = self.rtdp.set_upper_bound(ub);
+        self
+    }
+
+    pub fn set_belief_threshold(mut self, belief_threshold: f32) -> Self {
+        self.belief_threshold = belief_threshold;
+        self
+    }
 }
 
 impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
@@ -129,6 +142,14 @@ where
     fn lrtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
         self.rtdp.lrtdp(&mut self.oamdp_d, num_trials, rng, 1e-3)
     }
+
+    fn frtdp(&mut self, num_trials: usize) {
+        self.rtdp.frtdp(&mut self.oamdp_d, num_trials, 1e-3)
+    }
+
+    fn vpi_rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng) {
+        self.rtdp.vpi_rtdp(&mut self.oamdp_d, num_trials, rng, 1e-3)
+    }
 }
 
 impl<OM, M: StatesActions, A: PartialEq + Eq + Copy + Clone + Debug + Hash, H, const N: usize>
@@ -216,15 +237,23 @@ where
 {
     fn run_episode(&mut self, rng: &mut ThreadRng) {
         let max_t = self.max_t;
+        let mut belief_changes = vec![];
         unsafe {
             let self_p = self as *mut Self;
-            for (s, a, _ss, _c) in (*self_p).into_iter_with(rng).set_max_t(max_t) {
+            for (s, a, ss, _c) in (*self_p).into_iter_with(rng).set_max_t(max_t) {
                 println!("{:?}", a);
                 (*self_p).oamdp_d.oamdp.display(&s);
+                if belief_changes.is_empty() {
+                    belief_changes.push(s.get_belief_over_goal());
+                }
+                belief_changes.push(ss.get_belief_over_goal());
             }
         }
 
         self.oamdp_d.oamdp.print_cache_stats();
+        if let Some(true_goal) = self.oamdp_d.oamdp.distance_measure.true_goal() {
+            DeceptionMetrics::new(&belief_changes, true_goal, self.belief_threshold).print();
+        }
     }
 }
 
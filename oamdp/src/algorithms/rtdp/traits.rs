@@ -19,6 +19,10 @@ pub trait RTDPTrait {
     fn rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng);
 
     fn lrtdp(&mut self, num_trials: usize, rng: &mut ThreadRng);
+
+    fn frtdp(&mut self, num_trials: usize);
+
+    fn vpi_rtdp(&mut self, num_trials: usize, rng: &mut ThreadRng);
 }
 
 pub trait RTDPTraitAll: RTDPNumStates + RTDPRootValue + RunEpisode + RTDPTrait + Eval {}
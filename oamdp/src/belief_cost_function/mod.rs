@@ -8,7 +8,10 @@ pub enum BeliefCostType {
     KLDivergence,
     Disimulation,
     TVDistance,
+    /// Simulation: pull the observer's belief towards the given decoy goal.
     Deceptive(usize),
+    /// Keep the observer's belief in the true goal low.
+    HideTrueGoal,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Copy)]
@@ -24,8 +27,14 @@ pub enum BeliefCostFunction<const N: usize> {
     #[serde(with = "serde_arrays")]
     TVDistance([NotNan<f32>; N]),
     KLDivergence(usize),
-    Disimulation,
+    /// Keep the observer's belief uniform; the true goal is only reported.
+    Disimulation(usize),
     Threashold(f32, f32, usize),
+    Simulation {
+        decoy: usize,
+        true_goal: usize,
+    },
+    TrueGoalBelief(usize),
     //     Add(Box<BeliefCostFunction<N>>, Box<BeliefCostFunction<N>>),
 }
 
@@ -45,16 +54,54 @@ impl<const N: usize> BeliefCostFunction<N> {
             Self::Euclidean(target_belief) => euclidean_distance(target_belief, b),
             Self::KLDivergence(true_goal) => kl_divergence_for_one_hot(b, *true_goal),
             Self::TVDistance(target_belief) => 0.5 * l1_distance(target_belief, b),
-            Self::Disimulation => (N as f32).log2() - entropy(b),
+            Self::Disimulation(_) => (N as f32).log2() - entropy(b),
             Self::Threashold(th, cost, i) => {
                 if b[*i].into_inner() > *th {
                     *cost
                 } else {
                     0.0
                 }
-            } //             Self::Add(c1, c2) => c1.b_cost(b) + c2.b_cost(b),
+            }
+            Self::Simulation { decoy, .. } => 0.5 * l1_distance(&one_hot::<N>(*decoy), b),
+            Self::TrueGoalBelief(true_goal) => b[*true_goal].into_inner(),
+            //             Self::Add(c1, c2) => c1.b_cost(b) + c2.b_cost(b),
+        }
+    }
+
+    pub fn max_b_cost(&self) -> f32 {
+        match self {
+            Self::Euclidean(target_belief) => (0..N)
+                .map(|i| euclidean_distance(target_belief, &one_hot::<N>(i)))
+                .fold(0.0, f32::max),
+            Self::TVDistance(target_belief) => (0..N)
+                .map(|i| 0.5 * l1_distance(target_belief, &one_hot::<N>(i)))
+                .fold(0.0, f32::max),
+            Self::KLDivergence(_) => f32::INFINITY,
+            Self::Disimulation(_) => (N as f32).log2(),
+            Self::Threashold(_, cost, _) => cost.max(0.0),
+            Self::Simulation { .. } => 1.0,
+            Self::TrueGoalBelief(_) => 1.0,
         }
     }
+
+    /// The goal the agent actually pursues, when the cost function knows it.
+    pub fn true_goal(&self) -> Option<usize> {
+        match self {
+            Self::Euclidean(target_belief) | Self::TVDistance(target_belief) => {
+                target_belief.iter().position(|p| p.into_inner() == 1.0)
+            }
+            Self::KLDivergence(true_goal) | Self::Disimulation(true_goal) => Some(*true_goal),
+            Self::Simulation { true_goal, .. } => Some(*true_goal),
+            Self::TrueGoalBelief(true_goal) => Some(*true_goal),
+            Self::Threashold(..) => None,
+        }
+    }
+}
+
+fn one_hot<const N: usize>(i: usize) -> [NotNan<f32>; N] {
+    let mut b = [NotNan::from_f32(0.0).unwrap(); N];
+    b[i] = NotNan::from_f32(1.0).unwrap();
+    b
 }
 
 pub fn squared_euclidean_distance(a: &[NotNan<f32>], b: &[NotNan<f32>]) -> f32 {
@@ -224,7 +271,7 @@ mod tests {
             err
         );
 
-        let f = BeliefCostFunction::Disimulation;
+        let f = BeliefCostFunction::Disimulation(0);
         let b0 = [NotNan::<f32>::from_f32(0.5).unwrap(); 2];
         let b1 = [
             NotNan::<f32>::from_f32(0.0).unwrap(),
@@ -233,4 +280,38 @@ mod tests {
         println!("{}", f.b_cost(&b0));
         println!("{}", f.b_cost(&b1));
     }
+
+    #[test]
+    fn test_max_b_cost() {
+        let err = 1e-3;
+        let f = BeliefCostFunction::<3>::get_legible_cost_function(0);
+        assert_approx_eq!(1.0, f.max_b_cost(), err);
+
+        let f = BeliefCostFunction::<2>::Disimulation(0);
+        assert_approx_eq!(1.0, f.max_b_cost(), err);
+    }
+
+    #[test]
+    fn test_deceptive_b_cost() {
+        let err = 1e-3;
+        let b = [
+            NotNan::<f32>::from_f32(0.2).unwrap(),
+            NotNan::<f32>::from_f32(0.7).unwrap(),
+            NotNan::<f32>::from_f32(0.1).unwrap(),
+        ];
+
+        let f = BeliefCostFunction::<3>::Simulation {
+            decoy: 1,
+            true_goal: 0,
+        };
+        assert_approx_eq!(0.3, f.b_cost(&b), err);
+        assert_eq!(Some(0), f.true_goal());
+
+        let f = BeliefCostFunction::<3>::TrueGoalBelief(0);
+        assert_approx_eq!(0.2, f.b_cost(&b), err);
+        assert_eq!(
+            Some(2),
+            BeliefCostFunction::<3>::get_legible_cost_function(2).true_goal()
+        );
+    }
 }
@@ -0,0 +1,237 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::time::Instant;
+
+use brtdp::UpperBoundWithMDPMut;
+use clap::Parser;
+use mdp::{
+    episode_runner::monte_carlo_evaluation,
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    heuristic::{HeuristicWithMDPMut, ZeroHeuristic},
+    into_inner::IntoInner,
+    mdp_traits::{
+        ActionAvailability, ActionEnumerable, Build, Cost, Eval, GetNextStateMut, InitialState,
+        IsTerminal, PMass, PMassMut, StateEnumerable, StatesActions,
+    },
+    value_iteration::value_iteration_ssp,
+};
+use oamdp::{
+    algorithms::brtdp::{DomainPolicyUpperBound, BRTDP_OAMDP},
+    belief_cost_function::Objective,
+    domains::{
+        baker_grid::{BakerCOAMDPBuilder, BakerOAMDPBuilder},
+        baker_grid_reset::BakerResetOAMDPBuilder,
+        baker_multi_grid::BakerMultiOAMDPBuilder,
+        grid_turning::GridTurningOAMDPBuilder,
+        lane_change::LaneChangeOAMDPBuilder,
+        obstacle_avoidance::ObstacleAvoidanceOAMDPBuilder,
+        race_track::RaceTrackOAMDPBuilder,
+        recycle::RecycleCOAMDPBuilder,
+        salome_grid::SalomeOAMDPBuilder,
+        search_rescue::SearchRescueOAMDPBuilder,
+        simple_av::SimpleAVOAMDPBuilder,
+    },
+    oamdp::oamdp::OAMDP,
+    oamdp_d::{VState, OAMDPD},
+    scaled_value_table::ScaledValueTable,
+};
+use rand::thread_rng;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    domain: String,
+
+    id: usize,
+
+    n_bin_per_dim: usize,
+
+    num_trials: usize,
+
+    #[arg(short, long, default_value_t = 10)]
+    n: usize,
+
+    #[arg(long, default_value_t = 13)]
+    horizon: usize,
+
+    #[arg(short, long, default_value_t = false)]
+    domain_heuristic: bool,
+
+    #[arg(short, long, default_value_t = 100)]
+    report_interval: usize,
+}
+
+fn run<OM, M, A: Eq + Hash + Debug + Copy + Clone, H, U, const N: usize>(
+    args: &Args,
+    mut brtdp: BRTDP_OAMDP<OM, M, A, H, U, N>,
+) where
+    M: StatesActions,
+    OAMDPD<OM, M, A, N>: InitialState
+        + StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability,
+    H: HeuristicWithMDPMut<OAMDPD<OM, M, A, N>>,
+    U: UpperBoundWithMDPMut<OAMDPD<OM, M, A, N>>,
+    BRTDP_OAMDP<OM, M, A, H, U, N>: Eval,
+{
+    let mut rng = thread_rng();
+    let start = Instant::now();
+    let mut num_trials = 0;
+    while num_trials < args.num_trials {
+        let k = args.report_interval.min(args.num_trials - num_trials);
+        brtdp.solve(k, &mut rng);
+        num_trials += k;
+        let (lb, ub) = brtdp.root_bounds();
+        println!(
+            "Trials: {} LB: {:.4} UB: {:.4} Gap: {:.4}",
+            num_trials,
+            lb,
+            ub,
+            ub - lb
+        );
+        if ub - lb < 1e-3 {
+            break;
+        }
+    }
+    let end = Instant::now();
+
+    let result = monte_carlo_evaluation(&mut brtdp, &mut rng, args.n);
+    let (lb, ub) = brtdp.root_bounds();
+
+    println!("Legibility Cost: {:.2?}", result);
+    println!("Elapsed time: {:.2?}s", (end - start).as_secs_f32());
+    println!("Num States: {}", brtdp.num_states());
+    println!("Num Domain States: {}", brtdp.num_domain_states());
+    println!("Root Lower Bound: {:.2?}", lb);
+    println!("Root Upper Bound: {:.2?}", ub);
+    println!("Root Gap: {:.2?}", ub - lb);
+}
+
+fn build_brtdp<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
+    args: &Args,
+    builder: B,
+) where
+    B: Build<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+    M: IsTerminal
+        + ActionEnumerable
+        + StateEnumerable
+        + StatesActions
+        + Cost
+        + PMass<f32>
+        + ActionAvailability,
+    OAMDPD<OM, M, A, N>: InitialState
+        + StatesActions<State = VState<M::State, N>, Action = A>
+        + PMassMut<f32>
+        + Cost
+        + IsTerminal
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability,
+    BRTDP_OAMDP<OM, M, A, ZeroHeuristic, DomainPolicyUpperBound<M::State>, N>: Eval,
+    BRTDP_OAMDP<OM, M, A, ScaledValueTable<M::State>, DomainPolicyUpperBound<M::State>, N>: Eval,
+{
+    let oamdp = builder.build().mdp;
+    let u = DomainPolicyUpperBound::new(&oamdp);
+
+    if args.domain_heuristic {
+        let vt = value_iteration_ssp(oamdp.into_inner());
+        let alpha = match oamdp.objective {
+            Objective::BeliefCostOnly => 0.0,
+            Objective::LinearCombination(_c, d) => d,
+        };
+        let h = ScaledValueTable::new(alpha, vt);
+        let brtdp = BRTDP_OAMDP::new(oamdp, h, u, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        run(args, brtdp);
+    } else {
+        let h = ZeroHeuristic {};
+        let brtdp = BRTDP_OAMDP::new(oamdp, h, u, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        run(args, brtdp);
+    }
+}
+
+fn main() {
+    env_logger::init();
+    let args = Args::parse();
+    println!("{:?}", args);
+
+    match args.domain.as_str() {
+        "baker" => build_brtdp(
+            &args,
+            BakerOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_com" => build_brtdp(
+            &args,
+            BakerCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker5" => build_brtdp(
+            &args,
+            BakerOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker5_com" => build_brtdp(
+            &args,
+            BakerCOAMDPBuilder::new5(args.id).set_horizon(args.horizon),
+        ),
+        "reset" => build_brtdp(
+            &args,
+            BakerResetOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "reset5" => build_brtdp(
+            &args,
+            BakerResetOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
+        ),
+        "search_rescue" => build_brtdp(
+            &args,
+            SearchRescueOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "search_rescue3" => build_brtdp(
+            &args,
+            SearchRescueOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track" => build_brtdp(
+            &args,
+            RaceTrackOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track3" => build_brtdp(
+            &args,
+            RaceTrackOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "simple_av" => build_brtdp(
+            &args,
+            SimpleAVOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "obstacle_avoidance" => build_brtdp(
+            &args,
+            ObstacleAvoidanceOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "lane_change" => build_brtdp(
+            &args,
+            LaneChangeOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi" => build_brtdp(
+            &args,
+            BakerMultiOAMDPBuilder::<2, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi3" => build_brtdp(
+            &args,
+            BakerMultiOAMDPBuilder::<3, 6>::new(args.id).set_horizon(args.horizon),
+        ),
+        "salome" => build_brtdp(
+            &args,
+            SalomeOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning" => build_brtdp(
+            &args,
+            GridTurningOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning3" => build_brtdp(
+            &args,
+            GridTurningOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "recycle" => build_brtdp(&args, RecycleCOAMDPBuilder::new(args.id)),
+        _ => panic!("{} not implemented", args.domain.as_str()),
+    }
+}
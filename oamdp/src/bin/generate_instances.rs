@@ -0,0 +1,97 @@
+use clap::Parser;
+use oamdp::instance_generator::{
+    generate, write_instances, BakerInstanceGenerator, BlocksInstanceGenerator, GeneratorConfig,
+    RecycleInstanceGenerator, SpellingInstanceGenerator,
+};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    domain: String,
+
+    n: usize,
+
+    out_dir: String,
+
+    #[arg(long, default_value_t = 1000)]
+    first_id: usize,
+
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    #[arg(long, default_value_t = 5)]
+    height: usize,
+
+    #[arg(long, default_value_t = 5)]
+    width: usize,
+
+    #[arg(long, default_value_t = 0.1)]
+    obstacle_density: f32,
+
+    #[arg(long, default_value_t = 2)]
+    min_goal_separation: usize,
+
+    #[arg(long, default_value_t = 0.3)]
+    beta_min: f32,
+
+    #[arg(long, default_value_t = 0.3)]
+    beta_max: f32,
+
+    #[arg(long, default_value_t = 20)]
+    horizon: usize,
+
+    #[arg(long, default_value_t = 3)]
+    num_goals: usize,
+}
+
+fn main() {
+    let args = Args::parse();
+    let config = GeneratorConfig::default()
+        .set_seed(args.seed)
+        .set_size(args.height, args.width)
+        .set_obstacle_density(args.obstacle_density)
+        .set_min_goal_separation(args.min_goal_separation)
+        .set_beta(args.beta_min, args.beta_max)
+        .set_horizon(args.horizon);
+    let (n, dir, id) = (args.n, args.out_dir.as_str(), args.first_id);
+
+    match (args.domain.as_str(), args.num_goals) {
+        ("baker", 3) => write_instances(
+            &generate(&BakerInstanceGenerator::<3>, &config, n),
+            dir,
+            "",
+            id,
+        ),
+        ("baker", 5) => write_instances(
+            &generate(&BakerInstanceGenerator::<5>, &config, n),
+            dir,
+            "",
+            id,
+        ),
+        ("blocks", 2) => write_instances(
+            &generate(&BlocksInstanceGenerator::<4, 2>, &config, n),
+            dir,
+            "",
+            id,
+        ),
+        ("blocks", 3) => write_instances(
+            &generate(&BlocksInstanceGenerator::<4, 3>, &config, n),
+            dir,
+            "",
+            id,
+        ),
+        ("recycle", 4) => write_instances(
+            &generate(&RecycleInstanceGenerator, &config, n),
+            dir,
+            "recycle_",
+            id,
+        ),
+        ("spelling", 3) => write_instances(
+            &generate(&SpellingInstanceGenerator, &config, n),
+            dir,
+            "",
+            id,
+        ),
+        (domain, num_goals) => panic!("{} with {} goals not implemented", domain, num_goals),
+    }
+}
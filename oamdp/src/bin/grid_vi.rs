@@ -7,13 +7,24 @@ use mdp::{
     episode_runner::{monte_carlo_evaluation, EpisodeRunner},
     mdp_traits::{Build, InitialState},
 };
+use oamdp::deception::DeceptionMetrics;
 use oamdp::domains::baker_grid::BakerCOAMDPBuilder;
 use oamdp::domains::baker_grid_reset::BakerResetOAMDPBuilder;
+use oamdp::domains::baker_multi_grid::BakerMultiOAMDPBuilder;
 use oamdp::domains::blocks_world::BlocksOAMDPBuilder;
+use oamdp::domains::grid_turning::GridTurningOAMDPBuilder;
+use oamdp::domains::lane_change::LaneChangeOAMDPBuilder;
+use oamdp::domains::obstacle_avoidance::ObstacleAvoidanceOAMDPBuilder;
+use oamdp::domains::race_track::RaceTrackOAMDPBuilder;
 use oamdp::domains::recycle::RecycleCOAMDPBuilder;
+use oamdp::domains::salome_grid::SalomeOAMDPBuilder;
+use oamdp::domains::search_rescue::SearchRescueOAMDPBuilder;
+use oamdp::domains::simple_av::SimpleAVOAMDPBuilder;
 use oamdp::domains::spelling::{SpellingCOAMDPBuilder, SpellingOAMDPBuilder};
 use oamdp::oamdp::oamdp::OAMDP;
 use oamdp::oamdp::BeliefState;
+use oamdp::plotting::convergence_chart;
+use oamdp::traits::BeliefOverGoal;
 use oamdp::{
     algorithms::grid_based_value_iteration::grid_based_value_iteration_ssp,
     domains::baker_grid::BakerOAMDPBuilder,
@@ -39,6 +50,13 @@ struct Args {
 
     #[arg(short, long, default_value_t = 13)]
     horizon: usize,
+
+    /// Save the residual of each sweep as a PNG, or SVG if the path ends in `.svg`.
+    #[arg(long)]
+    plot_convergence: Option<String>,
+
+    #[arg(long, default_value_t = 0.9)]
+    belief_threshold: f32,
 }
 
 fn grid_vi<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(args: &Args, builder: B)
@@ -74,11 +92,24 @@ where
     println!("Num States: {}", v.num_states());
     println!("Num Domain States: {}", v.num_domain_states());
     println!("Root Value: {:.2?}", v.get_value(&oamdp.initial_state()));
+    if let Some(path) = &args.plot_convergence {
+        convergence_chart(&v.residuals)
+            .save(path)
+            .expect("Unable to save convergence plot");
+    }
 
     let mut runner =
         EpisodeRunner::new(&oamdp, &v, oamdp.initial_state()).set_max_horizon(Some(args.horizon));
-    for (s, _a, _, _c) in runner.into_iter_with(&mut rng) {
+    let mut belief_changes = vec![];
+    for (s, _a, ss, _c) in runner.into_iter_with(&mut rng) {
         oamdp.display(&s);
+        if belief_changes.is_empty() {
+            belief_changes.push(s.get_belief_over_goal());
+        }
+        belief_changes.push(ss.get_belief_over_goal());
+    }
+    if let Some(true_goal) = oamdp.distance_measure.true_goal() {
+        DeceptionMetrics::new(&belief_changes, true_goal, args.belief_threshold).print();
     }
 }
 
@@ -133,6 +164,54 @@ fn main() {
             BakerResetOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
         ),
         "recycle" => grid_vi(&args, RecycleCOAMDPBuilder::new(args.id)),
+        "search_rescue" => grid_vi(
+            &args,
+            SearchRescueOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "search_rescue3" => grid_vi(
+            &args,
+            SearchRescueOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track" => grid_vi(
+            &args,
+            RaceTrackOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track3" => grid_vi(
+            &args,
+            RaceTrackOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "simple_av" => grid_vi(
+            &args,
+            SimpleAVOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "obstacle_avoidance" => grid_vi(
+            &args,
+            ObstacleAvoidanceOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "lane_change" => grid_vi(
+            &args,
+            LaneChangeOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi" => grid_vi(
+            &args,
+            BakerMultiOAMDPBuilder::<2, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi3" => grid_vi(
+            &args,
+            BakerMultiOAMDPBuilder::<3, 6>::new(args.id).set_horizon(args.horizon),
+        ),
+        "salome" => grid_vi(
+            &args,
+            SalomeOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning" => grid_vi(
+            &args,
+            GridTurningOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning3" => grid_vi(
+            &args,
+            GridTurningOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
         _ => panic!("{} not implemented", args.domain.as_str()),
     };
 }
@@ -0,0 +1,69 @@
+use clap::Parser;
+use mdp::baker_grid::{BakerGridState, GridAndGoals};
+use mdp::{heuristic::ZeroHeuristic, mdp_traits::Build};
+use oamdp::{
+    domains::baker_grid::{BakerCOAMDPBuilder, BakerOAMDPBuilder},
+    episode_viewer::{record_inspected_episode, EpisodeViewer, InspectedEpisode},
+};
+use rand::thread_rng;
+use rtdp::rtdp::RTDP;
+use std::fmt::Debug;
+
+/// Solves an OAMDP with RTDP and steps through one episode of the solution
+/// in the terminal.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    domain: String,
+
+    id: usize,
+
+    #[arg(short = 'H', long, default_value_t = 13)]
+    horizon: usize,
+
+    #[arg(short, long, default_value_t = 1000)]
+    num_trials: usize,
+}
+
+fn view<A: Copy + Debug + PartialEq>(
+    episode: &InspectedEpisode<BakerGridState, A, 3>,
+    likelihoods: &[Option<[f32; 3]>],
+    grid_and_goals: &GridAndGoals,
+) {
+    let mut viewer = EpisodeViewer::from_trace(
+        &episode.trace,
+        &episode.q_values,
+        likelihoods,
+        |s: &BakerGridState| grid_and_goals.format(s),
+    )
+    .set_goal_names(&["A", "B", "C"]);
+    viewer.run().expect("Terminal error");
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut rng = thread_rng();
+    let mut rtdp = RTDP::new(ZeroHeuristic {});
+
+    match args.domain.as_str() {
+        "baker" => {
+            let mut oamdp = BakerOAMDPBuilder::<3>::new(args.id)
+                .set_horizon(args.horizon)
+                .build();
+            rtdp.solve(&mut oamdp, &mut rng, args.num_trials);
+            let episode = record_inspected_episode(&mut oamdp, &mut rtdp, &mut rng);
+            let likelihoods = oamdp.mdp.goal_likelihoods(&episode.trace);
+            view(&episode, &likelihoods, &oamdp.mdp.grid_and_goals());
+        }
+        "baker_com" => {
+            let mut oamdp = BakerCOAMDPBuilder::new(args.id)
+                .set_horizon(args.horizon)
+                .build();
+            rtdp.solve(&mut oamdp, &mut rng, args.num_trials);
+            let episode = record_inspected_episode(&mut oamdp, &mut rtdp, &mut rng);
+            let likelihoods = oamdp.mdp.goal_likelihoods(&episode.trace);
+            view(&episode, &likelihoods, &oamdp.mdp.grid_and_goals());
+        }
+        _ => panic!("{} not implemented", args.domain),
+    }
+}
@@ -4,7 +4,7 @@ use std::hash::Hash;
 use std::time::Instant;
 
 use clap::Parser;
-use mcts::{Budget, MCTSTrait, MCTS};
+use mcts::{Budget, MCTSTrait, NodeSharing, MCTS};
 use mdp::{
     episode_runner::monte_carlo_evaluation,
     finite_horizon_wrapper::FiniteHorizonWrapper,
@@ -19,17 +19,31 @@ use mdp::{
 };
 use oamdp::{
     algorithms::mcts_split::{MCTSAM, MCTSMA},
+    deception::deception_report,
     domain_evaluator::DomainEvaluator,
     domains::{
         baker_grid::{BakerCOAMDPBuilder, BakerOAMDPBuilder},
         baker_grid_reset::BakerResetOAMDPBuilder,
+        baker_multi_grid::BakerMultiOAMDPBuilder,
         blocks_world::BlocksOAMDPBuilder,
+        grid_turning::GridTurningOAMDPBuilder,
+        lane_change::LaneChangeOAMDPBuilder,
+        obstacle_avoidance::ObstacleAvoidanceOAMDPBuilder,
+        race_track::RaceTrackOAMDPBuilder,
         recycle::RecycleCOAMDPBuilder,
+        salome_grid::SalomeOAMDPBuilder,
+        search_rescue::SearchRescueOAMDPBuilder,
+        simple_av::SimpleAVOAMDPBuilder,
         spelling::{SpellingCOAMDPBuilder, SpellingOAMDPBuilder},
     },
+    message_budget::MessageBudgetWrapper,
+    message_channel::{MessageChannel, MessageChannelPolicy, MessageChannelWrapper},
     oamdp::oamdp::OAMDP,
     policy::{RTDPOAMDPPolicy, RandomOAMDPPolicy, TabularOAMDPPolicy},
-    traits::{DomainAction, Message},
+    regular_grid_translator::RegularGridTranslator,
+    traits::{
+        BeliefOverGoal, DiscretizeBelief, DomainAction, EnumerateMessage, Message, SilentMessage,
+    },
 };
 use rand::thread_rng;
 use rtdp::{rtdp::RTDP, rtdp_ensure_convergence_wrapper::RTDPEnsureConvergenceWrapper};
@@ -72,6 +86,80 @@ struct Args {
 
     #[arg(short, long)]
     lookahead: Option<usize>,
+
+    #[arg(long, default_value_t = false)]
+    open_loop: bool,
+
+    #[arg(long)]
+    belief_bins: Option<usize>,
+
+    #[arg(long)]
+    max_messages: Option<usize>,
+
+    #[arg(long, default_value_t = 0.9)]
+    belief_threshold: f32,
+}
+
+fn node_sharing<S: 'static + DiscretizeBelief<N>, const N: usize>(args: &Args) -> NodeSharing<S> {
+    if args.open_loop {
+        NodeSharing::OpenLoop
+    } else if let Some(num_bin_per_dim) = args.belief_bins {
+        let translator = RegularGridTranslator::<N>::new(num_bin_per_dim);
+        NodeSharing::Abstraction(Box::new(move |s: &S| s.discretize_belief(&translator)))
+    } else {
+        NodeSharing::ClosedLoop
+    }
+}
+
+/// Builds an `MCTS` with the node sharing and deception report requested on
+/// the command line.
+fn new_mcts<X, P, const N: usize>(
+    args: &Args,
+    oamdp: X,
+    policy: P,
+    true_goal: Option<usize>,
+) -> Box<dyn MCTSTrait>
+where
+    X: 'static + StatesActions + InitialState,
+    X::State: DiscretizeBelief<N> + BeliefOverGoal<N>,
+    P: 'static,
+    MCTS<X, P>: MCTSTrait,
+{
+    Box::new(
+        MCTS::new(oamdp, policy)
+            .set_node_sharing(node_sharing::<_, N>(args))
+            .set_episode_report(deception_report::<_, N>(true_goal, args.belief_threshold)),
+    )
+}
+
+/// Like `new_mcts`, but searches over the message and the domain action
+/// separately in the order given by `--am-split` or `--ma-split`.
+fn new_split_mcts<X, P, const N: usize>(
+    args: &Args,
+    oamdp: X,
+    policy: P,
+    true_goal: Option<usize>,
+) -> Box<dyn MCTSTrait>
+where
+    X: 'static + StatesActions + InitialState + DomainAction + Message,
+    X::State: DiscretizeBelief<N> + BeliefOverGoal<N>,
+    P: 'static,
+    MCTSAM<X, P>: MCTSTrait,
+    MCTSMA<X, P>: MCTSTrait,
+{
+    if args.am_split {
+        Box::new(
+            MCTSAM::new(oamdp, policy)
+                .set_node_sharing(node_sharing::<_, N>(args))
+                .set_episode_report(deception_report::<_, N>(true_goal, args.belief_threshold)),
+        )
+    } else {
+        Box::new(
+            MCTSMA::new(oamdp, policy)
+                .set_node_sharing(node_sharing::<_, N>(args))
+                .set_episode_report(deception_report::<_, N>(true_goal, args.belief_threshold)),
+        )
+    }
 }
 
 fn build_mcts<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
@@ -100,19 +188,16 @@ where
     MCTS<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>, TabularOAMDPPolicy<M>>: MCTSTrait,
 {
     let oamdp = builder.build();
+    let true_goal = oamdp.mdp.distance_measure.true_goal();
 
     if args.use_random_policy {
         if args.full_rollouts {
             let policy = RandomOAMDPPolicy::new();
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let policy = DomainEvaluator::new(RandomPolicy {});
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     } else {
         let mdp = oamdp.into_inner_most();
@@ -123,15 +208,11 @@ where
         if args.full_rollouts {
             let policy = TabularOAMDPPolicy::new(tabular_policy);
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let policy = DomainEvaluator::new(tabular_policy);
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     }
 }
@@ -176,28 +257,19 @@ where
         DomainEvaluator<TabularPolicy<M::State, M::Action>>,
     >: MCTSTrait,
 {
+    if !args.am_split && !args.ma_split {
+        return build_mcts(args, builder);
+    }
     let oamdp = builder.build();
+    let true_goal = oamdp.mdp.distance_measure.true_goal();
 
     if args.use_random_policy {
         if args.full_rollouts {
             let policy = RandomOAMDPPolicy::new();
-            if args.am_split {
-                Box::new(MCTSAM::new(oamdp, policy))
-            } else if args.ma_split {
-                Box::new(MCTSMA::new(oamdp, policy))
-            } else {
-                Box::new(MCTS::new(oamdp, policy))
-            }
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let policy = DomainEvaluator::new(RandomPolicy {});
-
-            if args.am_split {
-                Box::new(MCTSAM::new(oamdp, policy))
-            } else if args.ma_split {
-                Box::new(MCTSMA::new(oamdp, policy))
-            } else {
-                Box::new(MCTS::new(oamdp, policy))
-            }
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     } else {
         let mdp = oamdp.into_inner_most();
@@ -206,22 +278,142 @@ where
         let tabular_policy = TabularPolicy::from_value_table_ssp(mdp, &value_table);
         if args.full_rollouts {
             let policy = TabularOAMDPPolicy::new(tabular_policy);
-            if args.am_split {
-                Box::new(MCTSAM::new(oamdp, policy))
-            } else if args.ma_split {
-                Box::new(MCTSMA::new(oamdp, policy))
-            } else {
-                Box::new(MCTS::new(oamdp, policy))
-            }
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let policy = DomainEvaluator::new(tabular_policy);
-            if args.am_split {
-                Box::new(MCTSAM::new(oamdp, policy))
-            } else if args.ma_split {
-                Box::new(MCTSMA::new(oamdp, policy))
-            } else {
-                Box::new(MCTS::new(oamdp, policy))
-            }
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        }
+    }
+}
+
+fn build_mcts_com_budget<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
+    args: &Args,
+    builder: B,
+    max_messages: usize,
+) -> Box<dyn MCTSTrait>
+where
+    B: Build<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+    M: 'static
+        + InitialState
+        + IsTerminal
+        + ActionEnumerable
+        + StateEnumerable
+        + StatesActions
+        + Cost
+        + PMass<f32>
+        + ActionAvailability,
+    OM: 'static,
+    A: 'static,
+    OAMDP<OM, M, A, N>: Message + DomainAction,
+    MCTSMA<MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>, RandomOAMDPPolicy>:
+        MCTSTrait,
+    MCTSMA<
+        MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+        DomainEvaluator<RandomPolicy>,
+    >: MCTSTrait,
+    MCTSMA<
+        MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+        DomainEvaluator<TabularPolicy<M::State, M::Action>>,
+    >: MCTSTrait,
+    MCTSMA<MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>, TabularOAMDPPolicy<M>>:
+        MCTSTrait,
+    MCTSAM<MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>, TabularOAMDPPolicy<M>>:
+        MCTSTrait,
+    MCTSAM<MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>, RandomOAMDPPolicy>:
+        MCTSTrait,
+    MCTSAM<
+        MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+        DomainEvaluator<RandomPolicy>,
+    >: MCTSTrait,
+    MCTSAM<
+        MessageBudgetWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+        DomainEvaluator<TabularPolicy<M::State, M::Action>>,
+    >: MCTSTrait,
+{
+    if !args.am_split && !args.ma_split {
+        panic!("message budgets require --am-split or --ma-split");
+    }
+    let oamdp = builder.build();
+    let true_goal = oamdp.mdp.distance_measure.true_goal();
+    let oamdp = MessageBudgetWrapper::new(oamdp, max_messages);
+
+    if args.use_random_policy {
+        if args.full_rollouts {
+            let policy = RandomOAMDPPolicy::new();
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        } else {
+            let policy = DomainEvaluator::new(RandomPolicy {});
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        }
+    } else {
+        let mdp = oamdp.mdp.into_inner_most();
+        let value_table = value_iteration_ssp(mdp);
+        let tabular_policy = TabularPolicy::from_value_table_ssp(mdp, &value_table);
+        if args.full_rollouts {
+            let policy = TabularOAMDPPolicy::new(tabular_policy);
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        } else {
+            let policy = DomainEvaluator::new(tabular_policy);
+            new_split_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        }
+    }
+}
+
+type ChannelOAMDP<OM, M, A, const N: usize> =
+    MessageChannelWrapper<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>;
+
+fn build_mcts_com_channel<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
+    args: &Args,
+    builder: B,
+    channel: MessageChannel,
+) -> Box<dyn MCTSTrait>
+where
+    B: Build<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+    M: 'static
+        + InitialState
+        + IsTerminal
+        + ActionEnumerable
+        + StateEnumerable
+        + StatesActions
+        + Cost
+        + PMass<f32>
+        + ActionAvailability,
+    OM: 'static,
+    A: 'static,
+    OAMDP<OM, M, A, N>: EnumerateMessage + SilentMessage,
+    MCTS<ChannelOAMDP<OM, M, A, N>, MessageChannelPolicy<RandomOAMDPPolicy>>: MCTSTrait,
+    MCTS<ChannelOAMDP<OM, M, A, N>, MessageChannelPolicy<DomainEvaluator<RandomPolicy>>>: MCTSTrait,
+    MCTS<
+        ChannelOAMDP<OM, M, A, N>,
+        MessageChannelPolicy<DomainEvaluator<TabularPolicy<M::State, M::Action>>>,
+    >: MCTSTrait,
+    MCTS<ChannelOAMDP<OM, M, A, N>, MessageChannelPolicy<TabularOAMDPPolicy<M>>>: MCTSTrait,
+{
+    if args.am_split || args.ma_split {
+        panic!("message channels are not supported with --am-split or --ma-split");
+    }
+    let oamdp = builder.build();
+    let true_goal = oamdp.mdp.distance_measure.true_goal();
+    let oamdp = MessageChannelWrapper::new(oamdp, channel);
+
+    if args.use_random_policy {
+        if args.full_rollouts {
+            let policy = MessageChannelPolicy::new(RandomOAMDPPolicy::new());
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        } else {
+            let policy = MessageChannelPolicy::new(DomainEvaluator::new(RandomPolicy {}));
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        }
+    } else {
+        let mdp = oamdp.mdp.into_inner_most();
+        let value_table = value_iteration_ssp(mdp);
+        let tabular_policy = TabularPolicy::from_value_table_ssp(mdp, &value_table);
+        if args.full_rollouts {
+            let policy = MessageChannelPolicy::new(TabularOAMDPPolicy::new(tabular_policy));
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
+        } else {
+            let policy = MessageChannelPolicy::new(DomainEvaluator::new(tabular_policy));
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     }
 }
@@ -253,19 +445,16 @@ where
         MCTSTrait,
 {
     let mut oamdp = builder.build();
+    let true_goal = oamdp.mdp.distance_measure.true_goal();
 
     if args.use_random_policy {
         if args.full_rollouts {
             let policy = RandomOAMDPPolicy::new();
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let policy = DomainEvaluator::new(RandomPolicy {});
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     } else {
         let mut lrtdp = RTDP::new(ZeroHeuristic {});
@@ -274,16 +463,12 @@ where
         if args.full_rollouts {
             let policy = RTDPOAMDPPolicy::new(lrtdp);
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         } else {
             let lrtdp = RTDPEnsureConvergenceWrapper::new(lrtdp, 1e-3);
             let policy = DomainEvaluator::new(lrtdp);
 
-            let mcts = MCTS::new(oamdp, policy);
-
-            Box::new(mcts)
+            new_mcts::<_, _, N>(args, oamdp, policy, true_goal)
         }
     }
 }
@@ -309,6 +494,14 @@ fn main() {
             &args,
             BlocksOAMDPBuilder::new4_3(args.id).set_horizon(args.horizon),
         ),
+        "blocks_yaml2" => build_mcts_state_not_enumerable(
+            &args,
+            BlocksOAMDPBuilder::<_, 4, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "blocks_yaml" => build_mcts_state_not_enumerable(
+            &args,
+            BlocksOAMDPBuilder::<_, 4, 3>::new(args.id).set_horizon(args.horizon),
+        ),
         "blocks6" => build_mcts_state_not_enumerable(
             &args,
             BlocksOAMDPBuilder::new6_2(args.id).set_horizon(args.horizon),
@@ -321,10 +514,18 @@ fn main() {
             &args,
             BakerResetOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
         ),
-        "baker_com" => build_mcts_com(
-            &args,
-            BakerCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
-        ),
+        "baker_com" => {
+            let builder = BakerCOAMDPBuilder::new(args.id).set_horizon(args.horizon);
+            match (
+                args.max_messages.or(builder.max_messages()),
+                builder.channel(),
+            ) {
+                (Some(_), Some(_)) => panic!("message budgets and channels cannot be combined"),
+                (Some(max_messages), None) => build_mcts_com_budget(&args, builder, max_messages),
+                (None, Some(channel)) => build_mcts_com_channel(&args, builder, channel),
+                (None, None) => build_mcts_com(&args, builder),
+            }
+        }
         "baker5" => build_mcts(
             &args,
             BakerOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
@@ -337,13 +538,67 @@ fn main() {
             &args,
             SpellingOAMDPBuilder::new_rtdp(args.id).set_horizon(args.horizon),
         ),
-        "spelling_com" => build_mcts_com(
+        "spelling_com" => {
+            let builder = SpellingCOAMDPBuilder::new(args.id).set_horizon(args.horizon);
+            match args.max_messages.or(builder.max_messages()) {
+                Some(max_messages) => build_mcts_com_budget(&args, builder, max_messages),
+                None => build_mcts_com(&args, builder),
+            }
+        }
+        "recycle" => {
+            let builder = RecycleCOAMDPBuilder::new(args.id).set_horizon(args.horizon);
+            match args.max_messages.or(builder.max_messages()) {
+                Some(max_messages) => build_mcts_com_budget(&args, builder, max_messages),
+                None => build_mcts_com(&args, builder),
+            }
+        }
+        "search_rescue" => build_mcts(
+            &args,
+            SearchRescueOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "search_rescue3" => build_mcts(
+            &args,
+            SearchRescueOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track" => build_mcts(
+            &args,
+            RaceTrackOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track3" => build_mcts(
+            &args,
+            RaceTrackOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "simple_av" => build_mcts(
+            &args,
+            SimpleAVOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "obstacle_avoidance" => build_mcts(
+            &args,
+            ObstacleAvoidanceOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "lane_change" => build_mcts(
+            &args,
+            LaneChangeOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi" => build_mcts(
+            &args,
+            BakerMultiOAMDPBuilder::<2, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi3" => build_mcts(
+            &args,
+            BakerMultiOAMDPBuilder::<3, 6>::new(args.id).set_horizon(args.horizon),
+        ),
+        "salome" => build_mcts(
+            &args,
+            SalomeOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning" => build_mcts(
             &args,
-            SpellingCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
+            GridTurningOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
         ),
-        "recycle" => build_mcts_com(
+        "grid_turning3" => build_mcts(
             &args,
-            RecycleCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
+            GridTurningOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
         ),
         _ => panic!("{} not implemented", args.domain.as_str()),
     };
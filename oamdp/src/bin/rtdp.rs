@@ -5,6 +5,7 @@ use std::time::Instant;
 
 use clap::Parser;
 use mdp::{
+    baker_grid::{BakerGridPartialMDP, BakerGridState},
     episode_runner::monte_carlo_evaluation,
     finite_horizon_wrapper::FiniteHorizonWrapper,
     heuristic::ZeroHeuristic,
@@ -16,18 +17,30 @@ use mdp::{
     value_iteration::value_iteration_ssp,
 };
 use oamdp::{
-    algorithms::rtdp::{RTDPTraitAll, RTDP_OAMDP},
+    algorithms::rtdp::{RTDPMessageBudget, RTDPMessageChannel, RTDPTraitAll, RTDP_OAMDP},
     belief_cost_function::Objective,
     domains::{
         baker_grid::{BakerCOAMDPBuilder, BakerOAMDPBuilder},
         baker_grid_reset::BakerResetOAMDPBuilder,
+        baker_multi_grid::BakerMultiOAMDPBuilder,
         blocks_world::BlocksOAMDPBuilder,
+        grid_turning::GridTurningOAMDPBuilder,
+        lane_change::LaneChangeOAMDPBuilder,
+        obstacle_avoidance::ObstacleAvoidanceOAMDPBuilder,
+        race_track::RaceTrackOAMDPBuilder,
         recycle::RecycleCOAMDPBuilder,
+        salome_grid::SalomeOAMDPBuilder,
+        search_rescue::SearchRescueOAMDPBuilder,
+        simple_av::SimpleAVOAMDPBuilder,
         spelling::{SpellingCOAMDPBuilder, SpellingOAMDPBuilder},
     },
+    message_channel::MessageChannel,
+    message_vocabulary::VocabularyCOAMDPBuilder,
     oamdp::oamdp::OAMDP,
+    observer_model::RSAConfig,
     scaled_rtdp::ScaledRTDP,
     scaled_value_table::ScaledValueTable,
+    traits::{EnumerateMessage, SilentMessage},
 };
 use rand::thread_rng;
 use rtdp::rtdp::RTDP;
@@ -52,8 +65,40 @@ struct Args {
     #[arg(short, long, default_value_t = false)]
     lrtdp: bool,
 
+    #[arg(long, default_value_t = false)]
+    frtdp: bool,
+
+    #[arg(long, default_value_t = false)]
+    vpi_rtdp: bool,
+
+    #[arg(long)]
+    upper_bound: Option<f32>,
+
     #[arg(short, long, default_value_t = false)]
     domain_heuristic: bool,
+
+    #[arg(long, default_value_t = 0.9)]
+    belief_threshold: f32,
+
+    #[arg(long)]
+    rsa_depth: Option<usize>,
+
+    #[arg(long, default_value_t = 1.0)]
+    rsa_rationality: f32,
+
+    #[arg(long)]
+    max_messages: Option<usize>,
+}
+
+impl Args {
+    fn rsa(&self) -> Option<RSAConfig> {
+        self.rsa_depth
+            .map(|depth| RSAConfig::new(self.rsa_rationality, depth))
+    }
+
+    fn max_messages(&self, instance_max_messages: Option<usize>) -> Option<usize> {
+        self.max_messages.or(instance_max_messages)
+    }
 }
 
 fn build_rtdp<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
@@ -84,11 +129,114 @@ where
             Objective::LinearCombination(_c, d) => d,
         };
         let vt = ScaledValueTable::new(alpha, vt);
-        let rtdp = RTDP_OAMDP::new(oamdp, vt, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        let mut rtdp = RTDP_OAMDP::new(oamdp, vt, args.n_bin_per_dim)
+            .set_max_horizon(args.horizon)
+            .set_belief_threshold(args.belief_threshold);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
+        Box::new(rtdp)
+    } else {
+        let h = ZeroHeuristic {};
+        let mut rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim)
+            .set_max_horizon(args.horizon)
+            .set_belief_threshold(args.belief_threshold);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
+        Box::new(rtdp)
+    }
+}
+
+fn build_rtdp_message_budget<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
+    args: &Args,
+    builder: B,
+    max_messages: usize,
+) -> Box<dyn RTDPTraitAll>
+where
+    B: Build<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+    M: 'static
+        + IsTerminal
+        + ActionEnumerable
+        + StateEnumerable
+        + StatesActions
+        + Cost
+        + PMass<f32>
+        + ActionAvailability,
+    OM: 'static,
+    A: 'static,
+    RTDPMessageBudget<OM, M, A, ZeroHeuristic, N>: RTDPTraitAll,
+    RTDPMessageBudget<OM, M, A, ScaledValueTable<M::State>, N>: RTDPTraitAll,
+{
+    let oamdp = builder.build().mdp;
+
+    if args.domain_heuristic {
+        let vt = value_iteration_ssp(oamdp.into_inner());
+        let alpha = match oamdp.objective {
+            Objective::BeliefCostOnly => 0.0,
+            Objective::LinearCombination(_c, d) => d,
+        };
+        let vt = ScaledValueTable::new(alpha, vt);
+        let mut rtdp = RTDPMessageBudget::new(oamdp, vt, args.n_bin_per_dim, max_messages)
+            .set_max_horizon(args.horizon);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
+        Box::new(rtdp)
+    } else {
+        let h = ZeroHeuristic {};
+        let mut rtdp = RTDPMessageBudget::new(oamdp, h, args.n_bin_per_dim, max_messages)
+            .set_max_horizon(args.horizon);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
+        Box::new(rtdp)
+    }
+}
+
+fn build_rtdp_message_channel<B, OM, M, A: Eq + Hash + Debug + Copy + Clone, const N: usize>(
+    args: &Args,
+    builder: B,
+    channel: MessageChannel,
+) -> Box<dyn RTDPTraitAll>
+where
+    B: Build<FiniteHorizonWrapper<OAMDP<OM, M, A, N>>>,
+    M: 'static
+        + IsTerminal
+        + ActionEnumerable
+        + StateEnumerable
+        + StatesActions
+        + Cost
+        + PMass<f32>
+        + ActionAvailability,
+    OM: 'static,
+    A: 'static,
+    OAMDP<OM, M, A, N>: EnumerateMessage + SilentMessage,
+    RTDPMessageChannel<OM, M, A, ZeroHeuristic, N>: RTDPTraitAll,
+    RTDPMessageChannel<OM, M, A, ScaledValueTable<M::State>, N>: RTDPTraitAll,
+{
+    let oamdp = builder.build().mdp;
+
+    if args.domain_heuristic {
+        let vt = value_iteration_ssp(oamdp.into_inner());
+        let alpha = match oamdp.objective {
+            Objective::BeliefCostOnly => 0.0,
+            Objective::LinearCombination(_c, d) => d,
+        };
+        let vt = ScaledValueTable::new(alpha, vt);
+        let mut rtdp = RTDPMessageChannel::new(oamdp, vt, args.n_bin_per_dim, channel)
+            .set_max_horizon(args.horizon);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
         Box::new(rtdp)
     } else {
         let h = ZeroHeuristic {};
-        let rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        let mut rtdp = RTDPMessageChannel::new(oamdp, h, args.n_bin_per_dim, channel)
+            .set_max_horizon(args.horizon);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
         Box::new(rtdp)
     }
 }
@@ -125,11 +273,21 @@ where
             Objective::LinearCombination(_c, d) => d,
         };
         let h = ScaledRTDP::new(alpha, rtdp);
-        let rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        let mut rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim)
+            .set_max_horizon(args.horizon)
+            .set_belief_threshold(args.belief_threshold);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
         Box::new(rtdp)
     } else {
         let h = ZeroHeuristic {};
-        let rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim).set_max_horizon(args.horizon);
+        let mut rtdp = RTDP_OAMDP::new(oamdp, h, args.n_bin_per_dim)
+            .set_max_horizon(args.horizon)
+            .set_belief_threshold(args.belief_threshold);
+        if let Some(ub) = args.upper_bound {
+            rtdp = rtdp.set_upper_bound(ub);
+        }
         Box::new(rtdp)
     }
 }
@@ -146,10 +304,31 @@ fn main() {
             &args,
             BakerOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
         ),
-        "baker_com" => build_rtdp(
-            &args,
-            BakerCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
-        ),
+        "baker_com" => {
+            let mut builder = BakerCOAMDPBuilder::new(args.id).set_horizon(args.horizon);
+            if let Some(config) = args.rsa() {
+                builder = builder.set_rsa(config);
+            }
+            match (args.max_messages(builder.max_messages()), builder.channel()) {
+                (Some(_), Some(_)) => panic!("message budgets and channels cannot be combined"),
+                (Some(max_messages), None) => {
+                    build_rtdp_message_budget(&args, builder, max_messages)
+                }
+                (None, Some(channel)) => build_rtdp_message_channel(&args, builder, channel),
+                (None, None) => build_rtdp(&args, builder),
+            }
+        }
+        "baker_vocab" => {
+            let builder = VocabularyCOAMDPBuilder::<BakerGridPartialMDP, BakerGridState, 3>::new(
+                "baker_grid",
+                args.id,
+            )
+            .set_horizon(args.horizon);
+            match args.max_messages(builder.max_messages()) {
+                Some(max_messages) => build_rtdp_message_budget(&args, builder, max_messages),
+                None => build_rtdp(&args, builder),
+            }
+        }
         "blocks" => build_rtdp_rtdp(
             &args,
             BlocksOAMDPBuilder::new4_2(args.id).set_horizon(args.horizon),
@@ -158,6 +337,14 @@ fn main() {
             &args,
             BlocksOAMDPBuilder::new4_3(args.id).set_horizon(args.horizon),
         ),
+        "blocks_yaml2" => build_rtdp_rtdp(
+            &args,
+            BlocksOAMDPBuilder::<_, 4, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "blocks_yaml" => build_rtdp_rtdp(
+            &args,
+            BlocksOAMDPBuilder::<_, 4, 3>::new(args.id).set_horizon(args.horizon),
+        ),
         "blocks6" => build_rtdp_rtdp(
             &args,
             BlocksOAMDPBuilder::new6_2(args.id).set_horizon(args.horizon),
@@ -178,10 +365,16 @@ fn main() {
         //             &args,
         //             SpellingOAMDPBuilder::new(args.id).set_horizon(args.horizon),
         //         ),
-        "spelling_com" => build_rtdp(
-            &args,
-            SpellingCOAMDPBuilder::new(args.id).set_horizon(args.horizon),
-        ),
+        "spelling_com" => {
+            let mut builder = SpellingCOAMDPBuilder::new(args.id).set_horizon(args.horizon);
+            if let Some(config) = args.rsa() {
+                builder = builder.set_rsa(config);
+            }
+            match args.max_messages(builder.max_messages()) {
+                Some(max_messages) => build_rtdp_message_budget(&args, builder, max_messages),
+                None => build_rtdp(&args, builder),
+            }
+        }
         "reset" => build_rtdp(
             &args,
             BakerResetOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
@@ -190,12 +383,73 @@ fn main() {
             &args,
             BakerResetOAMDPBuilder::<5>::new(args.id).set_horizon(args.horizon),
         ),
-        "recycle" => build_rtdp(&args, RecycleCOAMDPBuilder::new(args.id)),
+        "search_rescue" => build_rtdp(
+            &args,
+            SearchRescueOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "search_rescue3" => build_rtdp(
+            &args,
+            SearchRescueOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track" => build_rtdp(
+            &args,
+            RaceTrackOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "race_track3" => build_rtdp(
+            &args,
+            RaceTrackOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "simple_av" => build_rtdp(
+            &args,
+            SimpleAVOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "obstacle_avoidance" => build_rtdp(
+            &args,
+            ObstacleAvoidanceOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "lane_change" => build_rtdp(
+            &args,
+            LaneChangeOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi" => build_rtdp(
+            &args,
+            BakerMultiOAMDPBuilder::<2, 2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "baker_multi3" => build_rtdp(
+            &args,
+            BakerMultiOAMDPBuilder::<3, 6>::new(args.id).set_horizon(args.horizon),
+        ),
+        "salome" => build_rtdp(
+            &args,
+            SalomeOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning" => build_rtdp(
+            &args,
+            GridTurningOAMDPBuilder::<2>::new(args.id).set_horizon(args.horizon),
+        ),
+        "grid_turning3" => build_rtdp(
+            &args,
+            GridTurningOAMDPBuilder::<3>::new(args.id).set_horizon(args.horizon),
+        ),
+        "recycle" => {
+            let mut builder = RecycleCOAMDPBuilder::new(args.id);
+            if let Some(config) = args.rsa() {
+                builder = builder.set_rsa(config);
+            }
+            match args.max_messages(builder.max_messages()) {
+                Some(max_messages) => build_rtdp_message_budget(&args, builder, max_messages),
+                None => build_rtdp(&args, builder),
+            }
+        }
         _ => panic!("{} not implemented", args.domain.as_str()),
     };
 
     if args.lrtdp {
         rtdp.lrtdp(args.num_trials, &mut rng);
+    } else if args.frtdp {
+        rtdp.frtdp(args.num_trials);
+    } else if args.vpi_rtdp {
+        rtdp.vpi_rtdp(args.num_trials, &mut rng);
     } else {
         rtdp.rtdp(args.num_trials, &mut rng);
     }
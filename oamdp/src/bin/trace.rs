@@ -0,0 +1,234 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use clap::Parser;
+use mdp::{
+    finite_horizon_wrapper::{FiniteHorizonWrapper, FiniteHorizonWrapperState},
+    heuristic::{HeuristicWithMDPMut, ZeroHeuristic},
+    into_inner::Inner,
+    mdp_traits::{
+        ActionAvailability, ActionEnumerable, Build, Cost, DCost, GetNextStateMut, InitialState,
+        IsTerminal, PMassMut, RenderTo, StatesActions,
+    },
+    policy::policy_traits::GetActionMut,
+};
+use oamdp::{
+    domains::{
+        baker_grid::{
+            BakerCOAMDPBuilder, BakerCommunicationAction, BakerJointAction, BakerOAMDPBuilder,
+        },
+        obstacle_avoidance::ObstacleAvoidanceOAMDPBuilder,
+        recycle::{RecycleCOAMDPBuilder, RecycleCommunicationAction, RecycleJointAction},
+        simple_av::SimpleAVOAMDPBuilder,
+        spelling::SpellingOAMDPBuilder,
+    },
+    oamdp::{oamdp::OAMDP, BeliefState},
+    plotting::belief_trajectory_chart,
+    policy::{RTDPOAMDPPolicy, RandomOAMDPPolicy},
+    trace::{load_jsonl, record_episode, save_jsonl, Trace, TraceRenderer},
+    traits::ProbSassGivenTheta,
+};
+use rand::thread_rng;
+use rtdp::rtdp::RTDP;
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// `record` or `replay`
+    mode: String,
+
+    domain: String,
+
+    id: usize,
+
+    path: String,
+
+    #[arg(short, long, default_value_t = 1)]
+    n: usize,
+
+    #[arg(short = 'H', long, default_value_t = 20)]
+    horizon: usize,
+
+    /// Record with the domain-optimal policy instead of a random one.
+    #[arg(long, default_value_t = false)]
+    domain_policy: bool,
+
+    /// Render each replayed trace to `<render>_<k>.png` for `baker_com`, or to
+    /// one `<render>_<k>_<t>.png` per step for the other domains.
+    #[arg(long)]
+    render: Option<String>,
+
+    /// Animate each replayed trace with belief bars to `<gif>_<k>.gif`.
+    #[arg(long)]
+    gif: Option<String>,
+
+    /// Plot the observer's belief over time of each replayed trace to `<plot>_<k>.png`.
+    #[arg(long)]
+    plot: Option<String>,
+}
+
+fn trace<OM, M, A, const N: usize>(
+    args: &Args,
+    mut oamdp: FiniteHorizonWrapper<OAMDP<OM, M, A, N>>,
+    renderer: TraceRenderer<A>,
+    render: impl Fn(&TraceRenderer<A>, &OAMDP<OM, M, A, N>, &Trace<M::State, A, N>, &str),
+) where
+    M: InitialState
+        + StatesActions
+        + PMassMut<f32>
+        + IsTerminal
+        + Cost
+        + GetNextStateMut
+        + ActionEnumerable
+        + ActionAvailability
+        + RenderTo,
+    M::State: Serialize + DeserializeOwned,
+    A: Eq + Debug + Hash + Copy + Inner<Result = M::Action> + Serialize + DeserializeOwned,
+    OAMDP<OM, M, A, N>:
+        StatesActions<State = BeliefState<M::State, N>, Action = A> + ActionEnumerable + DCost,
+    FiniteHorizonWrapper<OAMDP<OM, M, A, N>>: StatesActions<State = FiniteHorizonWrapperState<BeliefState<M::State, N>>, Action = A>
+        + InitialState
+        + IsTerminal
+        + GetNextStateMut
+        + DCost,
+    for<'a> &'a OM: ProbSassGivenTheta<M::State, A>,
+    RandomOAMDPPolicy: GetActionMut<
+        FiniteHorizonWrapperState<BeliefState<M::State, N>>,
+        FiniteHorizonWrapper<OAMDP<OM, M, A, N>>,
+    >,
+    RTDPOAMDPPolicy<M::State, ZeroHeuristic>: GetActionMut<
+        FiniteHorizonWrapperState<BeliefState<M::State, N>>,
+        FiniteHorizonWrapper<OAMDP<OM, M, A, N>>,
+    >,
+    ZeroHeuristic: HeuristicWithMDPMut<M>,
+{
+    let mut rng = thread_rng();
+
+    match args.mode.as_str() {
+        "record" => {
+            let traces = if args.domain_policy {
+                let mut lrtdp = RTDP::new(ZeroHeuristic {});
+                lrtdp.lrtdp(&mut oamdp.mdp.mdp, 0, &mut rng, 1e-3);
+                let mut policy = RTDPOAMDPPolicy::new(lrtdp);
+                (0..args.n)
+                    .map(|_| record_episode(&mut oamdp, &mut policy, &mut rng))
+                    .collect::<Vec<_>>()
+            } else {
+                let mut policy = RandomOAMDPPolicy::new();
+                (0..args.n)
+                    .map(|_| record_episode(&mut oamdp, &mut policy, &mut rng))
+                    .collect::<Vec<_>>()
+            };
+            save_jsonl::<_, _, N>(&traces, &args.path).expect("Unable to write traces");
+        }
+        "replay" => {
+            let oamdp = oamdp.mdp;
+            let traces: Vec<Trace<M::State, A, N>> =
+                load_jsonl(&args.path).expect("Unable to read traces");
+            for (k, trace) in traces.iter().enumerate() {
+                let replayed = oamdp.replay(trace);
+                println!(
+                    "trace {}: recorded cost {} replayed cost {}",
+                    k,
+                    trace.total_cost(),
+                    replayed.total_cost()
+                );
+                if let Some(prefix) = &args.render {
+                    render(&renderer, &oamdp, &replayed, &format!("{}_{}", prefix, k));
+                }
+                if let Some(prefix) = &args.gif {
+                    renderer.save_gif(&oamdp.mdp, &replayed, &format!("{}_{}.gif", prefix, k));
+                }
+                if let Some(prefix) = &args.plot {
+                    belief_trajectory_chart(&replayed.beliefs(), &[])
+                        .save(&format!("{}_{}.png", prefix, k))
+                        .expect("Unable to save belief plot");
+                }
+            }
+        }
+        _ => panic!("{} not implemented", args.mode),
+    }
+}
+
+/// Writes one frame per step of `trace` for domains without a trajectory view.
+fn save_frames<OM, M: RenderTo, A, const N: usize>(
+    renderer: &TraceRenderer<A>,
+    oamdp: &OAMDP<OM, M, A, N>,
+    trace: &Trace<M::State, A, N>,
+    prefix: &str,
+) where
+    A: Eq + Debug + Hash + Copy,
+{
+    renderer.save_frames(&oamdp.mdp, trace, prefix);
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.domain.as_str() {
+        "baker" => trace(
+            &args,
+            BakerOAMDPBuilder::<3>::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new(),
+            save_frames,
+        ),
+        "baker_com" => trace(
+            &args,
+            BakerCOAMDPBuilder::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new().set_message(|a: &BakerJointAction| {
+                match BakerCommunicationAction::from(*a) {
+                    BakerCommunicationAction::None => None,
+                    m => Some(format!("{:?}", m)),
+                }
+            }),
+            |_, oamdp, trace, prefix| {
+                oamdp.render_trace(&trace.domain_trace(), &format!("{}.png", prefix))
+            },
+        ),
+        "simple_av" => trace(
+            &args,
+            SimpleAVOAMDPBuilder::<3>::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new(),
+            save_frames,
+        ),
+        "obstacle_avoidance" => trace(
+            &args,
+            ObstacleAvoidanceOAMDPBuilder::<3>::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new(),
+            save_frames,
+        ),
+        "spelling" => trace(
+            &args,
+            SpellingOAMDPBuilder::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new(),
+            save_frames,
+        ),
+        "recycle" => trace(
+            &args,
+            RecycleCOAMDPBuilder::new(args.id)
+                .set_horizon(args.horizon)
+                .build(),
+            TraceRenderer::new().set_message(|a: &RecycleJointAction| {
+                match RecycleCommunicationAction::from(*a) {
+                    RecycleCommunicationAction::None => None,
+                    RecycleCommunicationAction::Announce(location) => {
+                        Some(format!("{:?}", location))
+                    }
+                }
+            }),
+            save_frames,
+        ),
+        _ => panic!("{} not implemented", args.domain),
+    }
+}
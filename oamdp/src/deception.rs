@@ -0,0 +1,104 @@
+use mcts::EpisodeReport;
+use ordered_float::NotNan;
+
+use crate::traits::BeliefOverGoal;
+
+/// Summary of how long an observer was kept away from the true goal along one
+/// belief trajectory, as returned by `OAMDP::get_belief_changes`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DeceptionMetrics {
+    /// First step at which the belief in the true goal exceeds the threshold.
+    pub first_exceeding_step: Option<usize>,
+    /// Last step at which some other goal is at least as likely as the true
+    /// goal. `None` when the observer is never deceived.
+    pub last_deceptive_point: Option<usize>,
+    pub mean_true_goal_belief: f32,
+    pub final_true_goal_belief: f32,
+    pub num_steps: usize,
+}
+
+impl DeceptionMetrics {
+    pub fn new<const N: usize>(
+        belief_changes: &[[NotNan<f32>; N]],
+        true_goal: usize,
+        threshold: f32,
+    ) -> DeceptionMetrics {
+        let true_goal_belief = belief_changes
+            .iter()
+            .map(|b| b[true_goal].into_inner())
+            .collect::<Vec<_>>();
+        let is_deceived =
+            |b: &[NotNan<f32>; N]| (0..N).any(|i| i != true_goal && b[i] >= b[true_goal]);
+
+        DeceptionMetrics {
+            first_exceeding_step: true_goal_belief.iter().position(|p| *p > threshold),
+            last_deceptive_point: belief_changes.iter().rposition(is_deceived),
+            mean_true_goal_belief: true_goal_belief.iter().sum::<f32>()
+                / true_goal_belief.len().max(1) as f32,
+            final_true_goal_belief: true_goal_belief.last().cloned().unwrap_or(0.0),
+            num_steps: belief_changes.len(),
+        }
+    }
+
+    pub fn print(&self) {
+        match self.first_exceeding_step {
+            Some(t) => println!("True goal belief exceeds threshold at: {}", t),
+            None => println!("True goal belief exceeds threshold at: never"),
+        }
+        match self.last_deceptive_point {
+            Some(t) => println!("Last deceptive point: {}", t),
+            None => println!("Last deceptive point: none"),
+        }
+        println!("Mean true goal belief: {:.3}", self.mean_true_goal_belief);
+        println!("Final true goal belief: {:.3}", self.final_true_goal_belief);
+    }
+}
+
+/// Episode report for the MCTS solvers that prints the `DeceptionMetrics` of
+/// the visited states. Nothing is printed when the true goal is unknown.
+pub fn deception_report<S: BeliefOverGoal<N>, const N: usize>(
+    true_goal: Option<usize>,
+    threshold: f32,
+) -> EpisodeReport<S> {
+    Box::new(move |trajectory: &[S]| {
+        if let Some(true_goal) = true_goal {
+            let belief_changes = trajectory
+                .iter()
+                .map(|s| s.get_belief_over_goal())
+                .collect::<Vec<_>>();
+            DeceptionMetrics::new(&belief_changes, true_goal, threshold).print();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use num_traits::FromPrimitive;
+
+    fn belief(p: [f32; 2]) -> [NotNan<f32>; 2] {
+        p.map(|p| NotNan::from_f32(p).unwrap())
+    }
+
+    #[test]
+    fn test_deception_metrics() {
+        let belief_changes = [
+            belief([0.5, 0.5]),
+            belief([0.3, 0.7]),
+            belief([0.6, 0.4]),
+            belief([0.4, 0.6]),
+            belief([0.9, 0.1]),
+        ];
+        let metrics = DeceptionMetrics::new(&belief_changes, 0, 0.8);
+
+        assert_eq!(metrics.first_exceeding_step, Some(4));
+        assert_eq!(metrics.last_deceptive_point, Some(3));
+        assert_approx_eq!(metrics.mean_true_goal_belief, 0.54);
+        assert_approx_eq!(metrics.final_true_goal_belief, 0.9);
+
+        let metrics = DeceptionMetrics::new(&belief_changes[2..3], 0, 0.8);
+        assert_eq!(metrics.first_exceeding_step, None);
+        assert_eq!(metrics.last_deceptive_point, None);
+    }
+}
@@ -20,9 +20,10 @@ use crate::belief_update_type::ObserveabilityAssumption;
 use crate::domains::baker_grid::{
     BakerCommunicationAction, BakerJointAction, CommunicationType, Shape,
 };
+use crate::message_channel::MessageChannel;
 use crate::oamdp::oamdp::OAMDP;
 use crate::oamdp::OAMDPFiniteHorizon;
-use crate::observer_model::ExplicitCommunicationModel;
+use crate::observer_model::{ExplicitCommunicationModel, RSAConfig};
 
 use super::communication_model::{BakerCommunicationModel, BakerCommunicationProb};
 
@@ -41,6 +42,10 @@ pub struct BakerCOAMDPBuilder<const N: usize> {
     shapes: [Shape; N],
     belief_cost_function: BeliefCostFunction<N>,
     cost_type: Objective,
+    #[serde(default)]
+    max_messages: Option<usize>,
+    #[serde(default)]
+    channel: Option<MessageChannel>,
 }
 
 impl<const N: usize> BakerCOAMDPBuilder<N> {
@@ -49,6 +54,32 @@ impl<const N: usize> BakerCOAMDPBuilder<N> {
 
         self
     }
+
+    pub fn set_rsa(mut self, config: RSAConfig) -> BakerCOAMDPBuilder<N> {
+        self.communication_type = CommunicationType::IteratedRSA(config.rationality, config.depth);
+
+        self
+    }
+
+    pub fn set_max_messages(mut self, max_messages: usize) -> BakerCOAMDPBuilder<N> {
+        self.max_messages = Some(max_messages);
+
+        self
+    }
+
+    pub fn max_messages(&self) -> Option<usize> {
+        self.max_messages
+    }
+
+    pub fn set_channel(mut self, channel: MessageChannel) -> BakerCOAMDPBuilder<N> {
+        self.channel = Some(channel);
+
+        self
+    }
+
+    pub fn channel(&self) -> Option<MessageChannel> {
+        self.channel
+    }
 }
 
 impl BakerCOAMDPBuilder<3> {
@@ -95,7 +126,7 @@ impl BakerCOAMDPBuilder<3> {
             true_goal: true_goal,
             communication_cost: communication_cost,
             belief_cost_function: match belief_cost_type {
-                BeliefCostType::Disimulation => BeliefCostFunction::Disimulation,
+                BeliefCostType::Disimulation => BeliefCostFunction::Disimulation(true_goal),
                 BeliefCostType::TVDistance => {
                     BeliefCostFunction::get_legible_cost_function(true_goal)
                 }
@@ -104,6 +135,8 @@ impl BakerCOAMDPBuilder<3> {
             max_t: 20,
             shapes: [Shape::random(rng), Shape::random(rng), Shape::random(rng)],
             cost_type: Objective::LinearCombination(alpha, 1.0 - alpha),
+            max_messages: None,
+            channel: None,
         }
     }
 }
@@ -253,7 +286,7 @@ impl BakerCOAMDPBuilder<5> {
             true_goal: true_goal,
             communication_cost: communication_cost,
             belief_cost_function: match belief_cost_type {
-                BeliefCostType::Disimulation => BeliefCostFunction::Disimulation,
+                BeliefCostType::Disimulation => BeliefCostFunction::Disimulation(true_goal),
                 BeliefCostType::TVDistance => {
                     BeliefCostFunction::get_legible_cost_function(true_goal)
                 }
@@ -268,6 +301,8 @@ impl BakerCOAMDPBuilder<5> {
                 Shape::random(rng),
             ],
             cost_type: Objective::LinearCombination(alpha, 1.0 - alpha),
+            max_messages: None,
+            channel: None,
         }
     }
 }
@@ -1,13 +1,14 @@
 // use super::BakerCommunicationAction;
 use core::fmt::Debug;
 use mdp::{baker_grid::BakerGridAction, into_inner::Inner};
+use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 
 use crate::traits::{Message, Set};
 
 use super::BakerCommunicationAction;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BakerJointAction {
     pub grid_action: BakerGridAction,
     pub(crate) communication_action: BakerCommunicationAction,
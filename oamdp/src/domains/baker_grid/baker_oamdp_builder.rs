@@ -1,7 +1,8 @@
-use std::fs;
+use std::{convert::TryInto, fs};
 
 use mdp::{
     baker_grid::{BakerGridAction, BakerGridMDP, BakerGridPartialMDP, BakerGridState},
+    common::grid2d::GridMap,
     finite_horizon_wrapper::FiniteHorizonWrapper,
     mdp_traits::Build,
     policy::softmax_policy::SoftmaxPolicyBuilder,
@@ -27,8 +28,13 @@ pub struct BakerOAMDPBuilder<const N: usize> {
     pub(crate) horizon: usize,
     pub(crate) random: Option<usize>,
     pub(crate) prob_veering: f32,
+    /// Grid map in `mdp/data/maps` that replaces `partial_mdp` and `possible_goals`.
+    #[serde(default)]
+    pub(crate) map: Option<String>,
+    #[serde(default = "empty_partial_mdp")]
     pub(crate) partial_mdp: BakerGridPartialMDP,
     #[serde_as(as = "[_; N]")]
+    #[serde(default = "origin_goals")]
     pub possible_goals: [BakerGridState; N],
     pub(crate) true_goal: usize,
     #[serde_as(as = "[_; N]")]
@@ -44,7 +50,44 @@ impl<const N: usize> BakerOAMDPBuilder<N> {
             instance_id
         );
         let data = fs::read_to_string(&path).expect("Unable to read file");
-        serde_yaml::from_str(&data).expect("Invalid yaml")
+        match BakerOAMDPBuilder::from_yaml(&data) {
+            Ok(builder) => builder,
+            Err(e) => panic!("{}: {}", path, e),
+        }
+    }
+
+    /// The grid is given either by `map` or by `partial_mdp` together with
+    /// `possible_goals`.
+    pub fn from_yaml(data: &str) -> Result<Self, String> {
+        let value: serde_yaml::Value = serde_yaml::from_str(data).map_err(|e| e.to_string())?;
+        let given = |key: &str| value.get(key).is_some_and(|v| !v.is_null());
+        let grid = (given("partial_mdp"), given("possible_goals"));
+        let builder: Self = serde_yaml::from_value(value).map_err(|e| e.to_string())?;
+        match (builder.map.clone(), grid.0, grid.1) {
+            (Some(name), false, false) => Ok(builder.set_grid_map(&GridMap::from_data(&name))),
+            (None, true, true) => Ok(builder),
+            (Some(_), _, _) => {
+                Err("map cannot be combined with partial_mdp or possible_goals".to_string())
+            }
+            (None, _, _) => {
+                Err("either map or partial_mdp and possible_goals is required".to_string())
+            }
+        }
+    }
+
+    pub fn set_grid_map(mut self, map: &GridMap) -> Self {
+        self.partial_mdp = BakerGridPartialMDP::from_grid_map(map);
+        let possible_goals = map
+            .goals
+            .iter()
+            .map(|&(i, j)| BakerGridState::new(i as i32, j as i32))
+            .collect::<Vec<_>>();
+        self.possible_goals = match possible_goals.try_into() {
+            Ok(goals) => goals,
+            Err(goals) => panic!("expected {} goals but the map has {}", N, goals.len()),
+        };
+
+        self
     }
 
     pub fn set_observabaility_assumption(
@@ -96,6 +139,14 @@ impl<const N: usize> BakerOAMDPBuilder<N> {
     }
 }
 
+fn empty_partial_mdp() -> BakerGridPartialMDP {
+    BakerGridPartialMDP::new(1, 1, vec![])
+}
+
+fn origin_goals<const N: usize>() -> [BakerGridState; N] {
+    [BakerGridState::new(0, 0); N]
+}
+
 impl<const N: usize>
     Build<OAMDPFiniteHorizon<SoftmaxModel<BakerGridMDP, N>, BakerGridMDP, BakerGridAction, N>>
     for BakerOAMDPBuilder<N>
@@ -132,3 +183,67 @@ impl<const N: usize> BakerOAMDPBuilder<N> {
         oamdp
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::belief_cost_function::BeliefCostFunction;
+    use mdp::mdp_traits::BuildFrom;
+
+    #[test]
+    fn test_baker_oamdp_builder_from_map() {
+        let from_yaml = BakerOAMDPBuilder::<3>::new(101);
+        let from_map = BakerOAMDPBuilder::<3>::new(111);
+        assert_eq!(from_map.possible_goals, from_yaml.possible_goals);
+        for g in from_yaml.possible_goals.iter() {
+            let m0: BakerGridMDP = from_yaml.partial_mdp.build_from(g);
+            let m1: BakerGridMDP = from_map
+                .partial_mdp
+                .clone()
+                .set_prob_veering(from_map.prob_veering)
+                .build_from(g);
+            assert_eq!(m0, m1);
+        }
+    }
+
+    #[test]
+    fn test_baker_oamdp_builder_requires_one_grid() {
+        let read = |id: usize| {
+            fs::read_to_string(format!(
+                "{}/src/domains/baker_grid/oamdp_instances/{}.yaml",
+                env!("CARGO_MANIFEST_DIR"),
+                id
+            ))
+            .unwrap()
+        };
+        let explicit = read(101);
+        let from_map = read(111);
+        assert!(BakerOAMDPBuilder::<3>::from_yaml(&explicit).is_ok());
+        assert!(BakerOAMDPBuilder::<3>::from_yaml(&from_map).is_ok());
+
+        let both = format!("{}map: baker_wall.map\n", explicit);
+        assert!(BakerOAMDPBuilder::<3>::from_yaml(&both).is_err());
+        let neither = from_map.replace("map: baker_wall.map\n", "");
+        assert!(BakerOAMDPBuilder::<3>::from_yaml(&neither).is_err());
+        let goals_only = from_map.replace(
+            "map: baker_wall.map\n",
+            "possible_goals: [{i: 0, j: 16}, {i: 8, j: 16}, {i: 0, j: 4}]\n",
+        );
+        assert!(BakerOAMDPBuilder::<3>::from_yaml(&goals_only).is_err());
+    }
+
+    #[test]
+    fn test_baker_oamdp_builder_deceptive() {
+        let oamdp = BakerOAMDPBuilder::<3>::new(501).build_oamdp();
+        assert_eq!(
+            oamdp.distance_measure,
+            BeliefCostFunction::Simulation {
+                decoy: 1,
+                true_goal: 0
+            }
+        );
+
+        let oamdp = BakerOAMDPBuilder::<3>::new(503).build_oamdp();
+        assert_eq!(oamdp.distance_measure.true_goal(), Some(0));
+    }
+}
@@ -5,7 +5,7 @@ use mdp::{
 
 use std::slice::Iter;
 
-use crate::traits::DomainAction;
+use crate::traits::{DomainAction, SilentMessage};
 use crate::{
     oamdp::oamdp::OAMDP,
     traits::{EnumerateDomainAction, EnumerateMessage, Message},
@@ -54,6 +54,16 @@ where
     }
 }
 
+impl<const N: usize> SilentMessage
+    for OAMDP<BakerCommunicationModel<N>, BakerGridMDP, BakerJointAction, N>
+where
+    Self: StatesActions<Action = BakerJointAction>,
+{
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        *m == BakerCommunicationAction::None
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::Shape;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BakerCommunicationAction {
     Blue,
@@ -8,3 +10,23 @@ pub enum BakerCommunicationAction {
     None,
     Square,
 }
+
+impl BakerCommunicationAction {
+    pub fn is_true_of(&self, shape: &Shape) -> bool {
+        match self {
+            BakerCommunicationAction::Blue => {
+                matches!(shape, Shape::BlueSquare | Shape::BlueCircle)
+            }
+            BakerCommunicationAction::Green => {
+                matches!(shape, Shape::GreenSquare | Shape::GreenCircle)
+            }
+            BakerCommunicationAction::Square => {
+                matches!(shape, Shape::BlueSquare | Shape::GreenSquare)
+            }
+            BakerCommunicationAction::Circle => {
+                matches!(shape, Shape::BlueCircle | Shape::GreenCircle)
+            }
+            BakerCommunicationAction::None => true,
+        }
+    }
+}
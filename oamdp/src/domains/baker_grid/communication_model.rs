@@ -5,13 +5,18 @@ use mdp::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{observer_model::ExplicitCommunicationModel, traits::CommunicationProbability};
+use crate::{
+    observer_model::{ExplicitCommunicationModel, IteratedRSA, RSAConfig},
+    traits::CommunicationProbability,
+};
 
 use super::{BakerCommunicationAction, Shape};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CommunicationType {
     RSA,
+    /// `(rationality, depth)`
+    IteratedRSA(f32, usize),
     GenerativeNoise(f32),
     SoftGenerativeNoise(f32, f32),
 }
@@ -29,6 +34,7 @@ pub struct BakerCommunicationProb<const N: usize> {
     communication_type: CommunicationType,
     pub(crate) communication_actions: Vec<BakerCommunicationAction>,
     pub(crate) communication_cost: f32,
+    rsa: Option<IteratedRSA>,
 }
 
 impl<const N: usize> BakerCommunicationProb<N> {
@@ -39,12 +45,40 @@ impl<const N: usize> BakerCommunicationProb<N> {
         communication_actions: Vec<BakerCommunicationAction>,
         communication_cost: f32,
     ) -> Self {
+        let rsa = match communication_type {
+            CommunicationType::RSA => Some(RSAConfig::new(1.0, 1)),
+            CommunicationType::IteratedRSA(rationality, depth) => {
+                Some(RSAConfig::new(rationality, depth))
+            }
+            _ => None,
+        }
+        .map(|config| {
+            let lexicon = shapes
+                .iter()
+                .map(|shape| {
+                    communication_actions
+                        .iter()
+                        .map(|a| a.is_true_of(shape))
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+            let costs = communication_actions
+                .iter()
+                .map(|a| match a {
+                    BakerCommunicationAction::None => 0.0,
+                    _ => communication_cost,
+                })
+                .collect::<Vec<_>>();
+            IteratedRSA::new(&lexicon, &costs, &[1.0 / N as f32; N], config)
+        });
+
         BakerCommunicationProb {
             shapes,
             possible_goals,
             communication_type,
             communication_actions,
             communication_cost,
+            rsa,
         }
     }
 }
@@ -84,6 +118,12 @@ impl<const N: usize> CommunicationProbability<BakerCommunicationAction>
                     BakerCommunicationAction::None => 1.0 - alpha - eta,
                 },
             },
+            CommunicationType::RSA | CommunicationType::IteratedRSA(_, _) => {
+                match self.communication_actions.iter().position(|m| m == a) {
+                    Some(m) => self.rsa.as_ref().unwrap().speaker(id, m),
+                    None => 0.0,
+                }
+            }
             _ => panic!("Invalid communication type"),
         }
     }
@@ -101,7 +141,12 @@ mod tests {
     use crate::domains::baker_grid::BakerCOAMDPBuilder;
     use crate::domains::baker_grid::BakerCommunicationAction;
     use crate::domains::baker_grid::BakerJointAction;
+    use crate::domains::baker_grid::CommunicationType;
+    use crate::domains::baker_grid::Shape;
     use crate::traits::BeliefOverGoal;
+    use crate::traits::CommunicationProbability;
+
+    use super::BakerCommunicationProb;
     use assert_approx_eq::assert_approx_eq;
 
     #[test]
@@ -120,4 +165,54 @@ mod tests {
         assert_approx_eq!(0.43419936, b[2].into_inner(), 1e-3);
         println!("{:?}", ss);
     }
+
+    #[test]
+    fn test_iterated_rsa_communication_probability() {
+        let messages = vec![
+            BakerCommunicationAction::Blue,
+            BakerCommunicationAction::Circle,
+            BakerCommunicationAction::None,
+        ];
+        let shapes = [Shape::BlueCircle, Shape::GreenSquare, Shape::BlueSquare];
+        let goals = [BakerGridState::new(0, 0); 3];
+        let literal = BakerCommunicationProb::new(
+            shapes,
+            goals,
+            CommunicationType::IteratedRSA(1.0, 0),
+            messages.clone(),
+            0.5,
+        );
+        assert_approx_eq!(literal.communication_probability(1, &messages[2]), 1.0);
+        assert_approx_eq!(literal.communication_probability(2, &messages[0]), 0.5);
+
+        let shallow = BakerCommunicationProb::new(
+            shapes,
+            goals,
+            CommunicationType::RSA,
+            messages.clone(),
+            0.5,
+        );
+        let deep = BakerCommunicationProb::new(
+            shapes,
+            goals,
+            CommunicationType::IteratedRSA(1.0, 3),
+            messages.clone(),
+            0.5,
+        );
+        for id in 0..3 {
+            let total = messages
+                .iter()
+                .map(|m| deep.communication_probability(id, m))
+                .sum::<f32>();
+            assert_approx_eq!(total, 1.0);
+        }
+        assert_approx_eq!(
+            deep.communication_probability(0, &BakerCommunicationAction::Green),
+            0.0
+        );
+        assert!(
+            deep.communication_probability(0, &messages[1])
+                > shallow.communication_probability(0, &messages[1])
+        );
+    }
 }
@@ -14,13 +14,21 @@ use std::hash::Hash;
 
 use super::communication_model::BakerCommunicationModel;
 
-impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<BakerGridState, N>>
-    for OAMDP<SoftmaxModel<BakerGridMDP, N>, BakerGridMDP, A, N>
-where
-    Self: StatesActions<State = BeliefState<BakerGridState, N>, Action = A>,
+fn goal_labels() -> Vec<String> {
+    vec![
+        "A".to_string(),
+        "B".to_string(),
+        "C".to_string(),
+        "D".to_string(),
+        "E".to_string(),
+    ]
+}
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize>
+    OAMDP<SoftmaxModel<BakerGridMDP, N>, BakerGridMDP, A, N>
 {
-    fn display(&self, s: &BeliefState<BakerGridState, N>) {
-        let env = self.mdp.grid2d.clone();
+    /// The grid with the possible goals labelled `A`, `B`, ...
+    pub fn grid_and_goals(&self) -> GridAndGoals {
         let possible_goals: Vec<_> = self
             .assumed_model
             .mdp_for_each_goal
@@ -28,29 +36,15 @@ where
             .map(|mdp| mdp.goal)
             .map(|s| (s.i as usize, s.j as usize))
             .collect();
-        let grid_and_goals = GridAndGoals::new(
-            env,
-            possible_goals,
-            vec![
-                "A".to_string(),
-                "B".to_string(),
-                "C".to_string(),
-                "D".to_string(),
-                "E".to_string(),
-            ],
-        );
-        println!("{:?}", s.get_belief_over_goal());
-        grid_and_goals.display(&s.inner());
+        GridAndGoals::new(self.mdp.grid2d.clone(), possible_goals, goal_labels())
     }
 }
 
-impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<BakerGridState, N>>
-    for OAMDP<BakerCommunicationModel<N>, BakerGridMDP, A, N>
-where
-    Self: StatesActions<State = BeliefState<BakerGridState, N>, Action = A>,
+impl<A: Eq + Copy + Debug + Hash, const N: usize>
+    OAMDP<BakerCommunicationModel<N>, BakerGridMDP, A, N>
 {
-    fn display(&self, s: &BeliefState<BakerGridState, N>) {
-        let env = self.mdp.grid2d.clone();
+    /// The grid with the possible goals labelled `A`, `B`, ...
+    pub fn grid_and_goals(&self) -> GridAndGoals {
         let possible_goals: Vec<_> = self
             .assumed_model
             .mdp_for_each_goal
@@ -58,14 +52,28 @@ where
             .map(|mdp| mdp.goal)
             .map(|s| (s.i as usize, s.j as usize))
             .collect();
-        let labels = vec![
-            "A".to_string(),
-            "B".to_string(),
-            "C".to_string(),
-            "D".to_string(),
-            "E".to_string(),
-        ];
-        let grid_and_goals = GridAndGoals::new(env, possible_goals, labels.clone());
+        GridAndGoals::new(self.mdp.grid2d.clone(), possible_goals, goal_labels())
+    }
+}
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<BakerGridState, N>>
+    for OAMDP<SoftmaxModel<BakerGridMDP, N>, BakerGridMDP, A, N>
+where
+    Self: StatesActions<State = BeliefState<BakerGridState, N>, Action = A>,
+{
+    fn display(&self, s: &BeliefState<BakerGridState, N>) {
+        println!("{:?}", s.get_belief_over_goal());
+        self.grid_and_goals().display(&s.inner());
+    }
+}
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<BakerGridState, N>>
+    for OAMDP<BakerCommunicationModel<N>, BakerGridMDP, A, N>
+where
+    Self: StatesActions<State = BeliefState<BakerGridState, N>, Action = A>,
+{
+    fn display(&self, s: &BeliefState<BakerGridState, N>) {
+        let labels = goal_labels();
         let b = s.get_belief_over_goal();
         for i in 0..N {
             println!(
@@ -75,6 +83,6 @@ where
                 b[i].into_inner(),
             );
         }
-        grid_and_goals.display(&s.inner());
+        self.grid_and_goals().display(&s.inner());
     }
 }
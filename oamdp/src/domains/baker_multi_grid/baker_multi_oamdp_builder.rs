@@ -0,0 +1,143 @@
+use std::convert::TryInto;
+use std::fs;
+
+use itertools::Itertools;
+use mdp::{
+    baker_grid::{BakerGridAction, BakerGridState, BakerMultiGridMDP, BakerMultiGridPartialMDP},
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+/// OAMDP over a team of `N` agents where the hypotheses are the `K = N!`
+/// assignments of `goals` to the agents. Hypothesis `k` is the `k`-th
+/// permutation of the goals in lexicographic order, so hypothesis 0 sends
+/// agent `i` to `goals[i]`.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct BakerMultiOAMDPBuilder<const N: usize, const K: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) partial_mdp: BakerMultiGridPartialMDP<N>,
+    #[serde_as(as = "[_; N]")]
+    pub goals: [BakerGridState; N],
+    pub(crate) true_goal: usize,
+    #[serde_as(as = "[_; K]")]
+    pub(crate) initial_belief: [NotNan<f32>; K],
+    pub(crate) objective: Objective,
+}
+
+impl<const N: usize, const K: usize> BakerMultiOAMDPBuilder<N, K> {
+    pub fn new(instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/baker_multi_grid/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn goal_assignments(&self) -> [[BakerGridState; N]; K] {
+        let assignments = self
+            .goals
+            .iter()
+            .cloned()
+            .permutations(N)
+            .map(|p| -> [BakerGridState; N] { p.try_into().unwrap() })
+            .collect::<Vec<_>>();
+        assert_eq!(assignments.len(), K, "K must be the number of permutations");
+
+        match assignments.try_into() {
+            Ok(a) => a,
+            Err(_) => panic!("Failed to convert Vec to array"),
+        }
+    }
+
+    pub fn build_oamdp(
+        &self,
+    ) -> OAMDP<SoftmaxModel<BakerMultiGridMDP<N>, K>, BakerMultiGridMDP<N>, [BakerGridAction; N], K>
+    {
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &self.partial_mdp,
+            &softmax_policy,
+            self.goal_assignments(),
+            self.true_goal,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<const N: usize, const K: usize>
+    Build<
+        OAMDPFiniteHorizon<
+            SoftmaxModel<BakerMultiGridMDP<N>, K>,
+            BakerMultiGridMDP<N>,
+            [BakerGridAction; N],
+            K,
+        >,
+    > for BakerMultiOAMDPBuilder<N, K>
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<
+        SoftmaxModel<BakerMultiGridMDP<N>, K>,
+        BakerMultiGridMDP<N>,
+        [BakerGridAction; N],
+        K,
+    > {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BeliefOverGoal;
+    use mdp::baker_grid::BakerGridAction::*;
+    use mdp::mdp_traits::{InitialState, PMass};
+
+    #[test]
+    fn test_baker_multi_goal_assignments() {
+        let builder = BakerMultiOAMDPBuilder::<3, 6>::new(11);
+        let assignments = builder.goal_assignments();
+        assert_eq!(assignments[0], builder.goals);
+        assert_eq!(
+            assignments[3],
+            [builder.goals[1], builder.goals[2], builder.goals[0]]
+        );
+    }
+
+    #[test]
+    fn test_baker_multi_oamdp_belief() {
+        let oamdp = BakerMultiOAMDPBuilder::<2, 2>::new(1).build_oamdp();
+        let s = oamdp.initial_state();
+
+        // The upper agent heading south-east hints that the agents swap goals.
+        let ss = PMass::<f32>::p_mass(&oamdp, &s, &[SouthEast, East])[0].0;
+        let b = ss.get_belief_over_goal();
+        assert!(b[1] > b[0]);
+    }
+}
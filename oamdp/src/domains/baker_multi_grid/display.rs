@@ -0,0 +1,32 @@
+use mdp::{
+    baker_grid::{BakerGridState, BakerMultiGridMDP},
+    into_inner::Inner,
+    mdp_traits::{DisplayState, StatesActions},
+};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{
+    oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
+    traits::BeliefOverGoal,
+};
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize, const K: usize>
+    DisplayState<BeliefState<[BakerGridState; N], K>>
+    for OAMDP<SoftmaxModel<BakerMultiGridMDP<N>, K>, BakerMultiGridMDP<N>, A, K>
+where
+    Self: StatesActions<Action = A>,
+{
+    fn display(&self, s: &BeliefState<[BakerGridState; N], K>) {
+        let b = s.get_belief_over_goal();
+        for (k, b_k) in b.iter().enumerate() {
+            println!(
+                "Belief over assignment {:?}: {}",
+                self.assumed_model.mdp_for_each_goal[k].goal,
+                b_k.into_inner()
+            );
+        }
+        self.mdp.display(&s.inner());
+    }
+}
@@ -0,0 +1,4 @@
+mod baker_multi_oamdp_builder;
+mod display;
+
+pub use baker_multi_oamdp_builder::BakerMultiOAMDPBuilder;
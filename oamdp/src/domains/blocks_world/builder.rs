@@ -1,8 +1,14 @@
+use std::fs;
+
 use mdp::{
-    blocks_world::BlocksWorldPartialMDPN, finite_horizon_wrapper::FiniteHorizonWrapper,
-    mdp_traits::Build, policy::softmax_policy::SoftmaxPolicyBuilder,
+    blocks_world::{BlocksWorldPartialMDPN, LetterManager},
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
 };
 use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicyBuilder;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
 use crate::{
     belief_cost_function::{BeliefCostType, Objective},
@@ -23,13 +29,68 @@ pub struct BlocksOAMDPBuilder<PB, const NB: usize, const N: usize> {
     pub(crate) objective: Objective,
 }
 
+/// YAML schema of a blocks-world instance. `start` lists the towers from top
+/// to bottom separated by spaces, e.g. `"A SM R"`.
+#[serde_as]
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct BlocksOAMDPInstance<const NB: usize, const N: usize> {
+    pub beta: f32,
+    pub observability_assumption: ObserveabilityAssumption,
+    pub belief_cost_type: BeliefCostType,
+    pub horizon: usize,
+    #[serde_as(as = "[_; NB]")]
+    pub letters: [char; NB],
+    pub start: String,
+    pub epsilon: f32,
+    #[serde_as(as = "[[_; NB]; N]")]
+    pub possible_goals: [[char; NB]; N],
+    pub true_goal: usize,
+    pub objective: Objective,
+}
+
+impl<const NB: usize, const N: usize> BlocksOAMDPInstance<NB, N> {
+    pub fn from_file(path: &str) -> Self {
+        let data = fs::read_to_string(path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn partial_mdp(&self) -> BlocksWorldPartialMDPN<NB> {
+        let lm = LetterManager::new(self.letters);
+        BlocksWorldPartialMDPN::new(lm.str_to_locations(&self.start), self.epsilon, self.letters)
+    }
+}
+
 impl<PB, const NB: usize, const N: usize> BlocksOAMDPBuilder<PB, NB, N> {
+    pub fn from_instance(instance: &BlocksOAMDPInstance<NB, N>, policy_builder: PB) -> Self {
+        BlocksOAMDPBuilder {
+            policy_builder,
+            observability_assumption: instance.observability_assumption,
+            belief_cost_type: instance.belief_cost_type,
+            horizon: instance.horizon,
+            partial_mdp: instance.partial_mdp(),
+            possible_goals: instance.possible_goals,
+            true_goal: instance.true_goal,
+            objective: instance.objective,
+        }
+    }
+
     pub fn set_horizon(mut self, horizon: usize) -> Self {
         self.horizon = horizon;
         self
     }
 }
 
+impl<const NB: usize, const N: usize> BlocksOAMDPBuilder<RTDPSoftmaxPolicyBuilder, NB, N> {
+    pub fn new(instance_id: usize) -> Self {
+        let instance = BlocksOAMDPInstance::from_file(&format!(
+            "{}/src/domains/blocks_world/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        ));
+        Self::from_instance(&instance, RTDPSoftmaxPolicyBuilder::new(instance.beta))
+    }
+}
+
 impl<const NB: usize, const N: usize> Build<OAMDPBlocksFiniteHorizon<NB, N>>
     for BlocksOAMDPBuilder<RTDPSoftmaxPolicyBuilder, NB, N>
 {
@@ -65,3 +126,29 @@ impl<const NB: usize, const N: usize> Build<OAMDPBlocksStateEnumerableFiniteHori
         FiniteHorizonWrapper::new(oamdp, self.horizon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::belief_cost_function::BeliefCostFunction;
+
+    #[test]
+    fn test_blocks_oamdp_builder_deceptive_instances() {
+        let oamdp = BlocksOAMDPBuilder::<_, 4, 3>::new(1).build().mdp;
+        assert_eq!(
+            oamdp.distance_measure,
+            BeliefCostFunction::Simulation {
+                decoy: 1,
+                true_goal: 0
+            }
+        );
+
+        let builder = BlocksOAMDPBuilder::<_, 4, 3>::new(2);
+        assert_eq!(builder.belief_cost_type, BeliefCostType::HideTrueGoal);
+        let builder = BlocksOAMDPBuilder::<_, 4, 3>::new(3);
+        assert_eq!(builder.belief_cost_type, BeliefCostType::Disimulation);
+        let oamdp = builder.build().mdp;
+        assert_eq!(oamdp.distance_measure, BeliefCostFunction::Disimulation(0));
+        assert_eq!(oamdp.distance_measure.true_goal(), Some(0));
+    }
+}
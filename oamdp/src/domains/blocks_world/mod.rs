@@ -5,6 +5,6 @@ mod new4_3;
 mod new6_2;
 mod oamdp;
 
-pub use builder::BlocksOAMDPBuilder;
+pub use builder::{BlocksOAMDPBuilder, BlocksOAMDPInstance};
 pub use oamdp::OAMDPBlocksFiniteHorizon;
-// pub use builder::BlocksOAMDPBuilder;
+// pub use builder::{BlocksOAMDPBuilder, BlocksOAMDPInstance};
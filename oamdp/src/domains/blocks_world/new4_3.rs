@@ -3,6 +3,7 @@ use crate::belief_update_type::ObserveabilityAssumption;
 
 use mdp::blocks_world::*;
 use mdp::policy::softmax_policy::SoftmaxPolicyBuilder;
+use mdp::value_iteration::SSPSolver;
 use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicyBuilder;
 
 use super::BlocksOAMDPBuilder;
@@ -25,7 +26,8 @@ impl BlocksOAMDPBuilder<RTDPSoftmaxPolicyBuilder, 4, 3> {
 impl BlocksOAMDPBuilder<SoftmaxPolicyBuilder, 4, 3> {
     pub fn new4_3_enumerable(id: usize) -> Self {
         BlocksOAMDPBuilder {
-            policy_builder: SoftmaxPolicyBuilder::new(1.0),
+            policy_builder: SoftmaxPolicyBuilder::new(1.0)
+                .set_solver(SSPSolver::TopologicalValueIteration),
             observability_assumption: ObserveabilityAssumption::ActionNotObservable,
             belief_cost_type: BeliefCostType::TVDistance,
             horizon: 13,
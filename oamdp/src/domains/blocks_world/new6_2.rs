@@ -1,6 +1,7 @@
 use mdp::{
     blocks_world::{Block, BlocksWorldPartialMDPN, Location::*},
     policy::softmax_policy::SoftmaxPolicyBuilder,
+    value_iteration::SSPSolver,
 };
 use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicyBuilder;
 
@@ -29,7 +30,8 @@ impl BlocksOAMDPBuilder<RTDPSoftmaxPolicyBuilder, 6, 2> {
 impl BlocksOAMDPBuilder<SoftmaxPolicyBuilder, 6, 2> {
     pub fn new6_2_enumerable(id: usize) -> BlocksOAMDPBuilder<SoftmaxPolicyBuilder, 6, 2> {
         BlocksOAMDPBuilder {
-            policy_builder: SoftmaxPolicyBuilder::new(1.0),
+            policy_builder: SoftmaxPolicyBuilder::new(1.0)
+                .set_solver(SSPSolver::TopologicalValueIteration),
             observability_assumption: ObserveabilityAssumption::ActionNotObservable,
             belief_cost_type: BeliefCostType::TVDistance,
             horizon: 13,
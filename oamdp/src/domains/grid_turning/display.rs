@@ -0,0 +1,27 @@
+use mdp::{
+    grid_turning::{GridTurningMDP, GridTurningState},
+    into_inner::Inner,
+    mdp_traits::{DisplayState, StatesActions},
+};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{
+    oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
+    traits::BeliefOverGoal,
+};
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<GridTurningState, N>>
+    for OAMDP<SoftmaxModel<GridTurningMDP, N>, GridTurningMDP, A, N>
+where
+    Self: StatesActions<Action = A>,
+{
+    fn display(&self, s: &BeliefState<GridTurningState, N>) {
+        let b = s.get_belief_over_goal();
+        for (i, b_i) in b.iter().enumerate() {
+            println!("Belief over goal {}: {}", i, b_i.into_inner());
+        }
+        self.mdp.display(&s.inner());
+    }
+}
@@ -0,0 +1,123 @@
+use std::fs;
+
+use mdp::{
+    common::coordinate2::Coordinate2,
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    grid_turning::{GridTurningAction, GridTurningMDP, GridTurningPartialMDP, GridTurningState},
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+/// Grid where the agent has a heading and can only turn or go straight, so
+/// the observer can read the goal from where the agent is facing.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct GridTurningOAMDPBuilder<const N: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) height: usize,
+    pub(crate) width: usize,
+    pub(crate) obstacles: Vec<(i32, i32)>,
+    pub(crate) initial_state: GridTurningState,
+    #[serde(default)]
+    pub(crate) prob_veering: f32,
+    #[serde_as(as = "[_; N]")]
+    pub possible_goals: [Coordinate2; N],
+    pub(crate) true_goal: usize,
+    #[serde_as(as = "[_; N]")]
+    pub(crate) initial_belief: [NotNan<f32>; N],
+    pub(crate) objective: Objective,
+}
+
+impl<const N: usize> GridTurningOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/grid_turning/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn build_partial_mdp(&self) -> GridTurningPartialMDP {
+        GridTurningPartialMDP::new(
+            self.height,
+            self.width,
+            self.initial_state,
+            self.obstacles.clone(),
+            self.prob_veering,
+        )
+    }
+
+    pub fn build_oamdp(
+        &self,
+    ) -> OAMDP<SoftmaxModel<GridTurningMDP, N>, GridTurningMDP, GridTurningAction, N> {
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &self.build_partial_mdp(),
+            &softmax_policy,
+            self.possible_goals,
+            self.true_goal,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<const N: usize>
+    Build<OAMDPFiniteHorizon<SoftmaxModel<GridTurningMDP, N>, GridTurningMDP, GridTurningAction, N>>
+    for GridTurningOAMDPBuilder<N>
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<SoftmaxModel<GridTurningMDP, N>, GridTurningMDP, GridTurningAction, N>
+    {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BeliefOverGoal;
+    use mdp::grid_turning::Direction::*;
+    use mdp::into_inner::Inner;
+    use mdp::mdp_traits::{InitialState, PMass, RenderTo};
+
+    #[test]
+    fn test_grid_turning_oamdp_heading() {
+        let oamdp = GridTurningOAMDPBuilder::<2>::new(1).build_oamdp();
+        let s = oamdp.initial_state();
+
+        // Turning towards one goal is evidence for it before the agent moves.
+        let ss = PMass::<f32>::p_mass(&oamdp, &s, &GridTurningAction::TurnLeft)[0].0;
+        assert_eq!(ss.inner(), GridTurningState::new(4, 2, NorthWest));
+        let b = ss.get_belief_over_goal();
+        assert!(b[0] > b[1]);
+
+        let path = std::env::temp_dir().join("oamdp_test_grid_turning.png");
+        oamdp.render_to(&ss, path.to_str().unwrap());
+        assert!(path.exists());
+    }
+}
@@ -0,0 +1,4 @@
+mod display;
+mod grid_turning_oamdp_builder;
+
+pub use grid_turning_oamdp_builder::GridTurningOAMDPBuilder;
@@ -0,0 +1,50 @@
+use mdp::simple_av_lane_change::{SimpleAVLaneChangeParameter, SimpleAVLaneChangePartialMDP};
+
+use crate::domains::SoftmaxOAMDPBuilder;
+
+pub type LaneChangeOAMDPBuilder<const N: usize> =
+    SoftmaxOAMDPBuilder<SimpleAVLaneChangePartialMDP, SimpleAVLaneChangeParameter, N>;
+
+impl<const N: usize> LaneChangeOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        Self::from_instance("lane_change", instance_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::simple_av::VehicleConfiguration;
+    use mdp::simple_av_lane_change::{
+        Lane, SimpleAVLaneChangeMDP, SimpleAVLaneChangeParameter::*, SimpleAVLaneChangeState,
+        VehicleConfigurationLane,
+    };
+
+    use crate::domains::softmax_oamdp_builder::tests::assert_instances;
+
+    use super::*;
+
+    fn start(ego: (usize, i32), other: (usize, i32)) -> SimpleAVLaneChangeState {
+        SimpleAVLaneChangeState::new(
+            VehicleConfigurationLane::new(ego.0, ego.1, Lane::RightCenter),
+            VehicleConfiguration::new(other.0, other.1),
+        )
+    }
+
+    #[test]
+    fn test_lane_change_oamdp_instances() {
+        let goals = [Yield, NoYield, NotChanging];
+        assert_instances::<
+            SimpleAVLaneChangePartialMDP,
+            SimpleAVLaneChangeParameter,
+            SimpleAVLaneChangeMDP,
+            3,
+        >(
+            "lane_change",
+            &[
+                (goals, 0, start((0, 2), (0, 2))),
+                (goals, 1, start((0, 2), (0, 2))),
+                ([Yield, NotChanging, NoYield], 0, start((0, 1), (1, 1))),
+            ],
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod display;
+mod lane_change_oamdp_builder;
+
+pub use lane_change_oamdp_builder::LaneChangeOAMDPBuilder;
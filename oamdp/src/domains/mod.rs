@@ -1,8 +1,16 @@
 pub mod baker_grid;
 pub mod baker_grid_reset;
+pub mod baker_multi_grid;
 pub mod blocks_world;
+pub mod grid_turning;
+pub mod lane_change;
 pub mod obstacle_avoidance;
+pub mod race_track;
 pub mod recycle;
+pub mod salome_grid;
 pub mod search_rescue;
 pub mod simple_av;
+mod softmax_oamdp_builder;
 pub mod spelling;
+
+pub use softmax_oamdp_builder::SoftmaxOAMDPBuilder;
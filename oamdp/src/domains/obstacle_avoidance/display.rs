@@ -0,0 +1,26 @@
+use mdp::{
+    into_inner::Inner,
+    mdp_traits::{DisplayState, StatesActions},
+    simple_av_obstacle_avoidance::{ObstacleAvoidanceMDP, ObstacleAvoidanceState},
+};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{
+    oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
+    traits::BeliefOverGoal,
+};
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize>
+    DisplayState<BeliefState<ObstacleAvoidanceState, N>>
+    for OAMDP<SoftmaxModel<ObstacleAvoidanceMDP, N>, ObstacleAvoidanceMDP, A, N>
+where
+    Self: StatesActions<Action = A>,
+{
+    fn display(&self, s: &BeliefState<ObstacleAvoidanceState, N>) {
+        let b = s.get_belief_over_goal();
+        println!("{:?}", b);
+        self.mdp.display(&s.inner());
+    }
+}
@@ -3,9 +3,12 @@ mod coamdp_instances;
 mod communication_action;
 mod communication_model;
 mod cost;
+mod display;
 mod joint_action;
+mod obstacle_avoidance_oamdp_builder;
 
 pub use coamdp_instances::ObstacleAvoidanceCOAMDPBuilder;
 pub use communication_action::ObstacleAvoidanceCommunicationAction;
 pub use joint_action::ObstacleAvoidanceJointAction;
+pub use obstacle_avoidance_oamdp_builder::ObstacleAvoidanceOAMDPBuilder;
 // pub use example::example;
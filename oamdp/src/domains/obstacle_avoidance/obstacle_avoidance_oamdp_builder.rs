@@ -0,0 +1,56 @@
+use mdp::simple_av_obstacle_avoidance::{ObstacleAvoidanceBuilder, ObstacleAvoidanceParameter};
+
+use crate::domains::SoftmaxOAMDPBuilder;
+
+/// Implicit-communication counterpart of `ObstacleAvoidanceCOAMDPBuilder`
+/// without the acknowledge message.
+pub type ObstacleAvoidanceOAMDPBuilder<const N: usize> =
+    SoftmaxOAMDPBuilder<ObstacleAvoidanceBuilder, ObstacleAvoidanceParameter, N>;
+
+impl<const N: usize> ObstacleAvoidanceOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        Self::from_instance("obstacle_avoidance", instance_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::simple_av::VehicleConfiguration;
+    use mdp::simple_av_obstacle_avoidance::{
+        Lane, ObstacleAvoidanceMDP, ObstacleAvoidanceParameter::*, ObstacleAvoidanceState,
+        VehicleConfigurationLane,
+    };
+
+    use crate::domains::softmax_oamdp_builder::tests::assert_instances;
+
+    use super::*;
+
+    fn start(ego: (usize, i32), other: (usize, i32)) -> ObstacleAvoidanceState {
+        ObstacleAvoidanceState::new(
+            VehicleConfigurationLane::new(ego.0, ego.1, Lane::Center),
+            VehicleConfiguration::new(other.0, other.1),
+        )
+    }
+
+    #[test]
+    fn test_obstacle_avoidance_oamdp_instances() {
+        let goals = [AwareNotYielding, AwareYielding, NotAwareNotYielding];
+        assert_instances::<
+            ObstacleAvoidanceBuilder,
+            ObstacleAvoidanceParameter,
+            ObstacleAvoidanceMDP,
+            3,
+        >(
+            "obstacle_avoidance",
+            &[
+                (goals, 0, start((0, 3), (0, 2))),
+                (goals, 1, start((0, 3), (0, 2))),
+                (
+                    [AwareYielding, NotAwareNotYielding, AwareNotYielding],
+                    0,
+                    start((0, 1), (0, 1)),
+                ),
+            ],
+        );
+    }
+}
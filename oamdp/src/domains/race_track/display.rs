@@ -0,0 +1,30 @@
+use mdp::{
+    into_inner::Inner,
+    mdp_traits::{DisplayState, StatesActions},
+    race_track::RaceTrackState,
+};
+
+use crate::{
+    oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
+    traits::BeliefOverGoal,
+};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use super::RaceTrackMDPE;
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<RaceTrackState, N>>
+    for OAMDP<SoftmaxModel<RaceTrackMDPE, N>, RaceTrackMDPE, A, N>
+where
+    Self: StatesActions<State = BeliefState<RaceTrackState, N>, Action = A>,
+{
+    fn display(&self, s: &BeliefState<RaceTrackState, N>) {
+        let b = s.get_belief_over_goal();
+        for (i, b_i) in b.iter().enumerate() {
+            println!("Belief over goal {}: {}", i, b_i.into_inner());
+        }
+        println!("{:?}", s.inner());
+        self.mdp.mdp.display(&s.inner());
+    }
+}
@@ -0,0 +1,4 @@
+mod display;
+mod race_track_oamdp_builder;
+
+pub use race_track_oamdp_builder::{RaceTrackMDPE, RaceTrackOAMDPBuilder};
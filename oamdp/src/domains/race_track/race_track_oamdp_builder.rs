@@ -0,0 +1,130 @@
+use std::fs;
+
+use mdp::{
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+    race_track::{FinishSegment, RaceTrackAction, RaceTrackMDP, RaceTrackPartialMDP},
+    state_enumerable_wrapper::StateEnumerableWrapper,
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+pub type RaceTrackMDPE = StateEnumerableWrapper<RaceTrackMDP>;
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct RaceTrackOAMDPBuilder<const N: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) track: String,
+    pub(crate) p_slip: f64,
+    #[serde(default)]
+    pub(crate) potholes: Vec<(usize, usize)>,
+    #[serde_as(as = "[_; N]")]
+    pub possible_goals: [FinishSegment; N],
+    pub(crate) true_goal: usize,
+    #[serde_as(as = "[_; N]")]
+    pub(crate) initial_belief: [NotNan<f32>; N],
+    pub(crate) objective: Objective,
+}
+
+impl<const N: usize> RaceTrackOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/race_track/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn build_partial_mdp(&self) -> RaceTrackPartialMDP {
+        let path = format!(
+            "{}/src/domains/race_track/tracks/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            self.track
+        );
+        let mdp = RaceTrackMDP::from_file(&path)
+            .set_p_slip(self.p_slip)
+            .set_potholes(&self.potholes);
+
+        RaceTrackPartialMDP::new(mdp)
+    }
+
+    pub fn build_oamdp(
+        &self,
+    ) -> OAMDP<SoftmaxModel<RaceTrackMDPE, N>, RaceTrackMDPE, RaceTrackAction, N> {
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &self.build_partial_mdp(),
+            &softmax_policy,
+            self.possible_goals,
+            self.true_goal,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<const N: usize>
+    Build<OAMDPFiniteHorizon<SoftmaxModel<RaceTrackMDPE, N>, RaceTrackMDPE, RaceTrackAction, N>>
+    for RaceTrackOAMDPBuilder<N>
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<SoftmaxModel<RaceTrackMDPE, N>, RaceTrackMDPE, RaceTrackAction, N> {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BeliefOverGoal;
+    use mdp::into_inner::Inner;
+    use mdp::mdp_traits::{InitialState, PMass, RenderTo};
+    use mdp::race_track::RaceTrackState;
+
+    #[test]
+    fn test_race_track_oamdp_builder() {
+        for id in [1, 2] {
+            let oamdp = RaceTrackOAMDPBuilder::<2>::new(id).build_oamdp();
+            println!("{:?}", oamdp.initial_state());
+        }
+        let oamdp = RaceTrackOAMDPBuilder::<3>::new(11).build_oamdp();
+        println!("{:?}", oamdp.initial_state());
+    }
+
+    #[test]
+    fn test_race_track_oamdp_belief() {
+        let oamdp = RaceTrackOAMDPBuilder::<2>::new(1).build_oamdp();
+        let s = oamdp.initial_state();
+        let s = PMass::<f32>::p_mass(&oamdp, &s, &RaceTrackAction::Keep)[0].0;
+        for (ss, _) in PMass::<f32>::p_mass(&oamdp, &s, &RaceTrackAction::NorthWest) {
+            assert_eq!(ss.inner(), RaceTrackState::new(5, 3, -1, 1));
+            let b = ss.get_belief_over_goal();
+            assert!(b[0] > b[1]);
+        }
+        oamdp.render_to(&s, "race_track_oamdp.png");
+    }
+}
@@ -5,7 +5,7 @@ use crate::traits::Set;
 
 use super::location::Location;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RecycleAction {
     Moveto(usize, Location),
     PickUp(usize),
@@ -17,7 +17,7 @@ pub enum RecycleCommunicationAction {
     None,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RecycleJointAction {
     pub(crate) domain_action: RecycleAction,
     pub(crate) communication_action: RecycleCommunicationAction,
@@ -48,6 +48,12 @@ impl From<(RecycleAction, RecycleCommunicationAction)> for RecycleJointAction {
     }
 }
 
+impl From<RecycleJointAction> for RecycleCommunicationAction {
+    fn from(a: RecycleJointAction) -> Self {
+        a.communication_action
+    }
+}
+
 impl From<RecycleAction> for RecycleJointAction {
     fn from(a: RecycleAction) -> Self {
         Self::new(a, RecycleCommunicationAction::None)
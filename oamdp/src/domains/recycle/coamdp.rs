@@ -1,10 +1,8 @@
 use mdp::mdp_traits::{ActionEnumerable, StatesActions};
 
-
-
 use crate::{
     oamdp::oamdp::OAMDP,
-    traits::{DomainAction, EnumerateDomainAction, EnumerateMessage, Message},
+    traits::{DomainAction, EnumerateDomainAction, EnumerateMessage, Message, SilentMessage},
 };
 
 use super::{
@@ -47,3 +45,13 @@ where
         self.assumed_model.messages.iter()
     }
 }
+
+impl<const K: usize, const N: usize> SilentMessage
+    for OAMDP<RecycleCommunicationModel<K>, RecycleMDP<K>, RecycleJointAction, N>
+where
+    Self: StatesActions<Action = RecycleJointAction>,
+{
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        *m == RecycleCommunicationAction::None
+    }
+}
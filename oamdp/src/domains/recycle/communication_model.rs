@@ -4,6 +4,7 @@ use mdp::{
     value_iteration::{value_iteration_ssp, ValueTable},
 };
 
+use crate::observer_model::{IteratedRSA, RSAConfig};
 use crate::traits::ProbSassGivenTheta;
 
 use super::{
@@ -21,6 +22,7 @@ pub struct RecycleCommunicationModel<const K: usize> {
     pub(crate) targets: Vec<[Location; 3]>,
     pub(crate) messages: Vec<RecycleCommunicationAction>,
     pub(crate) kinds: [usize; K],
+    rsa: Option<Vec<IteratedRSA>>,
 }
 
 impl<const K: usize> RecycleCommunicationModel<K> {
@@ -38,9 +40,45 @@ impl<const K: usize> RecycleCommunicationModel<K> {
             targets: targets,
             messages: enumerate_communication_actions(),
             kinds: kinds,
+            rsa: None,
         }
     }
 
+    /// Replaces the fixed announcement probabilities for a held object with an
+    /// iterated RSA speaker, one per kind of object.
+    pub fn set_rsa(mut self, config: RSAConfig) -> Self {
+        let costs = self
+            .messages
+            .iter()
+            .map(|m| match m {
+                RecycleCommunicationAction::None => 0.0,
+                RecycleCommunicationAction::Announce(_) => self.communication_cost,
+            })
+            .collect::<Vec<_>>();
+        let prior = vec![1.0 / self.targets.len() as f32; self.targets.len()];
+        let rsa = (0..3)
+            .map(|kind| {
+                let lexicon = self
+                    .targets
+                    .iter()
+                    .map(|target| {
+                        self.messages
+                            .iter()
+                            .map(|m| match m {
+                                RecycleCommunicationAction::Announce(loc) => target[kind] == *loc,
+                                RecycleCommunicationAction::None => true,
+                            })
+                            .collect()
+                    })
+                    .collect::<Vec<_>>();
+                IteratedRSA::new(&lexicon, &costs, &prior, config)
+            })
+            .collect();
+        self.rsa = Some(rsa);
+
+        self
+    }
+
     fn number_of_announcements_consistent(&self, id: usize, object_id: usize) -> usize {
         let mut count = 0;
         for a in self.messages.iter() {
@@ -73,6 +111,12 @@ impl<const K: usize> RecycleCommunicationModel<K> {
         _ss: &RecycleState<K>,
     ) -> f32 {
         if let Some(object_id) = self.object_id_in_hand(s) {
+            if let Some(rsa) = &self.rsa {
+                return match self.messages.iter().position(|m| m == a) {
+                    Some(m) => rsa[self.kinds[object_id]].speaker(id, m),
+                    None => 0.0,
+                };
+            }
             match a {
                 RecycleCommunicationAction::Announce(loc) => {
                     let count = self.number_of_announcements_consistent(id, object_id);
@@ -160,6 +204,7 @@ impl<const K: usize> RecycleCommunicationModel<K> {
             targets: targets,
             messages: enumerate_communication_actions(),
             kinds: builder.kinds,
+            rsa: None,
         }
     }
 }
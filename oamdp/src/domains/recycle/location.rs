@@ -1,5 +1,5 @@
 use self::Location::*;
-use rand::{rngs::ThreadRng, seq::SliceRandom};
+use rand::{seq::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
@@ -12,7 +12,7 @@ pub enum Location {
 }
 
 impl Location {
-    pub(crate) fn random_location(rng: &mut ThreadRng) -> Location {
+    pub(crate) fn random_location<R: Rng>(rng: &mut R) -> Location {
         *[Compost, Recycle, Trash].choose(rng).unwrap()
     }
 }
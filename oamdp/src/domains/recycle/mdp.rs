@@ -215,6 +215,7 @@ impl<const K: usize> PMass<f32> for RecycleMDP<K> {
 
 impl<const K: usize> GetNextStateFromPMass for RecycleMDP<K> {}
 impl<const K: usize> GetNextStateMutFromImmut for RecycleMDP<K> {}
+impl<const K: usize> PMassMutFrom<f32> for RecycleMDP<K> {}
 
 impl<const K: usize> Cost for RecycleMDP<K> {
     fn cost(&self, s: &Self::State, _a: &Self::Action) -> f32 {
@@ -5,7 +5,7 @@ use mdp::{
 };
 use num_traits::FromPrimitive;
 use ordered_float::NotNan;
-use rand::{rngs::ThreadRng, Rng};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::fs;
 
@@ -16,20 +16,25 @@ use crate::{
         RecycleCommunicationModel, RecycleJointAction, RecycleMDP,
     },
     oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::RSAConfig,
 };
 
 #[derive(Serialize, Deserialize)]
 pub struct RecycleCOAMDPBuilder<const NITEM: usize, const N: usize> {
     #[serde(with = "serde_arrays")]
-    possible_goals: [[Location; 3]; N],
-    beta: f32,
-    communication_actions: Vec<RecycleCommunicationAction>,
-    max_t: usize,
-    builder: RecycleMDPBuilder<NITEM>,
-    true_goal: usize,
-    belief_cost_function: BeliefCostFunction<N>,
-    cost_type: Objective,
-    communication_cost: f32,
+    pub(crate) possible_goals: [[Location; 3]; N],
+    pub(crate) beta: f32,
+    pub(crate) communication_actions: Vec<RecycleCommunicationAction>,
+    pub(crate) max_t: usize,
+    pub(crate) builder: RecycleMDPBuilder<NITEM>,
+    pub(crate) true_goal: usize,
+    pub(crate) belief_cost_function: BeliefCostFunction<N>,
+    pub(crate) cost_type: Objective,
+    pub(crate) communication_cost: f32,
+    #[serde(default)]
+    pub(crate) rsa: Option<RSAConfig>,
+    #[serde(default)]
+    pub(crate) max_messages: Option<usize>,
 }
 
 impl<const NITEM: usize, const N: usize> RecycleCOAMDPBuilder<NITEM, N> {
@@ -37,9 +42,23 @@ impl<const NITEM: usize, const N: usize> RecycleCOAMDPBuilder<NITEM, N> {
         self.max_t = max_t;
         self
     }
+
+    pub fn set_rsa(mut self, config: RSAConfig) -> Self {
+        self.rsa = Some(config);
+        self
+    }
+
+    pub fn set_max_messages(mut self, max_messages: usize) -> Self {
+        self.max_messages = Some(max_messages);
+        self
+    }
+
+    pub fn max_messages(&self) -> Option<usize> {
+        self.max_messages
+    }
 }
 
-pub(crate) fn pick_available_messages(rng: &mut ThreadRng) -> Vec<RecycleCommunicationAction> {
+pub(crate) fn pick_available_messages<R: Rng>(rng: &mut R) -> Vec<RecycleCommunicationAction> {
     let mut messages = vec![RecycleCommunicationAction::None];
     for m in [
         RecycleCommunicationAction::Announce(Location::Compost),
@@ -74,7 +93,7 @@ fn kinds<const N: usize>() -> [usize; N] {
     kinds
 }
 
-fn initial_locs<const N: usize>(rng: &mut ThreadRng) -> [Location; N] {
+fn initial_locs<R: Rng, const N: usize>(rng: &mut R) -> [Location; N] {
     let mut initial_locs = [Location::Compost; N];
     for i in 0..N {
         initial_locs[i] = Location::random_location(rng);
@@ -83,7 +102,7 @@ fn initial_locs<const N: usize>(rng: &mut ThreadRng) -> [Location; N] {
 }
 
 impl<const NITEM: usize> RecycleCOAMDPBuilder<NITEM, 4> {
-    pub fn random_instance(rng: &mut ThreadRng) -> Self {
+    pub fn random_instance<R: Rng>(rng: &mut R) -> Self {
         let success_prob = rng.gen_range(0.3, 0.8);
         let alpha = rng.gen_range(0.0, 1.0);
         let communication_cost = rng.gen_range(0.0, 0.5);
@@ -109,6 +128,8 @@ impl<const NITEM: usize> RecycleCOAMDPBuilder<NITEM, 4> {
             belief_cost_function: BeliefCostFunction::get_legible_cost_function(0),
             cost_type: Objective::LinearCombination(alpha, 1.0 - alpha),
             communication_cost: communication_cost,
+            rsa: None,
+            max_messages: None,
         }
     }
 }
@@ -138,11 +159,14 @@ impl<const NITEM: usize, const N: usize>
             .map(|(a, b)| RecycleJointAction::new(**a, *b))
             .collect::<Vec<_>>();
 
-        let communication_model = RecycleCommunicationModel::from_targets(
+        let mut communication_model = RecycleCommunicationModel::from_targets(
             self.possible_goals.to_vec(),
             self.communication_cost,
             &self.builder,
         );
+        if let Some(config) = self.rsa {
+            communication_model = communication_model.set_rsa(config);
+        }
 
         let oamdp = FiniteHorizonWrapper::new(
             OAMDP::new(
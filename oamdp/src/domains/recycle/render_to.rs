@@ -1,3 +1,4 @@
+use mdp::mdp_traits::RenderTo;
 use tiny_skia::*;
 
 use crate::domains::recycle::{Location, RecycleCommunicationAction};
@@ -41,9 +42,24 @@ fn to_x_y(object_id: usize, location: Location) -> (f32, f32) {
     }
 }
 
+impl<const K: usize> RenderTo for RecycleMDP<K> {
+    fn render_to(&self, s: &RecycleState<K>, path: &str) {
+        self.render_with_message(s, &RecycleCommunicationAction::None, path);
+    }
+}
+
 impl<const K: usize> RecycleMDP<K> {
     pub fn render_to(&self, s: &RecycleState<K>, a: &RecycleJointAction, file_path: &str) {
         println!("rendering to {}", file_path);
+        self.render_with_message(s, &a.communication_action, file_path);
+    }
+
+    fn render_with_message(
+        &self,
+        s: &RecycleState<K>,
+        communication_action: &RecycleCommunicationAction,
+        file_path: &str,
+    ) {
         let mut pixmap = Pixmap::new(600, 400).unwrap();
         let mut stroke = Stroke::default();
         stroke.width = 2.0;
@@ -82,7 +98,7 @@ impl<const K: usize> RecycleMDP<K> {
         let recycle = Pixmap::decode_png(RECYCLING_BYTES).unwrap();
         self.draw_icon(480.0, 350.0, &recycle, &mut pixmap);
 
-        match a.communication_action {
+        match communication_action {
             RecycleCommunicationAction::Announce(Location::Compost) => {
                 self.draw_icon(100.0, 10.0, &compost, &mut pixmap);
             }
@@ -1,7 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 use super::location::Location;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct RecycleState<const L: usize> {
+    #[serde(with = "serde_arrays")]
     pub(crate) locs: [Location; L],
 }
 
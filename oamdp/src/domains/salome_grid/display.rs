@@ -0,0 +1,27 @@
+use mdp::{
+    into_inner::Inner,
+    mdp_traits::{DisplayState, StatesActions},
+    salome_grid::{SalomeGridState, SalomeGridWaterFireMDP},
+};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{
+    oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
+    traits::BeliefOverGoal,
+};
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<SalomeGridState, N>>
+    for OAMDP<SoftmaxModel<SalomeGridWaterFireMDP, N>, SalomeGridWaterFireMDP, A, N>
+where
+    Self: StatesActions<Action = A>,
+{
+    fn display(&self, s: &BeliefState<SalomeGridState, N>) {
+        let b = s.get_belief_over_goal();
+        for (mdp, p) in self.assumed_model.mdp_for_each_goal.iter().zip(b.iter()) {
+            println!("{:?}: {}", mdp.agent_type(), p);
+        }
+        self.mdp.display(&s.inner());
+    }
+}
@@ -0,0 +1,4 @@
+mod display;
+mod salome_oamdp_builder;
+
+pub use salome_oamdp_builder::SalomeOAMDPBuilder;
@@ -0,0 +1,123 @@
+use std::fs;
+
+use mdp::{
+    common::grid2d::GridMap,
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+    salome_grid::{
+        AgentType, SalomeGridAction, SalomeGridWaterFireMDP, SalomeGridWaterFirePartialMDP,
+    },
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+/// Water/fire grid where the observer infers the agent type instead of the
+/// goal: every hypothesis shares the goal of the map and differs in which
+/// terrain is cheap to cross.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SalomeOAMDPBuilder<const N: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) map: String,
+    #[serde_as(as = "[_; N]")]
+    pub possible_types: [AgentType; N],
+    pub(crate) true_type: usize,
+    #[serde_as(as = "[_; N]")]
+    pub(crate) initial_belief: [NotNan<f32>; N],
+    pub(crate) objective: Objective,
+}
+
+impl<const N: usize> SalomeOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/salome_grid/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn build_oamdp(
+        &self,
+    ) -> OAMDP<SoftmaxModel<SalomeGridWaterFireMDP, N>, SalomeGridWaterFireMDP, SalomeGridAction, N>
+    {
+        let partial_mdp =
+            SalomeGridWaterFirePartialMDP::from_grid_map(&GridMap::from_data(&self.map))
+                .unwrap_or_else(|e| panic!("{}: {}", self.map, e));
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &partial_mdp,
+            &softmax_policy,
+            self.possible_types,
+            self.true_type,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<const N: usize>
+    Build<
+        OAMDPFiniteHorizon<
+            SoftmaxModel<SalomeGridWaterFireMDP, N>,
+            SalomeGridWaterFireMDP,
+            SalomeGridAction,
+            N,
+        >,
+    > for SalomeOAMDPBuilder<N>
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<
+        SoftmaxModel<SalomeGridWaterFireMDP, N>,
+        SalomeGridWaterFireMDP,
+        SalomeGridAction,
+        N,
+    > {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BeliefOverGoal;
+    use mdp::mdp_traits::{InitialState, PMass};
+    use mdp::salome_grid::SalomeGridAction::*;
+
+    #[test]
+    fn test_salome_oamdp_belief_over_types() {
+        let oamdp = SalomeOAMDPBuilder::<2>::new(1).build_oamdp();
+        assert_eq!(
+            oamdp.assumed_model.mdp_for_each_goal[1].agent_type(),
+            AgentType::FireResistent
+        );
+
+        // The fire cell left of the start is cheap only for a fire-resistant agent.
+        let s = oamdp.initial_state();
+        let ss = PMass::<f32>::p_mass(&oamdp, &s, &Left)[0].0;
+        let b = ss.get_belief_over_goal();
+        assert!(b[1] > b[0]);
+    }
+}
@@ -0,0 +1,4 @@
+mod display;
+mod search_rescue_oamdp_builder;
+
+pub use search_rescue_oamdp_builder::{SearchRescueMDPE, SearchRescueOAMDPBuilder};
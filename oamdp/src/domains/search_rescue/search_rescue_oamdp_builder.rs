@@ -0,0 +1,155 @@
+use std::fs;
+
+use mdp::{
+    finite_horizon_wrapper::FiniteHorizonWrapper,
+    mdp_traits::Build,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+    search_rescue::{
+        Coordinate, ObstacleCompatibility, SearchRescueAction, SearchRescueMDP,
+        SearchRescueParameter, SearchRescuePartialMDP,
+    },
+    state_enumerable_wrapper::StateEnumerableWrapper,
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+pub type SearchRescueMDPE = StateEnumerableWrapper<SearchRescueMDP>;
+
+/// The observer does not know where the victim is: each possible goal is one
+/// of the victim candidates, rescued from the same base.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+pub struct SearchRescueOAMDPBuilder<const N: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) height: usize,
+    pub(crate) width: usize,
+    pub(crate) obstacles: Vec<(usize, usize)>,
+    pub(crate) base_coordinate: Coordinate,
+    pub(crate) obstacle_compatibility: ObstacleCompatibility,
+    #[serde_as(as = "[_; N]")]
+    pub victim_candidates: [Coordinate; N],
+    pub(crate) true_goal: usize,
+    #[serde_as(as = "[_; N]")]
+    pub(crate) initial_belief: [NotNan<f32>; N],
+    pub(crate) objective: Objective,
+}
+
+impl<const N: usize> SearchRescueOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/search_rescue/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn possible_goals(&self) -> [SearchRescueParameter; N] {
+        self.victim_candidates.map(|c| {
+            SearchRescueParameter::new(self.base_coordinate, self.obstacle_compatibility)
+                .set_victim_coordinate(c)
+        })
+    }
+
+    pub fn partial_mdp(&self) -> SearchRescuePartialMDP {
+        SearchRescuePartialMDP::new(
+            self.height,
+            self.width,
+            self.obstacles.clone(),
+            self.victim_candidates[self.true_goal],
+        )
+    }
+
+    pub fn build_oamdp(
+        &self,
+    ) -> OAMDP<SoftmaxModel<SearchRescueMDPE, N>, SearchRescueMDPE, SearchRescueAction, N> {
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &self.partial_mdp(),
+            &softmax_policy,
+            self.possible_goals(),
+            self.true_goal,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<const N: usize>
+    Build<
+        OAMDPFiniteHorizon<
+            SoftmaxModel<SearchRescueMDPE, N>,
+            SearchRescueMDPE,
+            SearchRescueAction,
+            N,
+        >,
+    > for SearchRescueOAMDPBuilder<N>
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<
+        SoftmaxModel<SearchRescueMDPE, N>,
+        SearchRescueMDPE,
+        SearchRescueAction,
+        N,
+    > {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::BeliefOverGoal;
+    use mdp::mdp_traits::InitialState;
+
+    fn assert_instance<const N: usize>(
+        id: usize,
+        candidates: [(i64, i64); N],
+        true_goal: usize,
+        initial_belief: [f32; N],
+    ) {
+        let builder = SearchRescueOAMDPBuilder::<N>::new(id);
+        let base = Coordinate::new(4, 2);
+        let expected = candidates.map(|(i, j)| {
+            SearchRescueParameter::new(base, ObstacleCompatibility::High)
+                .set_victim_coordinate(Coordinate::new(i, j))
+        });
+        assert_eq!(builder.possible_goals(), expected);
+        assert_eq!(builder.true_goal, true_goal);
+
+        let oamdp = builder.build_oamdp();
+        let b = oamdp.initial_state().get_belief_over_goal();
+        for (b_i, p_i) in b.iter().zip(initial_belief.iter()) {
+            assert!((b_i.into_inner() - p_i).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_search_rescue_oamdp_builder() {
+        assert_instance(1, [(0, 4), (0, 0)], 0, [0.5, 0.5]);
+        assert_instance(2, [(0, 0), (0, 2)], 1, [0.5, 0.5]);
+        assert_instance(3, [(0, 0), (0, 4)], 0, [0.5, 0.5]);
+        assert_instance(11, [(0, 0), (0, 2), (0, 4)], 1, [1.0 / 3.0; 3]);
+    }
+}
@@ -8,6 +8,7 @@ use std::hash::Hash;
 
 use crate::{
     oamdp::{oamdp::OAMDP, BeliefState},
+    observer_model::SoftmaxModel,
     traits::BeliefOverGoal,
 };
 
@@ -25,3 +26,16 @@ where
         self.mdp.display(&s.inner());
     }
 }
+
+impl<A: Eq + Copy + Debug + Hash, const N: usize>
+    DisplayState<BeliefState<SimpleAVVehicleInFrontState, N>>
+    for OAMDP<SoftmaxModel<SimpleAVVehicleInFrontMDP, N>, SimpleAVVehicleInFrontMDP, A, N>
+where
+    Self: StatesActions<Action = A>,
+{
+    fn display(&self, s: &BeliefState<SimpleAVVehicleInFrontState, N>) {
+        let b = s.get_belief_over_goal();
+        println!("{:?}", b);
+        self.mdp.display(&s.inner());
+    }
+}
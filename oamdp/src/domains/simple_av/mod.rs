@@ -8,6 +8,7 @@ mod display;
 // mod example_traffic_light;
 mod joint_action;
 mod oamdp_builder;
+mod simple_av_oamdp_builder;
 // mod simple_av_domain_evaluator;
 // mod simple_av_tabular_policy_wrapper;
 
@@ -16,5 +17,6 @@ pub use communication_action::AVCommunicationAction;
 // pub use example::example;
 // pub use example_traffic_light::example_traffic_light;
 pub use oamdp_builder::LegibleAVBuilder;
+pub use simple_av_oamdp_builder::SimpleAVOAMDPBuilder;
 // pub use simple_av_domain_evaluator::SimpleAVDomainEvaluator;
 // pub use simple_av_tabular_policy_wrapper::SimpleAVTabularPolicyWrapper;
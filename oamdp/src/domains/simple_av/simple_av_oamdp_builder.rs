@@ -0,0 +1,51 @@
+use mdp::simple_av::{SimpleAVParameter, SimpleAVPartialMDP};
+
+use crate::domains::SoftmaxOAMDPBuilder;
+
+/// Implicit-communication counterpart of `SimpleAVCOAMDPBuilder`: the
+/// observer infers the parameter of the vehicle in front from the motion of
+/// the ego vehicle only.
+pub type SimpleAVOAMDPBuilder<const N: usize> =
+    SoftmaxOAMDPBuilder<SimpleAVPartialMDP, SimpleAVParameter, N>;
+
+impl<const N: usize> SimpleAVOAMDPBuilder<N> {
+    pub fn new(instance_id: usize) -> Self {
+        Self::from_instance("simple_av", instance_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::simple_av::{
+        SimpleAVParameter::*, SimpleAVVehicleInFrontMDP, SimpleAVVehicleInFrontState,
+        VehicleConfiguration,
+    };
+
+    use crate::domains::softmax_oamdp_builder::tests::assert_instances;
+
+    use super::*;
+
+    fn start(y: usize, dy: i32) -> SimpleAVVehicleInFrontState {
+        SimpleAVVehicleInFrontState::new(
+            VehicleConfiguration::new(y, dy),
+            VehicleConfiguration::new(y + 12, dy),
+        )
+    }
+
+    #[test]
+    fn test_simple_av_oamdp_instances() {
+        let goals = [NonYield(35, 2, 3), Stopping(6, 8), Stopping(15, 18)];
+        assert_instances::<SimpleAVPartialMDP, SimpleAVParameter, SimpleAVVehicleInFrontMDP, 3>(
+            "simple_av",
+            &[
+                (goals, 1, start(0, 2)),
+                (goals, 2, start(0, 2)),
+                (
+                    [NonYield(12, 1, 2), Stopping(4, 6), Stopping(8, 10)],
+                    1,
+                    start(0, 1),
+                ),
+            ],
+        );
+    }
+}
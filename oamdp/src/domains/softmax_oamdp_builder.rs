@@ -0,0 +1,154 @@
+use std::fmt::Debug;
+use std::fs;
+use std::hash::Hash;
+
+use mdp::{
+    finite_horizon_wrapper::FiniteHorizonWrapper, mdp_traits::*,
+    policy::softmax_policy::SoftmaxPolicyBuilder,
+};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    oamdp::{oamdp::OAMDP, OAMDPFiniteHorizon},
+    observer_model::SoftmaxModel,
+};
+
+/// Builds an implicit-communication OAMDP with a softmax observer for any
+/// domain whose partial MDP can be completed with a goal. Instances are read
+/// from `src/domains/<domain>/oamdp_instances/<id>.yaml`.
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "PM: Serialize, G: Serialize",
+    deserialize = "PM: Deserialize<'de>, G: Deserialize<'de>"
+))]
+pub struct SoftmaxOAMDPBuilder<PM, G, const N: usize> {
+    pub(crate) beta: f32,
+    pub(crate) observability_assumption: ObserveabilityAssumption,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) horizon: usize,
+    pub(crate) partial_mdp: PM,
+    #[serde_as(as = "[_; N]")]
+    pub possible_goals: [G; N],
+    pub(crate) true_goal: usize,
+    #[serde_as(as = "[_; N]")]
+    pub(crate) initial_belief: [NotNan<f32>; N],
+    pub(crate) objective: Objective,
+}
+
+impl<PM, G, const N: usize> SoftmaxOAMDPBuilder<PM, G, N>
+where
+    for<'de> PM: Deserialize<'de>,
+    for<'de> G: Deserialize<'de>,
+{
+    pub fn from_instance(domain: &str, instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/{}/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            domain,
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+}
+
+impl<PM, G: Copy, const N: usize> SoftmaxOAMDPBuilder<PM, G, N> {
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub fn build_oamdp<M>(&self) -> OAMDP<SoftmaxModel<M, N>, M, M::Action, N>
+    where
+        for<'a> PM: BuildFrom<&'a G, M>,
+        M: ActionEnumerable
+            + ActionAvailability
+            + StateEnumerable
+            + ExplicitTransition
+            + PMass<f32>
+            + IsTerminal
+            + Cost,
+        M::Action: Eq + Debug + Hash + Copy,
+    {
+        let softmax_policy = SoftmaxPolicyBuilder::new(self.beta);
+
+        OAMDP::new_with_initial_belief(
+            &self.partial_mdp,
+            &softmax_policy,
+            self.possible_goals,
+            self.true_goal,
+            self.belief_cost_type,
+            self.objective,
+            self.observability_assumption,
+            self.initial_belief,
+        )
+    }
+}
+
+impl<PM, G: Copy, M, const N: usize> Build<OAMDPFiniteHorizon<SoftmaxModel<M, N>, M, M::Action, N>>
+    for SoftmaxOAMDPBuilder<PM, G, N>
+where
+    for<'a> PM: BuildFrom<&'a G, M>,
+    M: ActionEnumerable
+        + ActionAvailability
+        + StateEnumerable
+        + ExplicitTransition
+        + PMass<f32>
+        + IsTerminal
+        + Cost,
+    M::Action: Eq + Debug + Hash + Copy,
+{
+    fn build(self) -> OAMDPFiniteHorizon<SoftmaxModel<M, N>, M, M::Action, N> {
+        FiniteHorizonWrapper::new(self.build_oamdp(), self.horizon)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+    use std::fs;
+
+    use super::*;
+
+    /// Checks that every shipped instance of `domain` parses into the
+    /// expected goals, true goal and initial state without solving it.
+    pub(crate) fn assert_instances<PM, G, M, const N: usize>(
+        domain: &str,
+        expected: &[([G; N], usize, M::State)],
+    ) where
+        for<'de> PM: Deserialize<'de>,
+        for<'de> G: Deserialize<'de>,
+        for<'a> PM: BuildFrom<&'a G, M>,
+        G: PartialEq + Debug,
+        M: InitialState,
+        M::State: Debug,
+    {
+        let dir = format!(
+            "{}/src/domains/{}/oamdp_instances",
+            env!("CARGO_MANIFEST_DIR"),
+            domain
+        );
+        assert_eq!(fs::read_dir(dir).unwrap().count(), expected.len());
+
+        for (id, (goals, true_goal, s)) in expected.iter().enumerate() {
+            let builder = SoftmaxOAMDPBuilder::<PM, G, N>::from_instance(domain, id + 1);
+            assert_eq!(&builder.possible_goals, goals);
+            assert_eq!(builder.true_goal, *true_goal);
+            let total = builder
+                .initial_belief
+                .iter()
+                .map(|p| p.into_inner())
+                .sum::<f32>();
+            assert!((total - 1.0).abs() < 1e-5);
+            for g in builder.possible_goals.iter() {
+                let mdp = builder.partial_mdp.build_from(g);
+                assert_eq!(&mdp.initial_state(), s);
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@ use mdp::{
 
 use crate::{
     oamdp::oamdp::OAMDP,
-    traits::{DomainAction, EnumerateDomainAction, EnumerateMessage, Message},
+    traits::{DomainAction, EnumerateDomainAction, EnumerateMessage, Message, SilentMessage},
 };
 
 use super::{
@@ -48,3 +48,13 @@ where
         self.assumed_model.messages.iter()
     }
 }
+
+impl<const N: usize, const NL: usize> SilentMessage
+    for OAMDP<SpellingCommunicationModel<NL, N>, SpellingMDPE<NL>, SpellingJointAction, N>
+where
+    Self: StatesActions<Action = SpellingJointAction>,
+{
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        *m == SpellingCommunicationAction::None
+    }
+}
@@ -18,6 +18,7 @@ use crate::domains::spelling::communication_action::SpellingCommunicationAction;
 use crate::domains::spelling::communication_model::SpellingCommunicationModel;
 use crate::domains::spelling::joint_action::SpellingJointAction;
 use crate::oamdp::oamdp::OAMDP;
+use crate::observer_model::RSAConfig;
 
 use crate::oamdp::OAMDPFiniteHorizon;
 
@@ -31,6 +32,8 @@ pub struct SpellingCOAMDPBuilder<const N: usize> {
     belief_cost_function: BeliefCostFunction<N>,
     cost_type: Objective,
     communication_cost: f32,
+    rsa: Option<RSAConfig>,
+    max_messages: Option<usize>,
 }
 
 impl<const N: usize> SpellingCOAMDPBuilder<N> {
@@ -38,6 +41,20 @@ impl<const N: usize> SpellingCOAMDPBuilder<N> {
         self.max_t = max_t;
         self
     }
+
+    pub fn set_rsa(mut self, config: RSAConfig) -> Self {
+        self.rsa = Some(config);
+        self
+    }
+
+    pub fn set_max_messages(mut self, max_messages: usize) -> Self {
+        self.max_messages = Some(max_messages);
+        self
+    }
+
+    pub fn max_messages(&self) -> Option<usize> {
+        self.max_messages
+    }
 }
 
 fn get_possible_goals(_id: usize) -> [[Letter; 4]; 3] {
@@ -145,10 +162,7 @@ fn get_builder(id: usize) -> SpellingMDPBuilder<4> {
 
 fn get_belief_cost_function(id: usize) -> BeliefCostFunction<3> {
     match id {
-        3 => BeliefCostFunction::Disimulation,
-        8 => BeliefCostFunction::Disimulation,
-        9 => BeliefCostFunction::Disimulation,
-        10 => BeliefCostFunction::Disimulation,
+        3 | 8 | 9 | 10 => BeliefCostFunction::Disimulation(get_true_goal(id)),
         _ => BeliefCostFunction::get_legible_cost_function(get_true_goal(id)),
     }
 }
@@ -180,6 +194,8 @@ impl SpellingCOAMDPBuilder<3> {
             belief_cost_function: get_belief_cost_function(instance_id),
             cost_type: get_objective(instance_id),
             communication_cost: 0.25,
+            rsa: None,
+            max_messages: None,
         }
     }
 }
@@ -206,12 +222,15 @@ impl
             .map(|(a, b)| SpellingJointAction::new(*a, *b))
             .collect::<Vec<_>>();
 
-        let communication_model = SpellingCommunicationModel::from_targets(
+        let mut communication_model = SpellingCommunicationModel::from_targets(
             &self.builder,
             self.possible_goals,
             self.communication_cost,
             self.beta,
         );
+        if let Some(config) = self.rsa {
+            communication_model = communication_model.set_rsa(config);
+        }
 
         let oamdp = FiniteHorizonWrapper::new(
             OAMDP::new(
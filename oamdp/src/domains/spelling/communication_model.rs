@@ -13,6 +13,7 @@ use mdp::state_enumerable_wrapper::StateEnumerableWrapper;
 use mdp::value_iteration::value_iteration_ssp;
 use mdp::value_iteration::ValueTable;
 
+use crate::observer_model::{IteratedRSA, RSAConfig};
 use crate::traits::ProbSassGivenTheta;
 
 use super::communication_action::SpellingCommunicationAction;
@@ -25,6 +26,7 @@ pub struct SpellingCommunicationModel<const NL: usize, const N: usize> {
     pub(crate) targets: [[Letter; NL]; N],
     pub(crate) messages: Vec<SpellingCommunicationAction>,
     pub(crate) letter_locs: [(usize, usize); NL],
+    rsa: Option<Vec<IteratedRSA>>,
 }
 
 impl<const NL: usize, const N: usize> SpellingCommunicationModel<NL, N> {
@@ -48,9 +50,44 @@ impl<const NL: usize, const N: usize> SpellingCommunicationModel<NL, N> {
                 SpellingCommunicationAction::Announce(S),
             ],
             letter_locs,
+            rsa: None,
         }
     }
 
+    /// Replaces the fixed announcement probabilities at letter locations with
+    /// an iterated RSA speaker, one per location.
+    pub fn set_rsa(mut self, config: RSAConfig) -> Self {
+        let costs = self
+            .messages
+            .iter()
+            .map(|m| match m {
+                SpellingCommunicationAction::None => 0.0,
+                SpellingCommunicationAction::Announce(_) => self.communication_cost,
+            })
+            .collect::<Vec<_>>();
+        let rsa = (0..NL)
+            .map(|loc_id| {
+                let lexicon = self
+                    .targets
+                    .iter()
+                    .map(|target| {
+                        self.messages
+                            .iter()
+                            .map(|m| match m {
+                                SpellingCommunicationAction::Announce(l) => target[loc_id] == *l,
+                                SpellingCommunicationAction::None => true,
+                            })
+                            .collect()
+                    })
+                    .collect::<Vec<_>>();
+                IteratedRSA::new(&lexicon, &costs, &[1.0 / N as f32; N], config)
+            })
+            .collect();
+        self.rsa = Some(rsa);
+
+        self
+    }
+
     fn loc_id(&self, s: &SpellingState<NL>) -> Option<usize> {
         for (i, loc) in self.letter_locs.iter().enumerate() {
             if s.coord == Coordinate2::new(loc.0 as i32, loc.1 as i32) {
@@ -68,6 +105,12 @@ impl<const NL: usize, const N: usize> SpellingCommunicationModel<NL, N> {
         _ss: &SpellingState<NL>,
     ) -> f32 {
         if let Some(loc_id) = self.loc_id(s) {
+            if let Some(rsa) = &self.rsa {
+                return match self.messages.iter().position(|m| m == a) {
+                    Some(m) => rsa[loc_id].speaker(id, m),
+                    None => 0.0,
+                };
+            }
             match a {
                 SpellingCommunicationAction::Announce(l) => {
                     if self.targets[id][loc_id] == *l {
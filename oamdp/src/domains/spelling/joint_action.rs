@@ -38,6 +38,12 @@ impl From<(SpellingAction, SpellingCommunicationAction)> for SpellingJointAction
     }
 }
 
+impl From<SpellingJointAction> for SpellingCommunicationAction {
+    fn from(a: SpellingJointAction) -> Self {
+        a.communication_action
+    }
+}
+
 impl From<SpellingAction> for SpellingJointAction {
     fn from(a: SpellingAction) -> Self {
         SpellingJointAction {
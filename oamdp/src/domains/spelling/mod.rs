@@ -11,7 +11,7 @@ mod oamdp_instances;
 
 pub use coamdp_instances::SpellingCOAMDPBuilder;
 pub use joint_action::SpellingJointAction;
-pub use oamdp_instances::SpellingOAMDPBuilder;
+pub use oamdp_instances::{SpellingOAMDPBuilder, SpellingOAMDPInstance};
 // pub use example::example;
 //pub use spelling_domain_evaluator::SpellingDomainEvaluator;
 // pub use spelling_tabular_policy_wrapper::SpellingTabularPolicyWrapper;
@@ -1,3 +1,3 @@
 mod spelling_oamdp_builder;
 
-pub use spelling_oamdp_builder::SpellingOAMDPBuilder;
+pub use spelling_oamdp_builder::{SpellingOAMDPBuilder, SpellingOAMDPInstance};
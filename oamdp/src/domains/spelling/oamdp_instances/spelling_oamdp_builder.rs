@@ -1,3 +1,6 @@
+use std::fs;
+use std::path::Path;
+
 use mdp::common::coordinate2::Coordinate2;
 use mdp::finite_horizon_wrapper::FiniteHorizonWrapper;
 use mdp::heuristic::ZeroHeuristic;
@@ -12,6 +15,7 @@ use mdp::spelling::SpellingState;
 use mdp::state_enumerable_wrapper::StateEnumerableWrapper;
 use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicy;
 use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicyBuilder;
+use serde::{Deserialize, Serialize};
 
 use crate::belief_cost_function::BeliefCostType;
 use crate::belief_cost_function::Objective;
@@ -26,13 +30,61 @@ type SpellingMDPE = StateEnumerableWrapper<SpellingMDP<4>>;
 // type SpellingMDPE = SpellingMDP<4>;
 
 pub struct SpellingOAMDPBuilder<PB, const N: usize> {
-    possible_goals: [[Letter; 4]; N],
-    policy_builder: PB,
-    max_t: usize,
-    builder: SpellingMDPBuilder<4>,
-    belief_cost_type: BeliefCostType,
-    true_goal: usize,
-    objective: Objective,
+    pub(crate) possible_goals: [[Letter; 4]; N],
+    pub(crate) policy_builder: PB,
+    pub(crate) max_t: usize,
+    pub(crate) builder: SpellingMDPBuilder<4>,
+    pub(crate) belief_cost_type: BeliefCostType,
+    pub(crate) true_goal: usize,
+    pub(crate) objective: Objective,
+}
+
+/// YAML schema of a spelling instance with three candidate words.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct SpellingOAMDPInstance {
+    pub beta: f32,
+    pub belief_cost_type: BeliefCostType,
+    pub horizon: usize,
+    pub height: usize,
+    pub width: usize,
+    pub obstacles: Vec<Coordinate2>,
+    pub letter_locs: [(usize, usize); 4],
+    pub initial_state: Coordinate2,
+    pub possible_goals: [[Letter; 4]; 3],
+    pub true_goal: usize,
+    pub objective: Objective,
+}
+
+impl SpellingOAMDPInstance {
+    pub fn from_file(path: &str) -> Self {
+        let data = fs::read_to_string(path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+
+    /// Instances other than the hard-coded ones, e.g. generated ones, are
+    /// read from `src/domains/spelling/oamdp_instances/<id>.yaml`.
+    pub fn from_id(instance_id: usize) -> Option<Self> {
+        let path = format!(
+            "{}/src/domains/spelling/oamdp_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            instance_id
+        );
+        if Path::new(&path).exists() {
+            Some(Self::from_file(&path))
+        } else {
+            None
+        }
+    }
+
+    pub fn partial_mdp(&self) -> SpellingMDPBuilder<4> {
+        SpellingMDPBuilder::new(
+            self.height,
+            self.width,
+            self.obstacles.clone(),
+            self.letter_locs,
+            SpellingState::new(self.initial_state, [A, A, A, A]),
+        )
+    }
 }
 
 impl<PB, const N: usize> SpellingOAMDPBuilder<PB, N> {
@@ -177,8 +229,25 @@ fn get_objective(_id: usize) -> Objective {
     Objective::LinearCombination(1.0, 0.5)
 }
 
+impl<PB> SpellingOAMDPBuilder<PB, 3> {
+    pub fn from_instance(instance: &SpellingOAMDPInstance, policy_builder: PB) -> Self {
+        SpellingOAMDPBuilder {
+            possible_goals: instance.possible_goals,
+            policy_builder,
+            builder: instance.partial_mdp(),
+            max_t: instance.horizon,
+            belief_cost_type: instance.belief_cost_type,
+            true_goal: instance.true_goal,
+            objective: instance.objective,
+        }
+    }
+}
+
 impl SpellingOAMDPBuilder<SoftmaxPolicyBuilder, 3> {
     pub fn new(instance_id: usize) -> Self {
+        if let Some(instance) = SpellingOAMDPInstance::from_id(instance_id) {
+            return Self::from_instance(&instance, SoftmaxPolicyBuilder::new(instance.beta));
+        }
         let true_goal = get_true_goal(instance_id);
         let builder = get_builder(instance_id);
         let possible_goals = get_possible_goals(instance_id);
@@ -197,6 +266,9 @@ impl SpellingOAMDPBuilder<SoftmaxPolicyBuilder, 3> {
 
 impl SpellingOAMDPBuilder<RTDPSoftmaxPolicyBuilder, 3> {
     pub fn new_rtdp(instance_id: usize) -> Self {
+        if let Some(instance) = SpellingOAMDPInstance::from_id(instance_id) {
+            return Self::from_instance(&instance, RTDPSoftmaxPolicyBuilder::new(instance.beta));
+        }
         let true_goal = get_true_goal(instance_id);
         let builder = get_builder(instance_id);
         let possible_goals = get_possible_goals(instance_id);
@@ -0,0 +1,91 @@
+use mdp::mdp_traits::{
+    ActionAvailability, ActionEnumerable, DCost, GetNextStateMut, InitialState, IsTerminal,
+    StatesActions,
+};
+use mdp::policy::policy_traits::GetActionMut;
+use mdp::value_estimator::CostEstimatorMut;
+use rand::rngs::ThreadRng;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::oamdp::oamdp::OAMDP;
+use crate::trace::{record_episode_with, Trace, TraceState};
+use crate::traits::ProbSassGivenTheta;
+
+/// An episode together with the Q-values the solver saw at every step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectedEpisode<S, A, const N: usize> {
+    pub trace: Trace<S, A, N>,
+    pub q_values: Vec<Vec<(A, f32)>>,
+}
+
+/// Q-value of every action available at `s`, cheapest first.
+pub fn q_values<M, V>(estimator: &mut V, s: &M::State, mdp: &mut M) -> Vec<(M::Action, f32)>
+where
+    M: ActionEnumerable + ActionAvailability,
+    V: CostEstimatorMut<M>,
+{
+    let actions = mdp
+        .enumerate_actions()
+        .copied()
+        .filter(|a| mdp.action_available(s, a))
+        .collect::<Vec<_>>();
+    let mut q = actions
+        .into_iter()
+        .map(|a| (a, estimator.get_qsa_ssp_mut(s, &a, mdp)))
+        .collect::<Vec<_>>();
+    q.sort_by(|(_, x), (_, y)| x.total_cmp(y));
+    q
+}
+
+/// Runs `solver` as the policy and records, next to each step, the
+/// Q-values it assigns to the actions it chose between.
+pub fn record_inspected_episode<M, P, S, const N: usize>(
+    mdp: &mut M,
+    solver: &mut P,
+    rng: &mut ThreadRng,
+) -> InspectedEpisode<S, M::Action, N>
+where
+    M: InitialState + IsTerminal + GetNextStateMut + DCost + ActionEnumerable + ActionAvailability,
+    M::State: TraceState<S, N>,
+    P: GetActionMut<M::State, M> + CostEstimatorMut<M>,
+    S: Copy,
+{
+    let mut q = vec![];
+    let trace = record_episode_with(mdp, solver, rng, |s, mdp, solver| {
+        q.push(if mdp.is_terminal(s) {
+            vec![]
+        } else {
+            q_values(solver, s, mdp)
+        });
+    });
+    InspectedEpisode { trace, q_values: q }
+}
+
+impl<OM, M, A, const N: usize> OAMDP<OM, M, A, N>
+where
+    M: StatesActions,
+    A: Eq + Debug + Hash + Copy,
+    for<'a> &'a OM: ProbSassGivenTheta<M::State, A>,
+{
+    /// `P(s' | s, a, goal)` of each recorded transition under the observer
+    /// model, the evidence behind every belief update. `None` at the last
+    /// step.
+    pub fn goal_likelihoods(&self, trace: &Trace<M::State, A, N>) -> Vec<Option<[f32; N]>> {
+        trace
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(k, step)| match (step.a, trace.steps.get(k + 1)) {
+                (Some(a), Some(next)) => {
+                    let mut likelihoods = [0.0; N];
+                    for (id, l) in likelihoods.iter_mut().enumerate() {
+                        *l = (&self.assumed_model).prob_sass_given_theta(id, &step.s, &a, &next.s);
+                    }
+                    Some(likelihoods)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,5 @@
+mod inspect;
+mod viewer;
+
+pub use inspect::{q_values, record_inspected_episode, InspectedEpisode};
+pub use viewer::{EpisodeViewer, ViewerFrame};
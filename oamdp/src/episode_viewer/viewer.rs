@@ -0,0 +1,337 @@
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
+use ratatui::Frame;
+use std::fmt::Debug;
+use std::io;
+
+use crate::trace::Trace;
+
+const BAR_WIDTH: usize = 20;
+
+/// Everything shown for one step of an episode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewerFrame<A, const N: usize> {
+    pub t: usize,
+    pub text: String,
+    pub belief: [f32; N],
+    pub action: Option<A>,
+    pub cost: f32,
+    pub q_values: Vec<(A, f32)>,
+    pub likelihoods: Option<[f32; N]>,
+}
+
+/// Terminal viewer stepping through an episode, showing the domain state,
+/// the observer's belief, the solver's Q-values and the per-goal likelihood
+/// of the observed transition.
+pub struct EpisodeViewer<A, const N: usize> {
+    frames: Vec<ViewerFrame<A, N>>,
+    goal_names: Vec<String>,
+    cursor: usize,
+}
+
+fn bar(p: f32) -> String {
+    let filled = (p.clamp(0.0, 1.0) * BAR_WIDTH as f32).round() as usize;
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
+
+impl<A: Copy + Debug + PartialEq, const N: usize> EpisodeViewer<A, N> {
+    pub fn new(frames: Vec<ViewerFrame<A, N>>) -> Self {
+        assert!(!frames.is_empty());
+        EpisodeViewer {
+            frames,
+            goal_names: (0..N).map(|k| format!("G{}", k)).collect(),
+            cursor: 0,
+        }
+    }
+
+    /// `q_values` and `likelihoods` are per step of `trace`; missing entries
+    /// are shown as empty. `text` draws a domain state.
+    pub fn from_trace<S, F: Fn(&S) -> String>(
+        trace: &Trace<S, A, N>,
+        q_values: &[Vec<(A, f32)>],
+        likelihoods: &[Option<[f32; N]>],
+        text: F,
+    ) -> Self {
+        let frames = trace
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(k, step)| ViewerFrame {
+                t: step.t,
+                text: text(&step.s),
+                belief: step.belief,
+                action: step.a,
+                cost: step.cost,
+                q_values: q_values.get(k).cloned().unwrap_or_default(),
+                likelihoods: likelihoods.get(k).copied().flatten(),
+            })
+            .collect();
+        EpisodeViewer::new(frames)
+    }
+
+    pub fn set_goal_names(mut self, goal_names: &[&str]) -> Self {
+        for (name, new_name) in self.goal_names.iter_mut().zip(goal_names) {
+            *name = new_name.to_string();
+        }
+
+        self
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn current(&self) -> &ViewerFrame<A, N> {
+        &self.frames[self.cursor]
+    }
+
+    pub fn next(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.frames.len() - 1);
+    }
+
+    pub fn prev(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn first(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn last(&mut self) {
+        self.cursor = self.frames.len() - 1;
+    }
+
+    /// Returns `false` once the viewer should close.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('n') | KeyCode::Char(' ') => {
+                self.next()
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('p') => self.prev(),
+            KeyCode::Home | KeyCode::Char('g') => self.first(),
+            KeyCode::End | KeyCode::Char('G') => self.last(),
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            _ => {}
+        }
+        true
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let current = self.current();
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .areas(main);
+        let [header, domain] =
+            Layout::vertical([Constraint::Length(4), Constraint::Min(0)]).areas(left);
+        let [belief, q, likelihood] = Layout::vertical([
+            Constraint::Length(N as u16 + 2),
+            Constraint::Min(0),
+            Constraint::Length(N as u16 + 3),
+        ])
+        .areas(right);
+
+        let total_cost = self.frames[..=self.cursor]
+            .iter()
+            .map(|f| f.cost)
+            .sum::<f32>();
+        let action = match &current.action {
+            Some(a) => format!("{:?}", a),
+            None => "-".to_string(),
+        };
+        frame.render_widget(
+            Paragraph::new(format!(
+                "Step {}/{}  t = {}\nAction: {}\nCost: {:.3}  Total: {:.3}",
+                self.cursor + 1,
+                self.frames.len(),
+                current.t,
+                action,
+                current.cost,
+                total_cost
+            ))
+            .block(Block::default().borders(Borders::ALL).title("Episode")),
+            header,
+        );
+        frame.render_widget(
+            Paragraph::new(current.text.as_str())
+                .block(Block::default().borders(Borders::ALL).title("State")),
+            domain,
+        );
+
+        let rows = current.belief.iter().enumerate().map(|(k, p)| {
+            Row::new(vec![
+                self.goal_names[k].clone(),
+                bar(*p),
+                format!("{:.3}", p),
+            ])
+        });
+        frame.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(8),
+                    Constraint::Length(BAR_WIDTH as u16 + 1),
+                    Constraint::Length(6),
+                ],
+            )
+            .block(Block::default().borders(Borders::ALL).title("Belief")),
+            belief,
+        );
+
+        let best = current.q_values.first().map(|(_, v)| *v).unwrap_or(0.0);
+        let rows = current.q_values.iter().map(|(a, v)| {
+            let chosen = current.action.as_ref() == Some(a);
+            let row = Row::new(vec![
+                if chosen { ">" } else { "" }.to_string(),
+                format!("{:?}", a),
+                format!("{:.3}", v),
+                format!("+{:.3}", v - best),
+            ]);
+            if chosen {
+                row.style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                row
+            }
+        });
+        frame.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(1),
+                    Constraint::Min(12),
+                    Constraint::Length(10),
+                    Constraint::Length(10),
+                ],
+            )
+            .header(Row::new(vec!["", "Action", "Q", "Regret"]))
+            .block(Block::default().borders(Borders::ALL).title("Q-values")),
+            q,
+        );
+
+        let rows = current.likelihoods.iter().flat_map(|l| {
+            let total = l.iter().sum::<f32>();
+            l.iter().enumerate().map(move |(k, p)| {
+                Row::new(vec![
+                    self.goal_names[k].clone(),
+                    format!("{:.4}", p),
+                    format!("{:.3}", if total > 0.0 { p / total } else { 0.0 }),
+                ])
+            })
+        });
+        frame.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(8),
+                    Constraint::Length(12),
+                    Constraint::Length(12),
+                ],
+            )
+            .header(Row::new(vec!["Goal", "P(s'|s,a)", "Normalized"]))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Observer likelihood"),
+            ),
+            likelihood,
+        );
+
+        frame.render_widget(
+            Paragraph::new("←/h prev  →/l next  g first  G last  q quit"),
+            footer,
+        );
+    }
+
+    /// Takes over the terminal until the user quits.
+    pub fn run(&mut self) -> io::Result<()> {
+        let mut terminal = ratatui::init();
+        let result = loop {
+            if let Err(e) = terminal.draw(|frame| self.draw(frame)) {
+                break Err(e);
+            }
+            match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                    if !self.handle_key(key.code) {
+                        break Ok(());
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => break Err(e),
+            }
+        };
+        ratatui::restore();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::baker_grid::BakerGridState;
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::mdp_traits::Build;
+    use rand::thread_rng;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use rtdp::rtdp::RTDP;
+
+    use crate::domains::baker_grid::{BakerCOAMDPBuilder, BakerJointAction};
+    use crate::episode_viewer::{record_inspected_episode, InspectedEpisode};
+
+    use super::*;
+
+    #[test]
+    fn test_inspect_and_view_episode() {
+        let mut rng = thread_rng();
+        let mut oamdp = BakerCOAMDPBuilder::new(1).set_horizon(4).build();
+        let mut rtdp = RTDP::new(ZeroHeuristic {});
+        rtdp.solve(&mut oamdp, &mut rng, 10);
+
+        let episode: InspectedEpisode<BakerGridState, BakerJointAction, 3> =
+            record_inspected_episode(&mut oamdp, &mut rtdp, &mut rng);
+        let likelihoods = oamdp.mdp.goal_likelihoods(&episode.trace);
+        assert_eq!(likelihoods.len(), episode.trace.steps.len());
+        assert!(likelihoods.last().unwrap().is_none());
+        for (step, q) in episode.trace.steps.iter().zip(episode.q_values.iter()) {
+            if let Some(a) = step.a {
+                assert!(q.iter().any(|(b, _)| *b == a));
+            }
+        }
+
+        let mut viewer = EpisodeViewer::from_trace(
+            &episode.trace,
+            &episode.q_values,
+            &likelihoods,
+            |s: &BakerGridState| format!("{:?}", s),
+        )
+        .set_goal_names(&["A", "B", "C"]);
+        viewer.prev();
+        assert_eq!(viewer.cursor(), 0);
+        assert!(viewer.handle_key(KeyCode::Right));
+        assert_eq!(viewer.cursor(), 1);
+        viewer.last();
+        viewer.next();
+        assert_eq!(viewer.cursor(), episode.trace.steps.len() - 1);
+        assert!(!viewer.handle_key(KeyCode::Char('q')));
+
+        viewer.first();
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| viewer.draw(frame)).unwrap();
+        let screen = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(screen.contains("Q-values"));
+        assert!(screen.contains("Observer likelihood"));
+        assert!(screen.contains("Step 1/"));
+    }
+}
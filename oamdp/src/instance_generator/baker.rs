@@ -0,0 +1,115 @@
+use std::convert::TryInto;
+
+use itertools::iproduct;
+use mdp::{
+    baker_grid::{BakerGridMDP, BakerGridPartialMDP, BakerGridState},
+    mdp_traits::BuildFrom,
+};
+use num_traits::FromPrimitive;
+use ordered_float::NotNan;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    domains::baker_grid::BakerOAMDPBuilder,
+};
+
+use super::{is_goal_reachable, manhattan_distance, GeneratorConfig, InstanceGenerator};
+
+/// Baker grids with `N` goals that are pairwise, and from the start, at least
+/// `min_goal_separation` apart in Manhattan distance.
+pub struct BakerInstanceGenerator<const N: usize>;
+
+impl<const N: usize> InstanceGenerator for BakerInstanceGenerator<N> {
+    type Instance = BakerOAMDPBuilder<N>;
+
+    fn sample<R: Rng>(&self, config: &GeneratorConfig, rng: &mut R) -> Option<Self::Instance> {
+        let (h, w) = (config.height, config.width);
+        let mut cells = iproduct!(0..h, 0..w).collect::<Vec<_>>();
+        cells.shuffle(rng);
+        let n_obstacles = (config.obstacle_density * (h * w) as f32).round() as usize;
+        if n_obstacles + N + 1 > h * w {
+            return None;
+        }
+        let (obstacles, free) = cells.split_at(n_obstacles);
+
+        let start = free[0];
+        let mut goals: Vec<(usize, usize)> = vec![];
+        for &c in free[1..].iter() {
+            if goals.len() == N {
+                break;
+            }
+            if goals
+                .iter()
+                .chain([start].iter())
+                .all(|&g| manhattan_distance(g, c) >= config.min_goal_separation)
+            {
+                goals.push(c);
+            }
+        }
+        if goals.len() < N {
+            return None;
+        }
+        let possible_goals = goals
+            .iter()
+            .map(|&(i, j)| BakerGridState::new(i as i32, j as i32))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        let obstacles = obstacles
+            .iter()
+            .map(|&(i, j)| (i as i32, j as i32))
+            .collect::<Vec<_>>();
+        let prob_veering = 0.1;
+        let partial_mdp = BakerGridPartialMDP::new(h, w, obstacles)
+            .set_initial_state(BakerGridState::new(start.0 as i32, start.1 as i32))
+            .set_prob_veering(prob_veering);
+
+        Some(BakerOAMDPBuilder {
+            beta: config.sample_beta(rng),
+            gamma: 0.9,
+            observability_assumption: ObserveabilityAssumption::OnlyActionsAreConsidered,
+            belief_cost_type: BeliefCostType::TVDistance,
+            horizon: config.horizon,
+            random: None,
+            prob_veering,
+            map: None,
+            partial_mdp,
+            possible_goals,
+            true_goal: rng.gen_range(0, N),
+            initial_belief: [NotNan::from_f32(1.0 / N as f32).unwrap(); N],
+            objective: Objective::LinearCombination(1.0, 0.1),
+        })
+    }
+
+    fn is_solvable(&self, instance: &Self::Instance) -> bool {
+        instance.possible_goals.iter().all(|g| {
+            let mdp: BakerGridMDP = instance.partial_mdp.build_from(g);
+            is_goal_reachable(&mdp)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance_generator::generate;
+
+    #[test]
+    fn test_baker_instance_generator() {
+        let config = GeneratorConfig::default()
+            .set_seed(3)
+            .set_obstacle_density(0.3)
+            .set_beta(0.1, 1.0);
+        let instances = generate(&BakerInstanceGenerator::<3>, &config, 2);
+        let yaml = serde_yaml::to_string(&instances[0]).unwrap();
+        let again = generate(&BakerInstanceGenerator::<3>, &config, 2);
+        assert_eq!(yaml, serde_yaml::to_string(&again[0]).unwrap());
+
+        let loaded: BakerOAMDPBuilder<3> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(loaded.possible_goals, instances[0].possible_goals);
+        assert!((0.1..1.0).contains(&loaded.beta));
+    }
+}
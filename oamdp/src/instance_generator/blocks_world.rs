@@ -0,0 +1,125 @@
+use std::convert::TryInto;
+
+use mdp::{
+    blocks_world::BlocksWorldMDPN, mdp_traits::BuildFrom,
+    state_enumerable_wrapper::StateEnumerableWrapper,
+};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    belief_update_type::ObserveabilityAssumption,
+    domains::blocks_world::BlocksOAMDPInstance,
+};
+
+use super::{hamming_distance, is_goal_reachable, GeneratorConfig, InstanceGenerator};
+
+/// Blocks-world instances over `NB` blocks with `N` goal towers that differ in
+/// at least `min_goal_separation` positions. Grid settings are ignored. The
+/// files are read by `BlocksOAMDPBuilder::new` from
+/// `blocks_world/oamdp_instances`.
+pub struct BlocksInstanceGenerator<const NB: usize, const N: usize>;
+
+impl<const NB: usize, const N: usize> InstanceGenerator for BlocksInstanceGenerator<NB, N> {
+    type Instance = BlocksOAMDPInstance<NB, N>;
+
+    fn sample<R: Rng>(&self, config: &GeneratorConfig, rng: &mut R) -> Option<Self::Instance> {
+        let letters: [char; NB] = (0..NB)
+            .map(|i| (b'A' + i as u8) as char)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        let mut order = letters.to_vec();
+        order.shuffle(rng);
+        let mut start = String::new();
+        for (k, c) in order.into_iter().enumerate() {
+            if k > 0 && rng.gen_bool(0.5) {
+                start.push(' ');
+            }
+            start.push(c);
+        }
+
+        let mut goals: Vec<[char; NB]> = vec![];
+        for _ in 0..(100 * N) {
+            if goals.len() == N {
+                break;
+            }
+            let mut goal = letters;
+            goal.shuffle(rng);
+            if goals
+                .iter()
+                .all(|g| hamming_distance(g, &goal) >= config.min_goal_separation.max(1))
+            {
+                goals.push(goal);
+            }
+        }
+        if goals.len() < N {
+            return None;
+        }
+
+        Some(BlocksOAMDPInstance {
+            beta: config.sample_beta(rng),
+            observability_assumption: ObserveabilityAssumption::ActionNotObservable,
+            belief_cost_type: BeliefCostType::TVDistance,
+            horizon: config.horizon,
+            letters,
+            start,
+            epsilon: 0.1,
+            possible_goals: goals.try_into().unwrap(),
+            true_goal: rng.gen_range(0, N),
+            objective: Objective::LinearCombination(1.0, 0.1),
+        })
+    }
+
+    fn is_solvable(&self, instance: &Self::Instance) -> bool {
+        let partial_mdp = instance.partial_mdp();
+        instance.possible_goals.iter().all(|g| {
+            let mdp: StateEnumerableWrapper<BlocksWorldMDPN<NB>> = partial_mdp.build_from(g);
+            is_goal_reachable(&mdp)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::blocks_world::BlocksOAMDPBuilder;
+    use crate::instance_generator::{generate, write_instances};
+    use rtdp::rtdp_softmax_policy::RTDPSoftmaxPolicyBuilder;
+
+    #[test]
+    fn test_blocks_instance_generator() {
+        let config = GeneratorConfig::default().set_min_goal_separation(3);
+        let instances = generate(&BlocksInstanceGenerator::<4, 3>, &config, 2);
+        let dir = std::env::temp_dir().join("oamdp_test_blocks_instances");
+        write_instances(&instances, dir.to_str().unwrap(), "", 1000);
+
+        for (k, instance) in instances.iter().enumerate() {
+            let path = dir.join(format!("{}.yaml", 1000 + k));
+            let loaded = BlocksOAMDPInstance::<4, 3>::from_file(path.to_str().unwrap());
+            assert_eq!(&loaded, instance);
+
+            assert_eq!(loaded.letters, ['A', 'B', 'C', 'D']);
+            let mut start = loaded.start.replace(' ', "").chars().collect::<Vec<_>>();
+            start.sort();
+            assert_eq!(start, loaded.letters);
+            for (i, g) in loaded.possible_goals.iter().enumerate() {
+                let mut letters = g.to_vec();
+                letters.sort();
+                assert_eq!(letters, loaded.letters);
+                for h in loaded.possible_goals[..i].iter() {
+                    assert!(hamming_distance(g, h) >= 3);
+                }
+            }
+            assert!(loaded.true_goal < 3);
+
+            let builder = BlocksOAMDPBuilder::from_instance(
+                &loaded,
+                RTDPSoftmaxPolicyBuilder::new(loaded.beta),
+            );
+            assert_eq!(builder.possible_goals, loaded.possible_goals);
+            assert_eq!(builder.horizon, config.horizon);
+        }
+    }
+}
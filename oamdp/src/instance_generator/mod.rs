@@ -0,0 +1,159 @@
+mod baker;
+mod blocks_world;
+mod recycle;
+mod spelling;
+
+pub use baker::BakerInstanceGenerator;
+pub use blocks_world::BlocksInstanceGenerator;
+pub use recycle::RecycleInstanceGenerator;
+pub use spelling::SpellingInstanceGenerator;
+
+use std::fs;
+
+use mdp::{
+    mdp_traits::{
+        ActionAvailability, ActionEnumerable, Cost, InitialState, IsTerminal, PMass,
+        StateEnumerable,
+    },
+    value_iteration::value_iteration_ssp,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::Serialize;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct GeneratorConfig {
+    pub seed: u64,
+    pub height: usize,
+    pub width: usize,
+    pub obstacle_density: f32,
+    pub min_goal_separation: usize,
+    pub beta: (f32, f32),
+    pub horizon: usize,
+    pub max_attempts: usize,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig {
+            seed: 0,
+            height: 5,
+            width: 5,
+            obstacle_density: 0.1,
+            min_goal_separation: 2,
+            beta: (0.3, 0.3),
+            horizon: 20,
+            max_attempts: 1000,
+        }
+    }
+}
+
+impl GeneratorConfig {
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn set_size(mut self, height: usize, width: usize) -> Self {
+        self.height = height;
+        self.width = width;
+        self
+    }
+
+    pub fn set_obstacle_density(mut self, obstacle_density: f32) -> Self {
+        self.obstacle_density = obstacle_density;
+        self
+    }
+
+    pub fn set_min_goal_separation(mut self, min_goal_separation: usize) -> Self {
+        self.min_goal_separation = min_goal_separation;
+        self
+    }
+
+    pub fn set_beta(mut self, min: f32, max: f32) -> Self {
+        assert!(min <= max);
+        self.beta = (min, max);
+        self
+    }
+
+    pub fn set_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    pub(crate) fn sample_beta<R: Rng>(&self, rng: &mut R) -> f32 {
+        if self.beta.0 < self.beta.1 {
+            rng.gen_range(self.beta.0, self.beta.1)
+        } else {
+            self.beta.0
+        }
+    }
+}
+
+pub trait InstanceGenerator {
+    type Instance: Serialize;
+
+    /// Samples a candidate instance, or `None` if the draw violates the config.
+    fn sample<R: Rng>(&self, config: &GeneratorConfig, rng: &mut R) -> Option<Self::Instance>;
+
+    /// Checks that every possible goal is reachable from the initial state.
+    fn is_solvable(&self, instance: &Self::Instance) -> bool;
+}
+
+/// Draws `n` solvable instances from an RNG seeded with `config.seed`.
+pub fn generate<G: InstanceGenerator>(
+    generator: &G,
+    config: &GeneratorConfig,
+    n: usize,
+) -> Vec<G::Instance> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut instances = vec![];
+    let mut attempts = 0;
+    while instances.len() < n {
+        attempts += 1;
+        if attempts > config.max_attempts * n {
+            panic!("no solvable instance found in {} attempts", attempts - 1);
+        }
+        if let Some(instance) = generator.sample(config, &mut rng) {
+            if generator.is_solvable(&instance) {
+                instances.push(instance);
+            }
+        }
+    }
+    instances
+}
+
+/// Writes `instances` as `{dir}/{prefix}{id}.yaml` with ids starting at `first_id`.
+pub fn write_instances<T: Serialize>(instances: &[T], dir: &str, prefix: &str, first_id: usize) {
+    fs::create_dir_all(dir).expect("Unable to create directory");
+    for (k, instance) in instances.iter().enumerate() {
+        let yaml = serde_yaml::to_string(instance).unwrap();
+        let path = format!("{}/{}{}.yaml", dir, prefix, first_id + k);
+        fs::write(&path, yaml).expect("Unable to write file");
+    }
+}
+
+// `value_iteration_ssp` starts from 1e+8, which states that cannot reach a
+// goal never improve on.
+const UNREACHABLE: f32 = 1e+7;
+
+pub(crate) fn is_goal_reachable<M>(mdp: &M) -> bool
+where
+    M: ActionAvailability
+        + ActionEnumerable
+        + StateEnumerable
+        + PMass<f32>
+        + Cost
+        + IsTerminal
+        + InitialState,
+{
+    let vt = value_iteration_ssp(mdp);
+    vt.get_value(&mdp.initial_state()) < UNREACHABLE
+}
+
+pub(crate) fn manhattan_distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    ((a.0 as i32 - b.0 as i32).abs() + (a.1 as i32 - b.1 as i32).abs()) as usize
+}
+
+pub(crate) fn hamming_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b.iter()).filter(|(x, y)| x != y).count()
+}
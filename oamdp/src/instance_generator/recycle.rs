@@ -0,0 +1,53 @@
+use rand::Rng;
+
+use crate::domains::recycle::RecycleCOAMDPBuilder;
+
+use super::{is_goal_reachable, GeneratorConfig, InstanceGenerator};
+
+/// Recycle instances with five items, drawn by
+/// `RecycleCOAMDPBuilder::random_instance` with `beta` from the config.
+pub struct RecycleInstanceGenerator;
+
+impl InstanceGenerator for RecycleInstanceGenerator {
+    type Instance = RecycleCOAMDPBuilder<5, 4>;
+
+    fn sample<R: Rng>(&self, config: &GeneratorConfig, rng: &mut R) -> Option<Self::Instance> {
+        let mut builder = RecycleCOAMDPBuilder::random_instance(rng);
+        builder.beta = config.sample_beta(rng);
+        builder.max_t = config.horizon;
+
+        Some(builder)
+    }
+
+    fn is_solvable(&self, instance: &Self::Instance) -> bool {
+        instance
+            .possible_goals
+            .iter()
+            .all(|g| is_goal_reachable(&instance.builder.build(*g)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance_generator::generate;
+
+    #[test]
+    fn test_recycle_instance_generator() {
+        let config = GeneratorConfig::default().set_seed(7).set_beta(0.5, 0.5);
+        let instances = generate(&RecycleInstanceGenerator, &config, 1);
+        let yaml = serde_yaml::to_string(&instances[0]).unwrap();
+        let loaded: RecycleCOAMDPBuilder<5, 4> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(loaded.beta, 0.5);
+        assert_eq!(loaded.max_t, config.horizon);
+        assert_eq!(loaded.possible_goals, instances[0].possible_goals);
+        assert_eq!(
+            loaded.builder.initial_locs,
+            instances[0].builder.initial_locs
+        );
+        assert_eq!(loaded.builder.kinds, [0, 1, 2, 0, 1]);
+        assert!(loaded.true_goal < 4);
+        assert!(!loaded.communication_actions.is_empty());
+        assert_eq!(serde_yaml::to_string(&loaded).unwrap(), yaml);
+    }
+}
@@ -0,0 +1,123 @@
+use std::convert::TryInto;
+
+use itertools::iproduct;
+use mdp::{
+    common::coordinate2::Coordinate2,
+    mdp_traits::BuildFrom,
+    spelling::{Letter, SpellingMDPE},
+};
+use rand::{seq::SliceRandom, Rng};
+use strum::IntoEnumIterator;
+
+use crate::{
+    belief_cost_function::{BeliefCostType, Objective},
+    domains::spelling::SpellingOAMDPInstance,
+};
+
+use super::{hamming_distance, is_goal_reachable, GeneratorConfig, InstanceGenerator};
+
+/// Spelling grids with four letter cells and three candidate words that
+/// differ in at least `min_goal_separation` letters. The files are read by
+/// `SpellingOAMDPBuilder::new` and `new_rtdp` from `spelling/oamdp_instances`.
+pub struct SpellingInstanceGenerator;
+
+impl InstanceGenerator for SpellingInstanceGenerator {
+    type Instance = SpellingOAMDPInstance;
+
+    fn sample<R: Rng>(&self, config: &GeneratorConfig, rng: &mut R) -> Option<Self::Instance> {
+        let (h, w) = (config.height, config.width);
+        let mut cells = iproduct!(0..h, 0..w).collect::<Vec<_>>();
+        cells.shuffle(rng);
+        let n_obstacles = (config.obstacle_density * (h * w) as f32).round() as usize;
+        if n_obstacles + 5 > h * w {
+            return None;
+        }
+        let (obstacles, free) = cells.split_at(n_obstacles);
+
+        let letters = Letter::iter().collect::<Vec<_>>();
+        let mut goals: Vec<[Letter; 4]> = vec![];
+        for _ in 0..300 {
+            if goals.len() == 3 {
+                break;
+            }
+            let goal = [(); 4].map(|_| *letters.choose(rng).unwrap());
+            if goals
+                .iter()
+                .all(|g| hamming_distance(g, &goal) >= config.min_goal_separation.max(1))
+            {
+                goals.push(goal);
+            }
+        }
+        if goals.len() < 3 {
+            return None;
+        }
+
+        Some(SpellingOAMDPInstance {
+            beta: config.sample_beta(rng),
+            belief_cost_type: BeliefCostType::TVDistance,
+            horizon: config.horizon,
+            height: h,
+            width: w,
+            obstacles: obstacles
+                .iter()
+                .map(|&(i, j)| Coordinate2::new(i as i32, j as i32))
+                .collect(),
+            letter_locs: free[1..5].try_into().unwrap(),
+            initial_state: Coordinate2::new(free[0].0 as i32, free[0].1 as i32),
+            possible_goals: goals.try_into().unwrap(),
+            true_goal: rng.gen_range(0, 3),
+            objective: Objective::LinearCombination(1.0, 0.5),
+        })
+    }
+
+    fn is_solvable(&self, instance: &Self::Instance) -> bool {
+        let partial_mdp = instance.partial_mdp();
+        instance.possible_goals.iter().all(|g| {
+            let mdp: SpellingMDPE<4> = partial_mdp.build_from(g);
+            is_goal_reachable(&mdp)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::spelling::SpellingOAMDPBuilder;
+    use crate::instance_generator::{generate, write_instances};
+    use mdp::policy::softmax_policy::SoftmaxPolicyBuilder;
+
+    #[test]
+    fn test_spelling_instance_generator() {
+        let config = GeneratorConfig::default()
+            .set_size(3, 3)
+            .set_obstacle_density(0.4);
+        let instances = generate(&SpellingInstanceGenerator, &config, 1);
+        let dir = std::env::temp_dir().join("oamdp_test_spelling_instances");
+        write_instances(&instances, dir.to_str().unwrap(), "", 1000);
+        let path = dir.join("1000.yaml");
+        let loaded = SpellingOAMDPInstance::from_file(path.to_str().unwrap());
+        assert_eq!(loaded, instances[0]);
+
+        assert_eq!(loaded.obstacles.len(), 4);
+        let mut cells = loaded
+            .obstacles
+            .iter()
+            .chain([loaded.initial_state].iter())
+            .map(|c| (c.i as usize, c.j as usize))
+            .chain(loaded.letter_locs.iter().cloned())
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells.dedup();
+        assert_eq!(cells.len(), 9);
+        for (i, g) in loaded.possible_goals.iter().enumerate() {
+            for h in loaded.possible_goals[..i].iter() {
+                assert!(hamming_distance(g, h) >= 2);
+            }
+        }
+        assert!(loaded.true_goal < 3);
+
+        let builder =
+            SpellingOAMDPBuilder::from_instance(&loaded, SoftmaxPolicyBuilder::new(loaded.beta));
+        assert_eq!(builder.possible_goals, loaded.possible_goals);
+    }
+}
@@ -14,16 +14,25 @@ pub mod algorithms;
 pub mod belief_cost_function;
 //mod bin;
 pub mod domains;
+pub mod episode_viewer;
 #[macro_use]
 pub mod oamdp;
 pub mod belief_update_type;
+pub mod deception;
 pub mod domain_evaluator;
+pub mod instance_generator;
+pub mod message_budget;
+pub mod message_channel;
+pub mod message_vocabulary;
 pub mod oamdp_d;
 pub mod observer_model;
 pub mod plot_belief_changes;
+pub mod plotting;
 // pub mod poamdp;
 pub mod policy;
+pub mod policy_distillation;
 pub mod regular_grid_translator;
 pub mod scaled_rtdp;
 pub mod scaled_value_table;
+pub mod trace;
 pub mod traits;
@@ -0,0 +1,224 @@
+use core::slice::Iter;
+use mdp::mdp_traits::*;
+
+use crate::traits::{
+    DomainAction, EnumerateDomainAction, EnumerateMessage, Message, SilentMessage,
+};
+
+use super::MessageBudgetState;
+
+/// Caps the number of non-silent messages sent in an episode. The remaining
+/// budget is part of the state and messages are unavailable once it runs out.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MessageBudgetWrapper<M: StatesActions> {
+    pub mdp: M,
+    max_messages: usize,
+}
+
+impl<M: StatesActions> MessageBudgetWrapper<M> {
+    pub fn new(mdp: M, max_messages: usize) -> MessageBudgetWrapper<M> {
+        MessageBudgetWrapper { mdp, max_messages }
+    }
+
+    pub fn max_messages(&self) -> usize {
+        self.max_messages
+    }
+}
+
+impl<M: StatesActions + SilentMessage> MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    pub fn sends_message(&self, a: &M::Action) -> bool {
+        !self.mdp.is_silent(&(*a).into())
+    }
+
+    pub(crate) fn remaining_after(&self, s: &MessageBudgetState<M::State>, a: &M::Action) -> usize {
+        if self.sends_message(a) {
+            s.remaining.saturating_sub(1)
+        } else {
+            s.remaining
+        }
+    }
+}
+
+impl<M: StatesActions> StatesActions for MessageBudgetWrapper<M> {
+    type State = MessageBudgetState<M::State>;
+    type Action = M::Action;
+}
+
+impl<M: ActionEnumerable> ActionEnumerable for MessageBudgetWrapper<M> {
+    fn enumerate_actions(&self) -> Iter<Self::Action> {
+        self.mdp.enumerate_actions()
+    }
+
+    fn num_actions(&self) -> usize {
+        self.mdp.num_actions()
+    }
+
+    fn id_to_action(&self, id: usize) -> &Self::Action {
+        self.mdp.id_to_action(id)
+    }
+}
+
+impl<M: ActionAvailability + SilentMessage> ActionAvailability for MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    fn action_available(&self, s: &Self::State, a: &Self::Action) -> bool {
+        self.mdp.action_available(&s.s, a) && (s.remaining > 0 || !self.sends_message(a))
+    }
+}
+
+impl<M: InitialState> InitialState for MessageBudgetWrapper<M> {
+    fn initial_state(&self) -> Self::State {
+        MessageBudgetState::new(self.mdp.initial_state(), self.max_messages)
+    }
+}
+
+impl<M: IsTerminal> IsTerminal for MessageBudgetWrapper<M> {
+    fn is_terminal(&self, s: &Self::State) -> bool {
+        self.mdp.is_terminal(&s.s)
+    }
+}
+
+impl<M: GetNextState + SilentMessage> GetNextState for MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    fn get_next_state(
+        &self,
+        s: &Self::State,
+        a: &Self::Action,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> Self::State {
+        let inner = self.mdp.get_next_state(&s.s, a, rng);
+        MessageBudgetState::new(inner, self.remaining_after(s, a))
+    }
+}
+
+impl<M: GetNextStateMut + SilentMessage> GetNextStateMut for MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    fn get_next_state_mut(
+        &mut self,
+        s: &Self::State,
+        a: &Self::Action,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> Self::State {
+        let inner = self.mdp.get_next_state_mut(&s.s, a, rng);
+        MessageBudgetState::new(inner, self.remaining_after(s, a))
+    }
+}
+
+impl<M: PMass<f32> + SilentMessage> PMass<f32> for MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    type Distribution = Vec<(Self::State, f32)>;
+    fn p_mass(&self, s: &Self::State, a: &Self::Action) -> Vec<(Self::State, f32)> {
+        let remaining = self.remaining_after(s, a);
+        self.mdp
+            .p_mass(&s.s, a)
+            .into_iter()
+            .map(|(ss, p)| (MessageBudgetState::new(ss, remaining), p))
+            .collect()
+    }
+}
+
+impl<M: PMassMut<f32> + SilentMessage> PMassMut<f32> for MessageBudgetWrapper<M>
+where
+    M::Action: Into<M::Message>,
+{
+    type Distribution = Vec<(Self::State, f32)>;
+    fn p_mass_mut(&mut self, s: &Self::State, a: &Self::Action) -> Vec<(Self::State, f32)> {
+        let remaining = self.remaining_after(s, a);
+        self.mdp
+            .p_mass_mut(&s.s, a)
+            .into_iter()
+            .map(|(ss, p)| (MessageBudgetState::new(ss, remaining), p))
+            .collect()
+    }
+}
+
+impl<M: Cost> Cost for MessageBudgetWrapper<M> {
+    fn cost(&self, s: &Self::State, a: &Self::Action) -> f32 {
+        self.mdp.cost(&s.s, a)
+    }
+}
+
+impl<M: DCost> DCost for MessageBudgetWrapper<M> {
+    fn d_cost(&self, s: &Self::State, a: &Self::Action, ss: &Self::State) -> f32 {
+        self.mdp.d_cost(&s.s, a, &ss.s)
+    }
+}
+
+impl<M: StatesActions + DisplayState<M::State>> DisplayState<MessageBudgetState<M::State>>
+    for MessageBudgetWrapper<M>
+{
+    fn display(&self, s: &MessageBudgetState<M::State>) {
+        println!("Remaining messages: {}", s.remaining);
+        self.mdp.display(&s.s);
+    }
+}
+
+impl<M: DomainAction + StatesActions> DomainAction for MessageBudgetWrapper<M> {
+    type DomainAction = M::DomainAction;
+}
+
+impl<M: EnumerateDomainAction + StatesActions> EnumerateDomainAction for MessageBudgetWrapper<M> {
+    fn enumerate_domain_actions(&self) -> Iter<Self::DomainAction> {
+        self.mdp.enumerate_domain_actions()
+    }
+}
+
+impl<M: Message + StatesActions> Message for MessageBudgetWrapper<M> {
+    type Message = M::Message;
+}
+
+impl<M: EnumerateMessage + StatesActions> EnumerateMessage for MessageBudgetWrapper<M> {
+    fn enumerate_message(&self) -> Iter<Self::Message> {
+        self.mdp.enumerate_message()
+    }
+}
+
+impl<M: SilentMessage + StatesActions> SilentMessage for MessageBudgetWrapper<M> {
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        self.mdp.is_silent(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::baker_grid::BakerGridAction;
+    use mdp::mdp_traits::{ActionAvailability, Build, GetNextState, InitialState};
+    use rand::thread_rng;
+
+    use crate::domains::baker_grid::{
+        BakerCOAMDPBuilder, BakerCommunicationAction, BakerJointAction,
+    };
+
+    use super::MessageBudgetWrapper;
+
+    #[test]
+    fn test_message_budget() {
+        let mut rng = thread_rng();
+        let builder = BakerCOAMDPBuilder::new(1);
+        let mdp = MessageBudgetWrapper::new(builder.build(), 1);
+        let s = mdp.initial_state();
+        assert_eq!(s.remaining, 1);
+
+        let silent = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::None);
+        let blue = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::Blue);
+        assert!(mdp.action_available(&s, &blue));
+
+        let ss = mdp.get_next_state(&s, &silent, &mut rng);
+        assert_eq!(ss.remaining, 1);
+
+        let ss = mdp.get_next_state(&ss, &blue, &mut rng);
+        assert_eq!(ss.remaining, 0);
+        assert!(!mdp.action_available(&ss, &blue));
+        assert!(mdp.action_available(&ss, &silent));
+    }
+}
@@ -0,0 +1,6 @@
+mod mdp;
+mod policy;
+mod state;
+
+pub use mdp::MessageBudgetWrapper;
+pub use state::MessageBudgetState;
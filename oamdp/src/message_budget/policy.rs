@@ -0,0 +1,162 @@
+use mdp::episode_runner::EpisodeRunnerMut;
+use mdp::finite_horizon_wrapper::FiniteHorizonWrapperState;
+use mdp::mdp_traits::{
+    ActionEnumerable, DCost, GetNextStateMut, InitialState, IntoEvalMut, IsTerminal, StatesActions,
+};
+use mdp::policy::policy_traits::GetActionMut;
+use rand::rngs::ThreadRng;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::domain_evaluator::DomainEvaluator;
+use crate::oamdp::{BeliefState, OAMDPFiniteHorizon};
+use crate::policy::{RandomOAMDPPolicy, TabularOAMDPPolicy};
+
+use super::{MessageBudgetState, MessageBudgetWrapper};
+
+type BudgetState<S, const N: usize> =
+    MessageBudgetState<FiniteHorizonWrapperState<BeliefState<S, N>>>;
+
+// Rollout policies only take silent actions, so they never touch the budget.
+
+impl<OM, M: StatesActions + ActionEnumerable, A: Eq + Copy + Debug + Hash, const N: usize>
+    GetActionMut<BudgetState<M::State, N>, MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>>
+    for RandomOAMDPPolicy
+where
+    A: From<M::Action>,
+{
+    fn get_action_mut(
+        &mut self,
+        s: &BudgetState<M::State, N>,
+        mdp: &mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+        rng: &mut ThreadRng,
+    ) -> Option<A> {
+        self.get_action_mut(&s.s, &mut mdp.mdp, rng)
+    }
+}
+
+impl<OM, M, A: Eq + Debug + Hash + Copy, const N: usize>
+    IntoEvalMut<MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>> for RandomOAMDPPolicy
+where
+    M: StatesActions + ActionEnumerable,
+    A: From<M::Action>,
+    MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>: StatesActions<State = BudgetState<M::State, N>, Action = A>
+        + IsTerminal
+        + InitialState
+        + GetNextStateMut
+        + DCost,
+{
+    type Evaluator<'a>
+        = EpisodeRunnerMut<
+        'a,
+        MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+        RandomOAMDPPolicy,
+    >
+    where
+        OM: 'a,
+        M: 'a,
+        A: 'a,
+        BeliefState<M::State, N>: 'a;
+
+    fn into_eval_mut<'a>(
+        &'a mut self,
+        s: BudgetState<M::State, N>,
+        mdp: &'a mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+    ) -> Self::Evaluator<'a> {
+        EpisodeRunnerMut::new(mdp, self, s)
+    }
+}
+
+impl<OM, M: StatesActions, A: Eq + PartialEq + Hash + Debug + Clone + Copy, const N: usize>
+    GetActionMut<BudgetState<M::State, N>, MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>>
+    for TabularOAMDPPolicy<M>
+where
+    A: From<M::Action>,
+{
+    fn get_action_mut(
+        &mut self,
+        s: &BudgetState<M::State, N>,
+        mdp: &mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+        rng: &mut ThreadRng,
+    ) -> Option<A> {
+        self.get_action_mut(&s.s, &mut mdp.mdp, rng)
+    }
+}
+
+impl<OM, M, A: Eq + Debug + Hash + Copy, const N: usize>
+    IntoEvalMut<MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>> for TabularOAMDPPolicy<M>
+where
+    M: StatesActions + ActionEnumerable,
+    A: From<M::Action>,
+    MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>: StatesActions<State = BudgetState<M::State, N>, Action = A>
+        + IsTerminal
+        + InitialState
+        + GetNextStateMut
+        + DCost,
+{
+    type Evaluator<'a>
+        = EpisodeRunnerMut<
+        'a,
+        MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+        TabularOAMDPPolicy<M>,
+    >
+    where
+        OM: 'a,
+        M: 'a,
+        A: 'a,
+        BeliefState<M::State, N>: 'a;
+
+    fn into_eval_mut<'a>(
+        &'a mut self,
+        s: BudgetState<M::State, N>,
+        mdp: &'a mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+    ) -> Self::Evaluator<'a> {
+        EpisodeRunnerMut::new(mdp, self, s)
+    }
+}
+
+impl<P, OM, M: StatesActions, A: Eq + PartialEq + Debug + Hash + Copy + Clone, const N: usize>
+    GetActionMut<BudgetState<M::State, N>, MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>>
+    for DomainEvaluator<P>
+where
+    A: From<M::Action>,
+    P: GetActionMut<M::State, M>,
+{
+    fn get_action_mut(
+        &mut self,
+        s: &BudgetState<M::State, N>,
+        mdp: &mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+        rng: &mut ThreadRng,
+    ) -> Option<A> {
+        self.get_action_mut(&s.s, &mut mdp.mdp, rng)
+    }
+}
+
+impl<
+        P,
+        OM,
+        M: StatesActions + IsTerminal + InitialState + GetNextStateMut + DCost,
+        A: Eq + PartialEq + Debug + Hash + Copy + Clone,
+        const N: usize,
+    > IntoEvalMut<MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>> for DomainEvaluator<P>
+where
+    P: GetActionMut<M::State, M>,
+{
+    type Evaluator<'a>
+        = EpisodeRunnerMut<'a, M, P>
+    where
+        OM: 'a,
+        M: 'a,
+        M::State: 'a,
+        M::Action: 'a,
+        A: 'a,
+        P: 'a;
+
+    fn into_eval_mut<'a>(
+        &'a mut self,
+        s: BudgetState<M::State, N>,
+        mdp: &'a mut MessageBudgetWrapper<OAMDPFiniteHorizon<OM, M, A, N>>,
+    ) -> Self::Evaluator<'a> {
+        self.into_eval_mut(s.s, &mut mdp.mdp)
+    }
+}
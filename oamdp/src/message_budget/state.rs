@@ -0,0 +1,44 @@
+use mdp::into_inner::Inner;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::regular_grid_translator::RegularGridTranslator;
+use crate::traits::{BeliefOverGoal, DiscretizeBelief};
+
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct MessageBudgetState<S: Eq + PartialEq + Debug + Copy + Clone + Hash> {
+    #[serde(bound(serialize = "S: Serialize", deserialize = "S: Deserialize<'de>"))]
+    pub s: S,
+    pub remaining: usize,
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash> MessageBudgetState<S> {
+    pub fn new(s: S, remaining: usize) -> MessageBudgetState<S> {
+        MessageBudgetState { s, remaining }
+    }
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash + Inner> Inner for MessageBudgetState<S> {
+    type Result = S::Result;
+    fn inner(&self) -> Self::Result {
+        self.s.inner()
+    }
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash + BeliefOverGoal<N>, const N: usize>
+    BeliefOverGoal<N> for MessageBudgetState<S>
+{
+    fn get_belief_over_goal(&self) -> [NotNan<f32>; N] {
+        self.s.get_belief_over_goal()
+    }
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash + DiscretizeBelief<N>, const N: usize>
+    DiscretizeBelief<N> for MessageBudgetState<S>
+{
+    fn discretize_belief(&self, translator: &RegularGridTranslator<N>) -> Self {
+        MessageBudgetState::new(self.s.discretize_belief(translator), self.remaining)
+    }
+}
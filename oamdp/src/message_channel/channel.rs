@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// How a message travels from the agent to the observer. A sent message is
+/// lost with `drop_probability`, otherwise replaced by another message with
+/// `corruption_probability`, and arrives `delay` steps after it was sent.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct MessageChannel {
+    #[serde(default)]
+    pub drop_probability: f32,
+    #[serde(default)]
+    pub corruption_probability: f32,
+    #[serde(default)]
+    pub delay: usize,
+}
+
+impl MessageChannel {
+    pub fn new(drop_probability: f32, corruption_probability: f32, delay: usize) -> Self {
+        MessageChannel {
+            drop_probability,
+            corruption_probability,
+            delay,
+        }
+    }
+
+    pub fn is_reliable(&self) -> bool {
+        self.drop_probability == 0.0 && self.corruption_probability == 0.0 && self.delay == 0
+    }
+
+    /// Distribution over what is delivered when `m` is sent, given the other
+    /// messages it can be corrupted into. `None` means the message was lost.
+    pub fn transmit<Msg: Copy + PartialEq>(
+        &self,
+        m: Msg,
+        others: &[Msg],
+    ) -> Vec<(Option<Msg>, f32)> {
+        let others = others.iter().filter(|o| **o != m).collect::<Vec<_>>();
+        let p_delivered = 1.0 - self.drop_probability;
+        let p_corrupted = if others.is_empty() {
+            0.0
+        } else {
+            self.corruption_probability
+        };
+
+        let mut outcomes = vec![(Some(m), p_delivered * (1.0 - p_corrupted))];
+        if self.drop_probability > 0.0 {
+            outcomes.push((None, self.drop_probability));
+        }
+        if p_corrupted > 0.0 {
+            for o in others.iter() {
+                outcomes.push((Some(**o), p_delivered * p_corrupted / others.len() as f32));
+            }
+        }
+        outcomes.retain(|(_, p)| *p > 0.0);
+        outcomes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_transmit() {
+        let channel = MessageChannel::new(0.2, 0.5, 0);
+        let outcomes = channel.transmit(0, &[0, 1, 2]);
+        assert_approx_eq!(outcomes.iter().map(|(_, p)| p).sum::<f32>(), 1.0);
+        assert!(outcomes.contains(&(Some(0), 0.4)));
+        assert!(outcomes.contains(&(None, 0.2)));
+        assert!(outcomes.contains(&(Some(2), 0.2)));
+
+        let outcomes = MessageChannel::default().transmit(1, &[0, 1, 2]);
+        assert_eq!(outcomes, vec![(Some(1), 1.0)]);
+    }
+}
@@ -0,0 +1,319 @@
+use core::slice::Iter;
+use mdp::mdp_traits::*;
+use rand::seq::SliceRandom;
+
+use crate::traits::{
+    DomainAction, EnumerateDomainAction, EnumerateMessage, Message, Set, SilentMessage,
+};
+
+use super::{MessageChannel, MessageChannelState, MAX_CHANNEL_DELAY};
+
+/// Sends messages through an unreliable channel. The observer updates its
+/// belief with the message that arrives in the current step, while the agent
+/// pays for the message it sent.
+pub struct MessageChannelWrapper<M: StatesActions + Message> {
+    pub mdp: M,
+    channel: MessageChannel,
+    silent: M::Message,
+    messages: Vec<M::Message>,
+}
+
+impl<M: StatesActions + EnumerateMessage + SilentMessage> MessageChannelWrapper<M> {
+    pub fn new(mdp: M, channel: MessageChannel) -> MessageChannelWrapper<M> {
+        assert!(channel.delay <= MAX_CHANNEL_DELAY);
+        let silent = *mdp
+            .enumerate_message()
+            .find(|m| mdp.is_silent(m))
+            .expect("no silent message");
+        let messages = mdp
+            .enumerate_message()
+            .filter(|m| !mdp.is_silent(m))
+            .cloned()
+            .collect();
+        MessageChannelWrapper {
+            mdp,
+            channel,
+            silent,
+            messages,
+        }
+    }
+}
+
+impl<M: StatesActions + SilentMessage> MessageChannelWrapper<M>
+where
+    M::Action: Into<M::Message> + Set<M::Message>,
+{
+    pub fn channel(&self) -> &MessageChannel {
+        &self.channel
+    }
+
+    fn transmit(&self, a: &M::Action) -> Vec<(Option<M::Message>, f32)> {
+        let m = (*a).into();
+        if self.mdp.is_silent(&m) {
+            vec![(None, 1.0)]
+        } else {
+            self.channel.transmit(m, &self.messages)
+        }
+    }
+
+    /// The action as seen by the observer and the messages still in flight
+    /// when `delivered` is what the channel made of the sent message.
+    fn observe(
+        &self,
+        s: &MessageChannelState<M::State, M::Message>,
+        a: &M::Action,
+        delivered: Option<M::Message>,
+    ) -> (M::Action, [Option<M::Message>; MAX_CHANNEL_DELAY]) {
+        let delay = self.channel.delay;
+        let mut in_flight = s.in_flight;
+        let arrived = if delay == 0 {
+            delivered
+        } else {
+            let arrived = in_flight[0];
+            in_flight.copy_within(1..delay, 0);
+            in_flight[delay - 1] = delivered;
+            arrived
+        };
+
+        let mut observed = *a;
+        observed.set(arrived.unwrap_or(self.silent));
+        (observed, in_flight)
+    }
+
+    /// Samples what the channel makes of the message in `a` and returns the
+    /// action as seen by the observer with the messages still in flight.
+    pub(crate) fn sample_observation(
+        &self,
+        s: &MessageChannelState<M::State, M::Message>,
+        a: &M::Action,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> (M::Action, [Option<M::Message>; MAX_CHANNEL_DELAY]) {
+        let outcomes = self.transmit(a);
+        let delivered = outcomes.choose_weighted(rng, |(_, p)| *p).unwrap().0;
+        self.observe(s, a, delivered)
+    }
+}
+
+impl<M: StatesActions + Message> StatesActions for MessageChannelWrapper<M> {
+    type State = MessageChannelState<M::State, M::Message>;
+    type Action = M::Action;
+}
+
+impl<M: ActionEnumerable + Message> ActionEnumerable for MessageChannelWrapper<M> {
+    fn enumerate_actions(&self) -> Iter<Self::Action> {
+        self.mdp.enumerate_actions()
+    }
+
+    fn num_actions(&self) -> usize {
+        self.mdp.num_actions()
+    }
+
+    fn id_to_action(&self, id: usize) -> &Self::Action {
+        self.mdp.id_to_action(id)
+    }
+}
+
+impl<M: ActionAvailability + Message> ActionAvailability for MessageChannelWrapper<M> {
+    fn action_available(&self, s: &Self::State, a: &Self::Action) -> bool {
+        self.mdp.action_available(&s.s, a)
+    }
+}
+
+impl<M: InitialState + Message> InitialState for MessageChannelWrapper<M> {
+    fn initial_state(&self) -> Self::State {
+        MessageChannelState::new(self.mdp.initial_state(), [None; MAX_CHANNEL_DELAY])
+    }
+}
+
+impl<M: IsTerminal + Message> IsTerminal for MessageChannelWrapper<M> {
+    fn is_terminal(&self, s: &Self::State) -> bool {
+        self.mdp.is_terminal(&s.s)
+    }
+}
+
+impl<M: GetNextState + SilentMessage> GetNextState for MessageChannelWrapper<M>
+where
+    M::Action: Into<M::Message> + Set<M::Message>,
+{
+    fn get_next_state(
+        &self,
+        s: &Self::State,
+        a: &Self::Action,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> Self::State {
+        let (observed, in_flight) = self.sample_observation(s, a, rng);
+        MessageChannelState::new(self.mdp.get_next_state(&s.s, &observed, rng), in_flight)
+    }
+}
+
+impl<M: GetNextStateMut + SilentMessage> GetNextStateMut for MessageChannelWrapper<M>
+where
+    M::Action: Into<M::Message> + Set<M::Message>,
+{
+    fn get_next_state_mut(
+        &mut self,
+        s: &Self::State,
+        a: &Self::Action,
+        rng: &mut rand::rngs::ThreadRng,
+    ) -> Self::State {
+        let (observed, in_flight) = self.sample_observation(s, a, rng);
+        MessageChannelState::new(self.mdp.get_next_state_mut(&s.s, &observed, rng), in_flight)
+    }
+}
+
+impl<M: PMass<f32> + SilentMessage> PMass<f32> for MessageChannelWrapper<M>
+where
+    M::Action: Into<M::Message> + Set<M::Message>,
+{
+    type Distribution = Vec<(Self::State, f32)>;
+    fn p_mass(&self, s: &Self::State, a: &Self::Action) -> Vec<(Self::State, f32)> {
+        let mut result = vec![];
+        for (delivered, q) in self.transmit(a) {
+            let (observed, in_flight) = self.observe(s, a, delivered);
+            for (ss, p) in self.mdp.p_mass(&s.s, &observed) {
+                result.push((MessageChannelState::new(ss, in_flight), p * q));
+            }
+        }
+        result
+    }
+}
+
+impl<M: PMassMut<f32> + SilentMessage> PMassMut<f32> for MessageChannelWrapper<M>
+where
+    M::Action: Into<M::Message> + Set<M::Message>,
+{
+    type Distribution = Vec<(Self::State, f32)>;
+    fn p_mass_mut(&mut self, s: &Self::State, a: &Self::Action) -> Vec<(Self::State, f32)> {
+        let mut result = vec![];
+        for (delivered, q) in self.transmit(a) {
+            let (observed, in_flight) = self.observe(s, a, delivered);
+            for (ss, p) in self.mdp.p_mass_mut(&s.s, &observed) {
+                result.push((MessageChannelState::new(ss, in_flight), p * q));
+            }
+        }
+        result
+    }
+}
+
+impl<M: Cost + Message> Cost for MessageChannelWrapper<M> {
+    fn cost(&self, s: &Self::State, a: &Self::Action) -> f32 {
+        self.mdp.cost(&s.s, a)
+    }
+}
+
+impl<M: DCost + Message> DCost for MessageChannelWrapper<M> {
+    fn d_cost(&self, s: &Self::State, a: &Self::Action, ss: &Self::State) -> f32 {
+        self.mdp.d_cost(&s.s, a, &ss.s)
+    }
+}
+
+impl<M: StatesActions + Message + DisplayState<M::State>>
+    DisplayState<MessageChannelState<M::State, M::Message>> for MessageChannelWrapper<M>
+{
+    fn display(&self, s: &MessageChannelState<M::State, M::Message>) {
+        println!("In flight: {:?}", &s.in_flight[..self.channel.delay]);
+        self.mdp.display(&s.s);
+    }
+}
+
+impl<M: DomainAction + Message + StatesActions> DomainAction for MessageChannelWrapper<M> {
+    type DomainAction = M::DomainAction;
+}
+
+impl<M: EnumerateDomainAction + Message + StatesActions> EnumerateDomainAction
+    for MessageChannelWrapper<M>
+{
+    fn enumerate_domain_actions(&self) -> Iter<Self::DomainAction> {
+        self.mdp.enumerate_domain_actions()
+    }
+}
+
+impl<M: Message + StatesActions> Message for MessageChannelWrapper<M> {
+    type Message = M::Message;
+}
+
+impl<M: EnumerateMessage + StatesActions> EnumerateMessage for MessageChannelWrapper<M> {
+    fn enumerate_message(&self) -> Iter<Self::Message> {
+        self.mdp.enumerate_message()
+    }
+}
+
+impl<M: SilentMessage + StatesActions> SilentMessage for MessageChannelWrapper<M> {
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        self.mdp.is_silent(m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use mdp::baker_grid::BakerGridAction;
+    use mdp::mdp_traits::{Build, GetNextState, InitialState, PMass};
+    use rand::thread_rng;
+
+    use crate::domains::baker_grid::{
+        BakerCOAMDPBuilder, BakerCommunicationAction, BakerJointAction,
+    };
+    use crate::message_channel::MessageChannel;
+    use crate::traits::BeliefOverGoal;
+
+    use super::MessageChannelWrapper;
+
+    #[test]
+    fn test_dropped_message_is_not_observed() {
+        let builder = BakerCOAMDPBuilder::new(1);
+        let channel = MessageChannelWrapper::new(builder.build(), MessageChannel::new(1.0, 0.0, 0));
+        let reliable = MessageChannelWrapper::new(
+            BakerCOAMDPBuilder::new(1).build(),
+            MessageChannel::default(),
+        );
+        let s = channel.initial_state();
+
+        let silent = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::None);
+        let blue = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::Blue);
+        let dropped = channel.p_mass(&s, &blue);
+        assert_eq!(dropped, reliable.p_mass(&s, &silent));
+
+        let delivered = reliable.p_mass(&s, &blue);
+        assert_eq!(dropped.len(), delivered.len());
+        for ((ss_dropped, _), (ss_delivered, _)) in dropped.iter().zip(delivered.iter()) {
+            assert_eq!(ss_dropped.s.s.s, ss_delivered.s.s.s);
+            assert_ne!(
+                ss_dropped.get_belief_over_goal(),
+                ss_delivered.get_belief_over_goal()
+            );
+        }
+    }
+
+    #[test]
+    fn test_delayed_message() {
+        let mut rng = thread_rng();
+        let builder = BakerCOAMDPBuilder::new(1);
+        let channel = MessageChannelWrapper::new(builder.build(), MessageChannel::new(0.0, 0.0, 1));
+        let s = channel.initial_state();
+
+        let silent = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::None);
+        let blue = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::Blue);
+        let ss = channel.get_next_state(&s, &blue, &mut rng);
+        assert_eq!(ss.in_flight[0], Some(BakerCommunicationAction::Blue));
+        let sss = channel.get_next_state(&ss, &silent, &mut rng);
+        assert_eq!(sss.in_flight[0], None);
+
+        let b = sss.get_belief_over_goal();
+        let bb = ss.get_belief_over_goal();
+        assert!(b[1] < bb[1]);
+    }
+
+    #[test]
+    fn test_lossy_p_mass() {
+        let builder = BakerCOAMDPBuilder::new(1);
+        let channel = MessageChannelWrapper::new(builder.build(), MessageChannel::new(0.3, 0.2, 2));
+        let s = channel.initial_state();
+        let blue = BakerJointAction::new(BakerGridAction::East, BakerCommunicationAction::Blue);
+        let p_mass = channel.p_mass(&s, &blue);
+        assert_approx_eq!(p_mass.iter().map(|(_, p)| p).sum::<f32>(), 1.0);
+        assert!(p_mass
+            .iter()
+            .any(|(ss, _)| ss.in_flight[1] == Some(BakerCommunicationAction::Circle)));
+    }
+}
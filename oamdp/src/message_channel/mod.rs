@@ -0,0 +1,9 @@
+mod channel;
+mod mdp;
+mod policy;
+mod state;
+
+pub use channel::MessageChannel;
+pub use mdp::MessageChannelWrapper;
+pub use policy::MessageChannelPolicy;
+pub use state::{MessageChannelState, MAX_CHANNEL_DELAY};
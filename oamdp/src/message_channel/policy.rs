@@ -0,0 +1,86 @@
+use mdp::episode_runner::EpisodeRunnerMut;
+use mdp::mdp_traits::{
+    DCost, GetNextStateMut, InitialState, IntoEvalMut, IsTerminal, StatesActions,
+};
+use mdp::policy::policy_traits::GetActionMut;
+use rand::rngs::ThreadRng;
+
+use crate::traits::Message;
+
+use super::{MessageChannelState, MessageChannelWrapper};
+
+/// Runs a policy computed for a reliable channel, acting on the belief the
+/// observer actually holds.
+pub struct MessageChannelPolicy<P> {
+    pub policy: P,
+}
+
+impl<P> MessageChannelPolicy<P> {
+    pub fn new(policy: P) -> Self {
+        MessageChannelPolicy { policy }
+    }
+}
+
+impl<M: StatesActions + Message, P: GetActionMut<M::State, M>>
+    GetActionMut<MessageChannelState<M::State, M::Message>, MessageChannelWrapper<M>>
+    for MessageChannelPolicy<P>
+{
+    fn get_action_mut(
+        &mut self,
+        s: &MessageChannelState<M::State, M::Message>,
+        mdp: &mut MessageChannelWrapper<M>,
+        rng: &mut ThreadRng,
+    ) -> Option<M::Action> {
+        self.policy.get_action_mut(&s.s, &mut mdp.mdp, rng)
+    }
+}
+
+impl<M: StatesActions + Message, P> IntoEvalMut<MessageChannelWrapper<M>>
+    for MessageChannelPolicy<P>
+where
+    MessageChannelWrapper<M>: StatesActions<State = MessageChannelState<M::State, M::Message>, Action = M::Action>
+        + IsTerminal
+        + InitialState
+        + GetNextStateMut
+        + DCost,
+    P: GetActionMut<M::State, M>,
+{
+    type Evaluator<'a>
+        = EpisodeRunnerMut<'a, MessageChannelWrapper<M>, MessageChannelPolicy<P>>
+    where
+        M: 'a,
+        P: 'a;
+
+    fn into_eval_mut<'a>(
+        &'a mut self,
+        s: MessageChannelState<M::State, M::Message>,
+        mdp: &'a mut MessageChannelWrapper<M>,
+    ) -> Self::Evaluator<'a> {
+        EpisodeRunnerMut::new(mdp, self, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::episode_runner::{monte_carlo_evaluation, EpisodeRunnerMut};
+    use mdp::mdp_traits::Build;
+    use rand::thread_rng;
+
+    use crate::domains::baker_grid::BakerCOAMDPBuilder;
+    use crate::message_channel::MessageChannelWrapper;
+    use crate::policy::RandomOAMDPPolicy;
+
+    use super::MessageChannelPolicy;
+
+    #[test]
+    fn test_evaluate_over_lossy_channel() {
+        let mut rng = thread_rng();
+        let builder = BakerCOAMDPBuilder::new(502);
+        let channel = builder.channel().unwrap();
+        let mut mdp = MessageChannelWrapper::new(builder.build(), channel);
+        let mut policy = MessageChannelPolicy::new(RandomOAMDPPolicy::new());
+        let mut runner = EpisodeRunnerMut::from_initial_state(&mut mdp, &mut policy);
+        let cost = monte_carlo_evaluation(&mut runner, &mut rng, 10);
+        assert!(cost > 0.0);
+    }
+}
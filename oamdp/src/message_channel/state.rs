@@ -0,0 +1,61 @@
+use mdp::into_inner::Inner;
+use ordered_float::NotNan;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::regular_grid_translator::RegularGridTranslator;
+use crate::traits::{BeliefOverGoal, DiscretizeBelief};
+
+pub const MAX_CHANNEL_DELAY: usize = 4;
+
+/// `in_flight[i]` reaches the observer `i + 1` steps from now.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub struct MessageChannelState<S, Msg>
+where
+    S: Eq + PartialEq + Debug + Copy + Clone + Hash,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    pub s: S,
+    pub in_flight: [Option<Msg>; MAX_CHANNEL_DELAY],
+}
+
+impl<S, Msg> MessageChannelState<S, Msg>
+where
+    S: Eq + PartialEq + Debug + Copy + Clone + Hash,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    pub fn new(s: S, in_flight: [Option<Msg>; MAX_CHANNEL_DELAY]) -> Self {
+        MessageChannelState { s, in_flight }
+    }
+}
+
+impl<S, Msg> Inner for MessageChannelState<S, Msg>
+where
+    S: Eq + PartialEq + Debug + Copy + Clone + Hash + Inner,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    type Result = S::Result;
+    fn inner(&self) -> Self::Result {
+        self.s.inner()
+    }
+}
+
+impl<S, Msg, const N: usize> BeliefOverGoal<N> for MessageChannelState<S, Msg>
+where
+    S: Eq + PartialEq + Debug + Copy + Clone + Hash + BeliefOverGoal<N>,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    fn get_belief_over_goal(&self) -> [NotNan<f32>; N] {
+        self.s.get_belief_over_goal()
+    }
+}
+
+impl<S, Msg, const N: usize> DiscretizeBelief<N> for MessageChannelState<S, Msg>
+where
+    S: Eq + PartialEq + Debug + Copy + Clone + Hash + DiscretizeBelief<N>,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    fn discretize_belief(&self, translator: &RegularGridTranslator<N>) -> Self {
+        MessageChannelState::new(self.s.discretize_belief(translator), self.in_flight)
+    }
+}
@@ -0,0 +1,68 @@
+use mdp::mdp_traits::{ActionEnumerable, StatesActions};
+use std::slice::Iter;
+
+use crate::{
+    oamdp::oamdp::OAMDP,
+    traits::{
+        CommunicationCost, DomainAction, EnumerateDomainAction, EnumerateMessage, Message,
+        SilentMessage,
+    },
+};
+
+use super::{JointAction, MessageId, VocabularyCommunicationModel};
+
+impl<P, M: StatesActions, const N: usize> DomainAction
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    type DomainAction = M::Action;
+}
+
+impl<P, M: ActionEnumerable, const N: usize> EnumerateDomainAction
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    fn enumerate_domain_actions(&self) -> Iter<Self::DomainAction> {
+        self.mdp.enumerate_actions()
+    }
+}
+
+impl<P, M: StatesActions, const N: usize> Message
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    type Message = MessageId;
+}
+
+impl<P, M: StatesActions, const N: usize> EnumerateMessage
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    fn enumerate_message(&self) -> Iter<Self::Message> {
+        self.assumed_model.communication_model.message_ids.iter()
+    }
+}
+
+impl<P, M: StatesActions, const N: usize> SilentMessage
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        self.assumed_model.communication_model.vocabulary.messages[m.0].is_silent()
+    }
+}
+
+impl<P, M: StatesActions, const N: usize> CommunicationCost
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, JointAction<M::Action>, N>
+where
+    Self: StatesActions<Action = JointAction<M::Action>>,
+{
+    fn communication_cost(&self, _s: &Self::State, a: &Self::Action) -> f32 {
+        self.assumed_model.communication_model.cost(&a.message)
+    }
+}
@@ -0,0 +1,24 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+use mdp::into_inner::Inner;
+use mdp::mdp_traits::{DisplayState, StatesActions};
+
+use crate::oamdp::{oamdp::OAMDP, BeliefState};
+use crate::traits::BeliefOverGoal;
+
+use super::VocabularyCommunicationModel;
+
+impl<P, M, A: Eq + Copy + Debug + Hash, const N: usize> DisplayState<BeliefState<M::State, N>>
+    for OAMDP<VocabularyCommunicationModel<P, M, N>, M, A, N>
+where
+    M: StatesActions + DisplayState<M::State>,
+    Self: StatesActions<State = BeliefState<M::State, N>, Action = A>,
+{
+    fn display(&self, s: &BeliefState<M::State, N>) {
+        let b = s.get_belief_over_goal();
+        for (i, p) in b.iter().enumerate() {
+            println!("Belief over goal {}: {:?}", i, p.into_inner());
+        }
+        self.mdp.display(&s.inner());
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt::Debug;
+use mdp::into_inner::Inner;
+use std::hash::Hash;
+
+use crate::traits::{Message, Set};
+
+use super::MessageId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JointAction<A> {
+    pub domain_action: A,
+    pub message: MessageId,
+}
+
+impl<A> JointAction<A> {
+    pub fn new(domain_action: A, message: MessageId) -> Self {
+        JointAction {
+            domain_action,
+            message,
+        }
+    }
+}
+
+impl<A> Message for JointAction<A> {
+    type Message = MessageId;
+}
+
+impl<A: Copy> Inner for JointAction<A> {
+    type Result = A;
+    fn inner(&self) -> Self::Result {
+        self.domain_action
+    }
+}
+
+impl<A> From<JointAction<A>> for MessageId {
+    fn from(a: JointAction<A>) -> Self {
+        a.message
+    }
+}
+
+impl<A> From<(A, MessageId)> for JointAction<A> {
+    fn from(pair: (A, MessageId)) -> Self {
+        JointAction::new(pair.0, pair.1)
+    }
+}
+
+impl<A> Set<MessageId> for JointAction<A> {
+    fn set(&mut self, m: MessageId) {
+        self.message = m;
+    }
+}
+
+impl<A: Eq + PartialEq + Debug + Copy + Clone + Hash + Inner<Result = A>> Set<A>
+    for JointAction<A>
+{
+    fn set(&mut self, a: A) {
+        self.domain_action = a;
+    }
+}
@@ -0,0 +1,12 @@
+mod coamdp;
+mod display;
+mod joint_action;
+mod vocabulary;
+mod vocabulary_coamdp_builder;
+
+pub use joint_action::JointAction;
+pub use vocabulary::{
+    ListenerModel, MessageId, MessageSpec, MessageVocabulary, VocabularyCommunicationModel,
+    VocabularyCommunicationProb,
+};
+pub use vocabulary_coamdp_builder::VocabularyCOAMDPBuilder;
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    observer_model::{ExplicitCommunicationModel, IteratedRSA, RSAConfig},
+    traits::CommunicationProbability,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MessageId(pub usize);
+
+/// A message and the goals it is literally true of. A message without
+/// `true_of` is true of every goal and is treated as staying silent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageSpec {
+    pub name: String,
+    #[serde(default)]
+    pub true_of: Option<Vec<usize>>,
+    #[serde(default)]
+    pub cost: f32,
+}
+
+impl MessageSpec {
+    pub fn is_silent(&self) -> bool {
+        self.true_of.is_none()
+    }
+
+    pub fn is_true_of(&self, goal: usize) -> bool {
+        match &self.true_of {
+            Some(goals) => goals.contains(&goal),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ListenerModel {
+    /// Uniform over the messages that are true of the goal.
+    Literal,
+    /// Pragmatic speaker with the given rationality over a literal listener.
+    RSA(f32),
+    /// `(rationality, depth)`: speaker of iterated RSA at the given depth.
+    IteratedRSA(f32, usize),
+    /// Literal speaker that says a false message with the given probability.
+    GenerativeNoise(f32),
+    /// `(alpha, eta)`: silence gets `1 - alpha - eta`, true messages share
+    /// `alpha - eta` and false messages share `eta`. The share of a goal
+    /// without true or false messages goes to silence, and the result is
+    /// normalized.
+    SoftGenerativeNoise(f32, f32),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageVocabulary {
+    pub messages: Vec<MessageSpec>,
+    pub listener: ListenerModel,
+}
+
+impl MessageVocabulary {
+    pub fn new(messages: Vec<MessageSpec>, listener: ListenerModel) -> Self {
+        MessageVocabulary { messages, listener }
+    }
+
+    /// `p[goal][message]`, the probability that an agent heading to `goal`
+    /// sends `message`.
+    pub fn speaker_probabilities(&self, num_goals: usize) -> Vec<Vec<f32>> {
+        (0..num_goals)
+            .map(|g| match self.listener {
+                ListenerModel::Literal => self.literal_speaker(g),
+                ListenerModel::RSA(rationality) => {
+                    self.pragmatic_speaker(g, num_goals, RSAConfig::new(rationality, 1))
+                }
+                ListenerModel::IteratedRSA(rationality, depth) => {
+                    self.pragmatic_speaker(g, num_goals, RSAConfig::new(rationality, depth))
+                }
+                ListenerModel::GenerativeNoise(eps) => {
+                    let literal = self.literal_speaker(g);
+                    let num_false = self.messages.iter().filter(|m| !m.is_true_of(g)).count();
+                    if num_false == 0 {
+                        literal
+                    } else {
+                        self.messages
+                            .iter()
+                            .zip(literal)
+                            .map(|(m, p)| {
+                                if m.is_true_of(g) {
+                                    (1.0 - eps) * p
+                                } else {
+                                    eps / num_false as f32
+                                }
+                            })
+                            .collect()
+                    }
+                }
+                ListenerModel::SoftGenerativeNoise(alpha, eta) => {
+                    let num_silent = self.messages.iter().filter(|m| m.is_silent()).count();
+                    let num_true = self
+                        .messages
+                        .iter()
+                        .filter(|m| !m.is_silent() && m.is_true_of(g))
+                        .count();
+                    let num_false = self.messages.len() - num_silent - num_true;
+                    let mut silent_mass = 1.0 - alpha - eta;
+                    let true_mass = if num_true > 0 {
+                        alpha - eta
+                    } else {
+                        silent_mass += alpha - eta;
+                        0.0
+                    };
+                    let false_mass = if num_false > 0 {
+                        eta
+                    } else {
+                        silent_mass += eta;
+                        0.0
+                    };
+                    let p = self
+                        .messages
+                        .iter()
+                        .map(|m| {
+                            if m.is_silent() {
+                                silent_mass / num_silent as f32
+                            } else if m.is_true_of(g) {
+                                true_mass / num_true as f32
+                            } else {
+                                false_mass / num_false as f32
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    let total = p.iter().sum::<f32>();
+                    p.iter().map(|p| p / total).collect()
+                }
+            })
+            .collect()
+    }
+
+    fn literal_speaker(&self, goal: usize) -> Vec<f32> {
+        let num_true = self.messages.iter().filter(|m| m.is_true_of(goal)).count();
+        self.messages
+            .iter()
+            .map(|m| {
+                if m.is_true_of(goal) {
+                    1.0 / num_true as f32
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    fn pragmatic_speaker(&self, goal: usize, num_goals: usize, config: RSAConfig) -> Vec<f32> {
+        let lexicon = (0..num_goals)
+            .map(|g| self.messages.iter().map(|m| m.is_true_of(g)).collect())
+            .collect::<Vec<_>>();
+        let costs = self.messages.iter().map(|m| m.cost).collect::<Vec<_>>();
+        let prior = vec![1.0 / num_goals as f32; num_goals];
+        let rsa = IteratedRSA::new(&lexicon, &costs, &prior, config);
+        (0..self.messages.len())
+            .map(|m| rsa.speaker(goal, m))
+            .collect()
+    }
+}
+
+pub type VocabularyCommunicationModel<P, M, const N: usize> =
+    ExplicitCommunicationModel<P, M, VocabularyCommunicationProb, N>;
+
+pub struct VocabularyCommunicationProb {
+    pub(crate) vocabulary: MessageVocabulary,
+    pub(crate) message_ids: Vec<MessageId>,
+    speaker_probabilities: Vec<Vec<f32>>,
+}
+
+impl VocabularyCommunicationProb {
+    pub fn new(vocabulary: MessageVocabulary, num_goals: usize) -> Self {
+        let speaker_probabilities = vocabulary.speaker_probabilities(num_goals);
+        let message_ids = (0..vocabulary.messages.len()).map(MessageId).collect();
+        VocabularyCommunicationProb {
+            vocabulary,
+            message_ids,
+            speaker_probabilities,
+        }
+    }
+
+    pub fn cost(&self, m: &MessageId) -> f32 {
+        self.vocabulary.messages[m.0].cost
+    }
+
+    pub fn name(&self, m: &MessageId) -> &str {
+        &self.vocabulary.messages[m.0].name
+    }
+}
+
+impl CommunicationProbability<MessageId> for VocabularyCommunicationProb {
+    fn communication_probability(&self, id: usize, m: &MessageId) -> f32 {
+        self.speaker_probabilities[id][m.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn shapes_vocabulary(listener: ListenerModel) -> MessageVocabulary {
+        let data = "
+messages:
+  - name: Blue
+    true_of: [0, 2]
+    cost: 0.5
+  - name: Circle
+    true_of: [0]
+    cost: 0.5
+  - name: None
+listener: Literal
+";
+        let vocabulary: MessageVocabulary = serde_yaml::from_str(data).unwrap();
+        MessageVocabulary::new(vocabulary.messages, listener)
+    }
+
+    #[test]
+    fn test_speaker_probabilities_sum_to_one() {
+        for listener in [
+            ListenerModel::Literal,
+            ListenerModel::RSA(1.0),
+            ListenerModel::IteratedRSA(1.0, 3),
+            ListenerModel::GenerativeNoise(0.1),
+            ListenerModel::SoftGenerativeNoise(0.5, 0.1),
+        ] {
+            let p = shapes_vocabulary(listener).speaker_probabilities(3);
+            for p_g in p {
+                assert_approx_eq!(p_g.iter().sum::<f32>(), 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_soft_generative_noise() {
+        let prob = VocabularyCommunicationProb::new(
+            shapes_vocabulary(ListenerModel::SoftGenerativeNoise(0.5, 0.1)),
+            3,
+        );
+        let blue = MessageId(0);
+        let circle = MessageId(1);
+        let none = MessageId(2);
+        // Goal 0 has no false message and goal 1 no true message, so their
+        // share goes to silence. Every goal is normalized by 1 - eta.
+        assert_approx_eq!(prob.communication_probability(0, &blue), 0.2 / 0.9);
+        assert_approx_eq!(prob.communication_probability(0, &circle), 0.2 / 0.9);
+        assert_approx_eq!(prob.communication_probability(0, &none), 0.5 / 0.9);
+        assert_approx_eq!(prob.communication_probability(1, &blue), 0.05 / 0.9);
+        assert_approx_eq!(prob.communication_probability(1, &none), 0.8 / 0.9);
+        assert_approx_eq!(prob.communication_probability(2, &blue), 0.4 / 0.9);
+        assert_approx_eq!(prob.communication_probability(2, &circle), 0.1 / 0.9);
+        assert_approx_eq!(prob.cost(&circle), 0.5);
+        assert_approx_eq!(prob.cost(&none), 0.0);
+    }
+
+    #[test]
+    fn test_rsa_prefers_specific_messages() {
+        let p = shapes_vocabulary(ListenerModel::RSA(2.0)).speaker_probabilities(3);
+        assert!(p[0][1] > p[0][0]);
+        assert_approx_eq!(p[1][0], 0.0);
+        assert_approx_eq!(p[1][2], 1.0);
+    }
+}
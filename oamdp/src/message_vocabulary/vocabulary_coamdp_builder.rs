@@ -0,0 +1,245 @@
+use std::fs;
+
+use itertools::iproduct;
+use mdp::finite_horizon_wrapper::FiniteHorizonWrapper;
+use mdp::mdp_traits::*;
+use mdp::policy::softmax_policy::{SoftmaxPolicy, SoftmaxPolicyBuilder};
+use mdp::value_iteration::ValueTable;
+use num_traits::FromPrimitive;
+use ordered_float::NotNan;
+use serde::Deserialize;
+
+use crate::belief_cost_function::{BeliefCostFunction, Objective};
+use crate::belief_update_type::ObserveabilityAssumption;
+use crate::oamdp::oamdp::OAMDP;
+use crate::oamdp::OAMDPFiniteHorizon;
+use crate::observer_model::ExplicitCommunicationModel;
+
+use super::{
+    JointAction, MessageVocabulary, VocabularyCommunicationModel, VocabularyCommunicationProb,
+};
+
+/// Builds a communicative OAMDP for any domain whose partial MDP can be
+/// completed with a goal. The messages and the listener model are read from
+/// the instance file.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "PM: Deserialize<'de>, G: Deserialize<'de>"))]
+pub struct VocabularyCOAMDPBuilder<PM, G, const N: usize> {
+    partial_mdp: PM,
+    #[serde(with = "serde_arrays")]
+    pub possible_goals: [G; N],
+    beta: f32,
+    vocabulary: MessageVocabulary,
+    max_t: usize,
+    true_goal: usize,
+    belief_cost_function: BeliefCostFunction<N>,
+    cost_type: Objective,
+    #[serde(default)]
+    max_messages: Option<usize>,
+}
+
+impl<PM, G, const N: usize> VocabularyCOAMDPBuilder<PM, G, N>
+where
+    for<'de> PM: Deserialize<'de>,
+    for<'de> G: Deserialize<'de>,
+{
+    pub fn new(domain: &str, instance_id: usize) -> Self {
+        let path = format!(
+            "{}/src/domains/{}/vocabulary_instances/{}.yaml",
+            env!("CARGO_MANIFEST_DIR"),
+            domain,
+            instance_id
+        );
+        let data = fs::read_to_string(&path).expect("Unable to read file");
+        serde_yaml::from_str(&data).expect("Invalid yaml")
+    }
+}
+
+impl<PM, G, const N: usize> VocabularyCOAMDPBuilder<PM, G, N> {
+    pub fn set_horizon(mut self, horizon: usize) -> VocabularyCOAMDPBuilder<PM, G, N> {
+        self.max_t = horizon;
+
+        self
+    }
+
+    pub fn set_max_messages(mut self, max_messages: usize) -> VocabularyCOAMDPBuilder<PM, G, N> {
+        self.max_messages = Some(max_messages);
+
+        self
+    }
+
+    pub fn max_messages(&self) -> Option<usize> {
+        self.max_messages
+    }
+
+    /// The listener models only give each goal a distribution over messages
+    /// when some message is true of it and silence is always available.
+    fn validate_vocabulary(&self) {
+        let messages = &self.vocabulary.messages;
+        for goal in 0..N {
+            assert!(
+                messages.iter().any(|m| m.is_true_of(goal)),
+                "no message is true of goal {}",
+                goal
+            );
+        }
+        assert!(
+            messages.iter().any(|m| m.is_silent()),
+            "the vocabulary has no silent message"
+        );
+    }
+}
+
+impl<PM, G, M, const N: usize>
+    Build<
+        OAMDPFiniteHorizon<
+            VocabularyCommunicationModel<SoftmaxPolicy<ValueTable<M::State>>, M, N>,
+            M,
+            JointAction<M::Action>,
+            N,
+        >,
+    > for VocabularyCOAMDPBuilder<PM, G, N>
+where
+    for<'a> PM: BuildFrom<&'a G, M>,
+    M: ActionEnumerable
+        + ActionAvailability
+        + StateEnumerable
+        + ExplicitTransition
+        + PMass<f32>
+        + IsTerminal
+        + Cost,
+{
+    fn build(
+        self,
+    ) -> OAMDPFiniteHorizon<
+        VocabularyCommunicationModel<SoftmaxPolicy<ValueTable<M::State>>, M, N>,
+        M,
+        JointAction<M::Action>,
+        N,
+    > {
+        self.validate_vocabulary();
+        let mdp = self
+            .partial_mdp
+            .build_from(&self.possible_goals[self.true_goal]);
+
+        let communication_prob = VocabularyCommunicationProb::new(self.vocabulary, N);
+        let joint_actions = iproduct!(
+            mdp.enumerate_actions(),
+            communication_prob.message_ids.iter()
+        )
+        .map(|(a, m)| JointAction::new(*a, *m))
+        .collect::<Vec<_>>();
+
+        let om = ExplicitCommunicationModel::new_from_possible_goals(
+            &self.partial_mdp,
+            &SoftmaxPolicyBuilder::new(self.beta),
+            self.possible_goals,
+            ObserveabilityAssumption::OnlyActionsAreConsidered,
+            communication_prob,
+        );
+
+        FiniteHorizonWrapper::new(
+            OAMDP::new(
+                om,
+                mdp,
+                self.belief_cost_function,
+                [NotNan::from_f32(1.0 / (N as f32)).unwrap(); N],
+                0.9,
+                joint_actions,
+                self.cost_type,
+            ),
+            self.max_t,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use mdp::baker_grid::{BakerGridAction, BakerGridPartialMDP, BakerGridState};
+    use mdp::mdp_traits::{Build, GetNextState, InitialState};
+    use rand::thread_rng;
+
+    use crate::message_vocabulary::{JointAction, MessageId};
+    use crate::traits::{BeliefOverGoal, EnumerateMessage};
+
+    use super::VocabularyCOAMDPBuilder;
+
+    #[test]
+    fn test_baker_vocabulary_coamdp() {
+        let mut rng = thread_rng();
+        let builder: VocabularyCOAMDPBuilder<BakerGridPartialMDP, BakerGridState, 3> =
+            VocabularyCOAMDPBuilder::new("baker_grid", 1);
+        let oamdp = builder.build();
+        assert_eq!(oamdp.enumerate_message().count(), 3);
+
+        let initial_state = oamdp.initial_state();
+        let a = JointAction::new(BakerGridAction::NorthEast, MessageId(0));
+        let ss = oamdp.get_next_state(&initial_state, &a, &mut rng);
+        let b = ss.get_belief_over_goal();
+        assert!(b[0] > b[1]);
+        assert!(b[2] > b[1]);
+    }
+
+    fn baker_builder_with_vocabulary(
+        vocabulary: &str,
+    ) -> VocabularyCOAMDPBuilder<BakerGridPartialMDP, BakerGridState, 3> {
+        let path = format!(
+            "{}/src/domains/baker_grid/vocabulary_instances/1.yaml",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let mut instance: serde_yaml::Value =
+            serde_yaml::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        instance["vocabulary"] = serde_yaml::from_str(vocabulary).unwrap();
+        serde_yaml::from_value(instance).unwrap()
+    }
+
+    #[test]
+    #[should_panic(expected = "no message is true of goal 1")]
+    fn test_goal_without_true_message() {
+        let builder = baker_builder_with_vocabulary(
+            "
+messages:
+  - name: Blue
+    true_of: [0, 2]
+  - name: Circle
+    true_of: [0]
+listener: Literal
+",
+        );
+        builder.build();
+    }
+
+    #[test]
+    #[should_panic(expected = "the vocabulary has no silent message")]
+    fn test_vocabulary_without_silent_message() {
+        let builder = baker_builder_with_vocabulary(
+            "
+messages:
+  - name: Blue
+    true_of: [0, 2]
+  - name: Green
+    true_of: [1]
+listener:
+  GenerativeNoise: 0.1
+",
+        );
+        builder.build();
+    }
+
+    #[test]
+    #[should_panic(expected = "no message is true of goal 0")]
+    fn test_empty_vocabulary() {
+        let builder = baker_builder_with_vocabulary(
+            "
+messages: []
+listener:
+  SoftGenerativeNoise:
+    - 0.5
+    - 0.1
+",
+        );
+        builder.build();
+    }
+}
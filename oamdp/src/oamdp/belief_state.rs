@@ -1,4 +1,5 @@
-use crate::traits::BeliefOverGoal;
+use crate::regular_grid_translator::RegularGridTranslator;
+use crate::traits::{BeliefOverGoal, DiscretizeBelief};
 use core::fmt::Debug;
 use core::hash::Hash;
 
@@ -32,6 +33,14 @@ impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash, const N: usize> BeliefOver
     }
 }
 
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash, const N: usize> DiscretizeBelief<N>
+    for BeliefState<S, N>
+{
+    fn discretize_belief(&self, translator: &RegularGridTranslator<N>) -> Self {
+        BeliefState::new(self.s, translator.discretize(&self.belief_over_goal))
+    }
+}
+
 impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash, const N: usize> Inner for BeliefState<S, N> {
     type Result = S;
     fn inner(&self) -> Self::Result {
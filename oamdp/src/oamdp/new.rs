@@ -116,12 +116,9 @@ where
         let belief_cost_function = match distance_measure {
             BeliefCostType::Euclidean => BeliefCostFunction::Euclidean(target_belief),
             BeliefCostType::KLDivergence => BeliefCostFunction::KLDivergence(true_goal),
-            BeliefCostType::Disimulation => BeliefCostFunction::Disimulation,
-            BeliefCostType::Deceptive(i) => {
-                let mut target_belief = [NotNan::<f32>::from_f32(0.0).unwrap(); N];
-                target_belief[i] = NotNan::<f32>::from_f32(1.0).unwrap();
-                BeliefCostFunction::TVDistance(target_belief)
-            }
+            BeliefCostType::Disimulation => BeliefCostFunction::Disimulation(true_goal),
+            BeliefCostType::Deceptive(decoy) => BeliefCostFunction::Simulation { decoy, true_goal },
+            BeliefCostType::HideTrueGoal => BeliefCostFunction::TrueGoalBelief(true_goal),
             BeliefCostType::TVDistance => BeliefCostFunction::TVDistance(target_belief),
         };
 
@@ -11,6 +11,7 @@ use std::{fmt::Debug, slice::Iter};
 use crate::{
     oamdp::{oamdp::OAMDP, BeliefState},
     regular_grid_translator::RegularGridTranslator,
+    traits::{EnumerateMessage, Message, SilentMessage},
 };
 
 use super::VState;
@@ -135,6 +136,34 @@ where
     }
 }
 
+impl<OM, M: StatesActions, A: Eq + PartialEq + Hash + Debug + Clone + Copy, const N: usize> Message
+    for OAMDPD<OM, M, A, N>
+where
+    OAMDP<OM, M, A, N>: Message,
+{
+    type Message = <OAMDP<OM, M, A, N> as Message>::Message;
+}
+
+impl<OM, M: StatesActions, A: Eq + PartialEq + Hash + Debug + Clone + Copy, const N: usize>
+    SilentMessage for OAMDPD<OM, M, A, N>
+where
+    OAMDP<OM, M, A, N>: SilentMessage,
+{
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        self.oamdp.is_silent(m)
+    }
+}
+
+impl<OM, M: StatesActions, A: Eq + PartialEq + Hash + Debug + Clone + Copy, const N: usize>
+    EnumerateMessage for OAMDPD<OM, M, A, N>
+where
+    OAMDP<OM, M, A, N>: EnumerateMessage,
+{
+    fn enumerate_message(&self) -> Iter<Self::Message> {
+        self.oamdp.enumerate_message()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RSAConfig {
+    pub rationality: f32,
+    pub depth: usize,
+}
+
+impl RSAConfig {
+    pub fn new(rationality: f32, depth: usize) -> Self {
+        RSAConfig { rationality, depth }
+    }
+}
+
+/// Rational speech acts iterated up to a fixed depth over a literal lexicon.
+///
+/// Depth 0 is the literal speaker and listener. At depth k the speaker S_k
+/// soft-maximises `rationality * (ln L_{k-1}(g | m) - cost(m))` and the
+/// listener L_k inverts S_k with the goal prior.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IteratedRSA {
+    /// `speaker[g][m]`
+    speaker: Vec<Vec<f32>>,
+    /// `listener[m][g]`
+    listener: Vec<Vec<f32>>,
+}
+
+impl IteratedRSA {
+    /// `lexicon[g][m]` tells whether message `m` is literally true of goal `g`.
+    pub fn new(lexicon: &[Vec<bool>], costs: &[f32], prior: &[f32], config: RSAConfig) -> Self {
+        let num_goals = lexicon.len();
+        let num_messages = costs.len();
+
+        let literal = lexicon
+            .iter()
+            .map(|row| row.iter().map(|t| if *t { 1.0 } else { 0.0 }).collect())
+            .collect::<Vec<Vec<f32>>>();
+        let mut speaker = literal.iter().cloned().map(normalize).collect::<Vec<_>>();
+        let mut listener = infer_goal(&literal, prior);
+
+        for _ in 0..config.depth {
+            speaker = (0..num_goals)
+                .map(|g| {
+                    normalize(
+                        (0..num_messages)
+                            .map(|m| {
+                                if listener[m][g] > 0.0 {
+                                    (config.rationality * (listener[m][g].ln() - costs[m])).exp()
+                                } else {
+                                    0.0
+                                }
+                            })
+                            .collect(),
+                    )
+                })
+                .collect();
+            listener = infer_goal(&speaker, prior);
+        }
+
+        IteratedRSA { speaker, listener }
+    }
+
+    pub fn speaker(&self, goal: usize, message: usize) -> f32 {
+        self.speaker[goal][message]
+    }
+
+    pub fn listener(&self, message: usize, goal: usize) -> f32 {
+        self.listener[message][goal]
+    }
+}
+
+fn normalize(p: Vec<f32>) -> Vec<f32> {
+    let sum = p.iter().sum::<f32>();
+    if sum > 0.0 {
+        p.into_iter().map(|x| x / sum).collect()
+    } else {
+        p
+    }
+}
+
+fn infer_goal(likelihood: &[Vec<f32>], prior: &[f32]) -> Vec<Vec<f32>> {
+    let num_messages = likelihood.first().map_or(0, |l| l.len());
+    (0..num_messages)
+        .map(|m| {
+            normalize(
+                likelihood
+                    .iter()
+                    .zip(prior)
+                    .map(|(l, p)| l[m] * p)
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Goals: blue square, blue circle. Messages: blue, circle, none.
+    fn rsa(depth: usize) -> IteratedRSA {
+        let lexicon = vec![vec![true, false, true], vec![true, true, true]];
+        IteratedRSA::new(
+            &lexicon,
+            &[0.1, 0.1, 0.0],
+            &[0.5, 0.5],
+            RSAConfig::new(2.0, depth),
+        )
+    }
+
+    #[test]
+    fn test_literal_depth() {
+        let rsa = rsa(0);
+        assert_approx_eq!(rsa.speaker(0, 0), 0.5);
+        assert_approx_eq!(rsa.speaker(0, 1), 0.0);
+        assert_approx_eq!(rsa.speaker(1, 1), 1.0 / 3.0);
+        assert_approx_eq!(rsa.listener(1, 1), 1.0);
+        assert_approx_eq!(rsa.listener(0, 0), 0.5);
+    }
+
+    #[test]
+    fn test_deeper_listener_draws_implicature() {
+        let shallow = rsa(1);
+        let deep = rsa(3);
+        for g in 0..2 {
+            assert_approx_eq!((0..3).map(|m| deep.speaker(g, m)).sum::<f32>(), 1.0);
+        }
+        // "blue" increasingly implies the blue square since the circle would
+        // have been named.
+        assert!(shallow.listener(0, 0) > 0.5);
+        assert!(deep.listener(0, 0) > shallow.listener(0, 0));
+        assert_approx_eq!(deep.speaker(0, 1), 0.0);
+    }
+}
@@ -1,9 +1,11 @@
 mod explicit_communication_model;
 mod implicit_communication_model;
+mod iterated_rsa;
 mod rtdp_softmax_model;
 mod softmax_model;
 
 pub use explicit_communication_model::ExplicitCommunicationModel;
 pub use implicit_communication_model::ImplicitCommunicationModel;
+pub use iterated_rsa::{IteratedRSA, RSAConfig};
 pub use rtdp_softmax_model::RTDPSoftmaxModel;
 pub use softmax_model::SoftmaxModel;
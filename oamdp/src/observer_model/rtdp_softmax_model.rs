@@ -23,8 +23,8 @@ mod tests {
     use mdp::heuristic::ZeroHeuristic;
     use mdp::mdp_traits::{BuildFrom, GetNextStateMut, InitialState};
     use mdp::search_rescue::{
-        Coordinate, ObstacleCompatibility, SearchRescueAction, SearchRescueParameter,
-        SearchRescuePartialMDP,
+        Coordinate, ObstacleCompatibility, SearchRescueAction, SearchRescueMDP,
+        SearchRescueParameter, SearchRescuePartialMDP,
     };
     use mdp::value_estimator::CostEstimator;
     use rand::thread_rng;
@@ -94,15 +94,18 @@ mod tests {
             SearchRescueParameter::new(Coordinate::new(4, 2), ObstacleCompatibility::High),
             SearchRescueParameter::new(Coordinate::new(4, 2), ObstacleCompatibility::Low),
         ];
-        let mut mdp = partial_mdp.build_from(&possible_types[0]);
+        let mut mdp: SearchRescueMDP = partial_mdp.build_from(&possible_types[0]);
 
-        let mut om: ImplicitCommunicationModel<RTDPSoftmaxPolicy<_, ZeroHeuristic>, _, 2> =
-            ImplicitCommunicationModel::new_from_possible_goals(
-                &partial_mdp,
-                &softmax_policy,
-                possible_types,
-                OnlyActionsAreConsidered,
-            );
+        let mut om: ImplicitCommunicationModel<
+            RTDPSoftmaxPolicy<_, ZeroHeuristic>,
+            SearchRescueMDP,
+            2,
+        > = ImplicitCommunicationModel::new_from_possible_goals(
+            &partial_mdp,
+            &softmax_policy,
+            possible_types,
+            OnlyActionsAreConsidered,
+        );
 
         let mut rng = thread_rng();
         let s = mdp.initial_state();
@@ -1,44 +1,24 @@
-use gnuplot::*;
 use ordered_float::NotNan;
+use std::io;
 
+use crate::plotting::{belief_trajectory_chart, Rgb};
+
+/// Saves the observer's belief in each goal over time, one curve per goal
+/// with its caption, color and line style, as PNG or SVG depending on the
+/// extension of `filename`.
 pub fn plot_belief_changes<const N: usize>(
     belief_changes: &[[NotNan<f32>; N]],
     filename: &str,
-    captions: &[PlotOption<&str>],
-    colors: &[PlotOption<&str>],
-    line_styles: &[PlotOption<&str>],
-) {
-    let mut fg = Figure::new();
-    let axes2d = fg
-        .axes2d()
-        .set_legend(Graph(1.0), Graph(1.0), &[], &[Font("Arial", 18.0)])
-        .set_x_ticks(Some((Auto, 0)), &[], &[Font("Arial", 18.0)])
-        .set_y_ticks(Some((Auto, 0)), &[], &[Font("Arial", 18.0)])
-        .set_x_label("Time Steps", &[Font("", 18.0)])
-        .set_y_label("Observer's Belief", &[Font("", 18.0)])
-        .set_y_range(Fix(0.0), Fix(1.0));
-
-    let x = (0..belief_changes.len()).collect::<Vec<_>>();
-    for (((goal_id, caption), color), line_style) in
-        (0..N).zip(captions).zip(colors).zip(line_styles)
-    {
-        let belief_change = belief_changes.iter().map(|item| item[goal_id].into_inner());
-        axes2d.lines_points(
-            &x,
-            belief_change,
-            &[
-                *color,
-                *line_style,
-                LineWidth(2.0),
-                PointSymbol('x'),
-                PointSize(1.0),
-                *caption,
-            ],
-        );
+    captions: &[&str],
+    colors: &[Rgb],
+    dashed: &[bool],
+) -> io::Result<()> {
+    let mut chart = belief_trajectory_chart(belief_changes, captions);
+    for ((series, color), dashed) in chart.series_mut().iter_mut().zip(colors).zip(dashed) {
+        series.color = Some(*color);
+        series.dashed = *dashed;
     }
-
-    fg.set_terminal("png", filename);
-    fg.show().unwrap();
+    chart.save(filename)
 }
 
 #[cfg(test)]
@@ -48,7 +28,7 @@ mod tests {
     };
 
     use super::*;
-    use gnuplot::PlotOption::Caption;
+    use crate::plotting::PALETTE;
     use mdp::{
         baker_grid::{BakerGridAction, BakerGridState},
         mdp_traits::Build,
@@ -112,14 +92,11 @@ mod tests {
     //         plot_belief_changes(
     //             &belief,
     //             "belief_changes.png",
-    //             &[Caption("A"), Caption("B"), Caption("C")],
-    //             &[Color("blue"), Color("green"), Color("blue")],
-    //             &[
-    //                 LineStyle(DashType::SmallDot),
-    //                 LineStyle(DashType::Dash),
-    //                 LineStyle(DashType::Solid),
-    //             ],
-    //         );
+    //             &["A", "B", "C"],
+    //             &[PALETTE[0], PALETTE[1], PALETTE[0]],
+    //             &[true, true, false],
+    //         )
+    //         .unwrap();
     //     }
 
     #[test]
@@ -171,17 +148,16 @@ mod tests {
             ),
         ];
         let belief = oamdp.mdp.get_belief_changes(&trace);
-        println!("{:?}", belief);
+        assert_eq!(belief.len(), trace.len());
+        let path = std::env::temp_dir().join("oamdp_test_belief_changes_communication.png");
         plot_belief_changes(
             &belief,
-            "belief_changes_communication.png",
-            &[Caption("A"), Caption("B"), Caption("C")],
-            &[Color("blue"), Color("green"), Color("blue")],
-            &[
-                LineStyle(DashType::SmallDot),
-                LineStyle(DashType::Dash),
-                LineStyle(DashType::Solid),
-            ],
-        );
+            path.to_str().unwrap(),
+            &["A", "B", "C"],
+            &[PALETTE[0], PALETTE[1], PALETTE[0]],
+            &[true, true, false],
+        )
+        .unwrap();
+        assert!(path.exists());
     }
 }
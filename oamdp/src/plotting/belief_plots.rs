@@ -0,0 +1,51 @@
+use ordered_float::NotNan;
+
+use super::{LineChart, Series};
+
+/// The observer's belief in each goal over time in the default palette,
+/// named by `captions` (falling back to `G<k>`).
+pub fn belief_trajectory_chart<const N: usize>(
+    belief_changes: &[[NotNan<f32>; N]],
+    captions: &[&str],
+) -> LineChart {
+    (0..N).fold(
+        LineChart::new()
+            .set_x_label("Time Steps")
+            .set_y_label("Observer's Belief")
+            .set_y_range(0.0, 1.0),
+        |chart, goal_id| {
+            let caption = captions
+                .get(goal_id)
+                .map(|caption| caption.to_string())
+                .unwrap_or_else(|| format!("G{}", goal_id));
+            let ys = belief_changes
+                .iter()
+                .map(|b| b[goal_id].into_inner())
+                .collect::<Vec<_>>();
+            chart.add_series(Series::from_values(&caption, &ys).set_markers(true))
+        },
+    )
+}
+
+/// Bellman residual per iteration on a log scale.
+pub fn convergence_chart(residuals: &[f32]) -> LineChart {
+    LineChart::new()
+        .set_x_label("Iteration")
+        .set_y_label("Residual")
+        .set_log_y(true)
+        .add_series(Series::from_values("", residuals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_belief_trajectory_chart() {
+        let beliefs = vec![[NotNan::new(1.0 / 3.0).unwrap(); 3]; 4];
+        let chart = belief_trajectory_chart(&beliefs, &["A", "B"]);
+        assert_eq!(chart.series().len(), 3);
+        assert_eq!(chart.series()[2].label, "G2");
+        assert_eq!(chart.series()[0].points.len(), 4);
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Stroke, StrokeDash, Transform};
+
+use super::font::{draw_text, text_width};
+
+pub(crate) const TEXT_SCALE: f32 = 2.0;
+
+pub type Rgb = (u8, u8, u8);
+
+pub const BLACK: Rgb = (0, 0, 0);
+pub const WHITE: Rgb = (255, 255, 255);
+pub const LIGHT_GRAY: Rgb = (220, 220, 220);
+
+pub const PALETTE: [Rgb; 6] = [
+    (31, 119, 180),
+    (44, 160, 44),
+    (255, 127, 14),
+    (214, 39, 40),
+    (148, 103, 189),
+    (140, 86, 75),
+];
+
+/// Drawing operations shared by the PNG and SVG backends, so every plot is
+/// described once and saved in either format.
+pub(crate) trait Canvas {
+    fn fill_polygon(&mut self, points: &[(f32, f32)], color: Rgb);
+
+    fn polyline(&mut self, points: &[(f32, f32)], color: Rgb, width: f32, dashed: bool);
+
+    /// Draws `text` with its top-left corner at `(x, y)`.
+    fn text(&mut self, text: &str, x: f32, y: f32, color: Rgb);
+
+    fn fill_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Rgb) {
+        self.fill_polygon(&[(x, y), (x + w, y), (x + w, y + h), (x, y + h)], color);
+    }
+}
+
+pub(crate) fn to_color((r, g, b): Rgb) -> Color {
+    Color::from_rgba8(r, g, b, 255)
+}
+
+pub(crate) struct PngCanvas {
+    pub pixmap: Pixmap,
+}
+
+impl PngCanvas {
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut pixmap = Pixmap::new(width, height).unwrap();
+        pixmap.fill(Color::WHITE);
+        PngCanvas { pixmap }
+    }
+
+    fn path(points: &[(f32, f32)], close: bool) -> Option<tiny_skia::Path> {
+        let mut pb = PathBuilder::new();
+        let (x0, y0) = points.first()?;
+        pb.move_to(*x0, *y0);
+        for (x, y) in points.iter().skip(1) {
+            pb.line_to(*x, *y);
+        }
+        if close {
+            pb.close();
+        }
+        pb.finish()
+    }
+}
+
+impl Canvas for PngCanvas {
+    fn fill_polygon(&mut self, points: &[(f32, f32)], color: Rgb) {
+        if let Some(path) = PngCanvas::path(points, true) {
+            let mut paint = Paint::default();
+            paint.set_color(to_color(color));
+            paint.anti_alias = false;
+            self.pixmap.fill_path(
+                &path,
+                &paint,
+                FillRule::Winding,
+                Transform::identity(),
+                None,
+            );
+        }
+    }
+
+    fn polyline(&mut self, points: &[(f32, f32)], color: Rgb, width: f32, dashed: bool) {
+        if let Some(path) = PngCanvas::path(points, false) {
+            let mut paint = Paint::default();
+            paint.set_color(to_color(color));
+            paint.anti_alias = true;
+            let stroke = Stroke {
+                width,
+                dash: if dashed {
+                    StrokeDash::new(vec![6.0, 4.0], 0.0)
+                } else {
+                    None
+                },
+                ..Stroke::default()
+            };
+            self.pixmap
+                .stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    fn text(&mut self, text: &str, x: f32, y: f32, color: Rgb) {
+        draw_text(&mut self.pixmap, text, x, y, TEXT_SCALE, to_color(color));
+    }
+}
+
+pub(crate) struct SvgCanvas {
+    width: u32,
+    height: u32,
+    body: String,
+}
+
+impl SvgCanvas {
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut canvas = SvgCanvas {
+            width,
+            height,
+            body: String::new(),
+        };
+        canvas.fill_rect(0.0, 0.0, width as f32, height as f32, WHITE);
+        canvas
+    }
+
+    fn points(points: &[(f32, f32)]) -> String {
+        points
+            .iter()
+            .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn finish(self) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n{}</svg>\n",
+            self.width, self.height, self.width, self.height, self.body
+        )
+    }
+}
+
+impl Canvas for SvgCanvas {
+    fn fill_polygon(&mut self, points: &[(f32, f32)], (r, g, b): Rgb) {
+        writeln!(
+            self.body,
+            "<polygon points=\"{}\" fill=\"rgb({},{},{})\" shape-rendering=\"crispEdges\"/>",
+            SvgCanvas::points(points),
+            r,
+            g,
+            b
+        )
+        .unwrap();
+    }
+
+    fn polyline(&mut self, points: &[(f32, f32)], (r, g, b): Rgb, width: f32, dashed: bool) {
+        writeln!(
+            self.body,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"rgb({},{},{})\" stroke-width=\"{}\"{}/>",
+            SvgCanvas::points(points),
+            r,
+            g,
+            b,
+            width,
+            if dashed {
+                " stroke-dasharray=\"6,4\""
+            } else {
+                ""
+            }
+        )
+        .unwrap();
+    }
+
+    fn text(&mut self, text: &str, x: f32, y: f32, (r, g, b): Rgb) {
+        let escaped = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        // Sized to occupy the same box as the bitmap font of the PNG backend.
+        writeln!(
+            self.body,
+            "<text x=\"{:.2}\" y=\"{:.2}\" font-family=\"monospace\" font-size=\"{}\" textLength=\"{:.2}\" fill=\"rgb({},{},{})\">{}</text>",
+            x,
+            y + 7.0 * TEXT_SCALE,
+            9.0 * TEXT_SCALE,
+            text_width(text, TEXT_SCALE),
+            r,
+            g,
+            b,
+            escaped
+        )
+        .unwrap();
+    }
+}
+
+/// Draws with `draw` and writes an SVG when `path` ends in `.svg`, a PNG
+/// otherwise.
+pub(crate) fn save_with<F: Fn(&mut dyn Canvas)>(
+    path: &str,
+    width: u32,
+    height: u32,
+    draw: F,
+) -> io::Result<()> {
+    let is_svg = Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+    if is_svg {
+        let mut canvas = SvgCanvas::new(width, height);
+        draw(&mut canvas);
+        fs::write(path, canvas.finish())
+    } else {
+        let mut canvas = PngCanvas::new(width, height);
+        draw(&mut canvas);
+        canvas.pixmap.save_png(path).map_err(io::Error::other)
+    }
+}
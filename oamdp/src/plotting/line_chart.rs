@@ -0,0 +1,385 @@
+use std::io;
+use tiny_skia::Pixmap;
+
+use super::canvas::{
+    save_with, Canvas, PngCanvas, Rgb, BLACK, LIGHT_GRAY, PALETTE, TEXT_SCALE, WHITE,
+};
+use super::font::{text_width, GLYPH_HEIGHT};
+
+const MARGIN_LEFT: f32 = 90.0;
+const MARGIN_RIGHT: f32 = 20.0;
+const MARGIN_TOP: f32 = 56.0;
+const MARGIN_BOTTOM: f32 = 60.0;
+const TEXT_HEIGHT: f32 = GLYPH_HEIGHT as f32 * TEXT_SCALE;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub label: String,
+    pub points: Vec<(f32, f32)>,
+    pub color: Option<Rgb>,
+    pub dashed: bool,
+    pub markers: bool,
+    pub lines: bool,
+}
+
+impl Series {
+    pub fn new(label: &str, points: Vec<(f32, f32)>) -> Self {
+        Series {
+            label: label.to_string(),
+            points,
+            color: None,
+            dashed: false,
+            markers: false,
+            lines: true,
+        }
+    }
+
+    /// Plots `ys` against their indices.
+    pub fn from_values(label: &str, ys: &[f32]) -> Self {
+        Series::new(
+            label,
+            ys.iter().enumerate().map(|(x, y)| (x as f32, *y)).collect(),
+        )
+    }
+
+    pub fn set_color(mut self, color: Rgb) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    pub fn set_dashed(mut self, dashed: bool) -> Self {
+        self.dashed = dashed;
+
+        self
+    }
+
+    pub fn set_markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+
+        self
+    }
+
+    /// Markers without connecting lines, for scatter plots.
+    pub fn set_scatter(mut self) -> Self {
+        self.markers = true;
+        self.lines = false;
+
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineChart {
+    title: String,
+    x_label: String,
+    y_label: String,
+    x_range: Option<(f32, f32)>,
+    y_range: Option<(f32, f32)>,
+    log_y: bool,
+    width: u32,
+    height: u32,
+    series: Vec<Series>,
+}
+
+impl Default for LineChart {
+    fn default() -> Self {
+        LineChart {
+            title: String::new(),
+            x_label: String::new(),
+            y_label: String::new(),
+            x_range: None,
+            y_range: None,
+            log_y: false,
+            width: 640,
+            height: 400,
+            series: vec![],
+        }
+    }
+}
+
+/// Evenly spaced ticks on a 1-2-5 grid covering `[lo, hi]`.
+fn ticks(lo: f32, hi: f32) -> (Vec<f32>, usize) {
+    let raw = (hi - lo) / 5.0;
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|s| *s >= raw)
+        .unwrap_or(10.0 * magnitude);
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    let first = (lo / step).ceil() as i64;
+    let last = (hi / step).floor() as i64;
+    ((first..=last).map(|k| k as f32 * step).collect(), decimals)
+}
+
+fn padded_range(values: impl Iterator<Item = f32>) -> (f32, f32) {
+    let (lo, hi) = values.fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+        (lo.min(v), hi.max(v))
+    });
+    if !lo.is_finite() || !hi.is_finite() {
+        (0.0, 1.0)
+    } else if lo == hi {
+        (lo - 0.5, hi + 0.5)
+    } else {
+        (lo, hi)
+    }
+}
+
+impl LineChart {
+    pub fn new() -> Self {
+        LineChart::default()
+    }
+
+    pub fn set_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+
+        self
+    }
+
+    pub fn set_x_label(mut self, x_label: &str) -> Self {
+        self.x_label = x_label.to_string();
+
+        self
+    }
+
+    pub fn set_y_label(mut self, y_label: &str) -> Self {
+        self.y_label = y_label.to_string();
+
+        self
+    }
+
+    pub fn set_x_range(mut self, lo: f32, hi: f32) -> Self {
+        self.x_range = Some((lo, hi));
+
+        self
+    }
+
+    pub fn set_y_range(mut self, lo: f32, hi: f32) -> Self {
+        self.y_range = Some((lo, hi));
+
+        self
+    }
+
+    /// Log-scaled y axis; non-positive values are dropped.
+    pub fn set_log_y(mut self, log_y: bool) -> Self {
+        self.log_y = log_y;
+
+        self
+    }
+
+    pub fn set_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+
+        self
+    }
+
+    pub fn add_series(mut self, series: Series) -> Self {
+        self.series.push(series);
+
+        self
+    }
+
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    pub fn series_mut(&mut self) -> &mut [Series] {
+        &mut self.series
+    }
+
+    fn y_transform(&self, y: f32) -> Option<f32> {
+        if !self.log_y {
+            Some(y)
+        } else if y > 0.0 {
+            Some(y.log10())
+        } else {
+            None
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.series
+            .iter()
+            .flat_map(|series| series.points.iter())
+            .filter_map(move |(x, y)| self.y_transform(*y).map(|y| (*x, y)))
+    }
+
+    fn ranges(&self) -> ((f32, f32), (f32, f32)) {
+        let x_range = self
+            .x_range
+            .unwrap_or_else(|| padded_range(self.points().map(|(x, _)| x)));
+        let y_range = match self.y_range {
+            Some((lo, hi)) => (
+                self.y_transform(lo).unwrap_or(-1.0),
+                self.y_transform(hi).unwrap_or(0.0),
+            ),
+            None => padded_range(self.points().map(|(_, y)| y)),
+        };
+        (x_range, y_range)
+    }
+
+    pub(crate) fn draw(&self, canvas: &mut dyn Canvas) {
+        let ((x_lo, x_hi), (y_lo, y_hi)) = self.ranges();
+        let left = MARGIN_LEFT;
+        let right = self.width as f32 - MARGIN_RIGHT;
+        let top = MARGIN_TOP;
+        let bottom = self.height as f32 - MARGIN_BOTTOM;
+        let to_px = |x: f32, y: f32| {
+            let px = left + (x - x_lo) / (x_hi - x_lo) * (right - left);
+            let py = bottom - (y - y_lo) / (y_hi - y_lo) * (bottom - top);
+            (px.clamp(left, right), py.clamp(top, bottom))
+        };
+
+        let (x_ticks, x_decimals) = ticks(x_lo, x_hi);
+        for x in x_ticks {
+            let (px, _) = to_px(x, y_lo);
+            canvas.polyline(&[(px, top), (px, bottom)], LIGHT_GRAY, 1.0, false);
+            let label = format!("{:.*}", x_decimals, x);
+            let w = text_width(&label, TEXT_SCALE);
+            canvas.text(&label, px - w / 2.0, bottom + 8.0, BLACK);
+        }
+        let (y_ticks, y_decimals) = if self.log_y {
+            let ticks = (y_lo.ceil() as i32..=y_hi.floor() as i32)
+                .map(|k| k as f32)
+                .collect();
+            (ticks, 0)
+        } else {
+            ticks(y_lo, y_hi)
+        };
+        for y in y_ticks {
+            let (_, py) = to_px(x_lo, y);
+            canvas.polyline(&[(left, py), (right, py)], LIGHT_GRAY, 1.0, false);
+            let label = if self.log_y {
+                format!("1E{}", y as i32)
+            } else {
+                format!("{:.*}", y_decimals, y)
+            };
+            let w = text_width(&label, TEXT_SCALE);
+            canvas.text(&label, left - w - 8.0, py - TEXT_HEIGHT / 2.0, BLACK);
+        }
+        canvas.polyline(
+            &[
+                (left, top),
+                (left, bottom),
+                (right, bottom),
+                (right, top),
+                (left, top),
+            ],
+            BLACK,
+            1.5,
+            false,
+        );
+
+        let title_width = text_width(&self.title, TEXT_SCALE);
+        canvas.text(&self.title, (left + right - title_width) / 2.0, 8.0, BLACK);
+        canvas.text(&self.y_label, 8.0, top - TEXT_HEIGHT - 8.0, BLACK);
+        let x_label_width = text_width(&self.x_label, TEXT_SCALE);
+        canvas.text(
+            &self.x_label,
+            (left + right - x_label_width) / 2.0,
+            bottom + TEXT_HEIGHT + 20.0,
+            BLACK,
+        );
+
+        for (k, series) in self.series.iter().enumerate() {
+            let color = series.color.unwrap_or(PALETTE[k % PALETTE.len()]);
+            let points = series
+                .points
+                .iter()
+                .filter_map(|(x, y)| self.y_transform(*y).map(|y| to_px(*x, y)))
+                .collect::<Vec<_>>();
+            if series.lines {
+                canvas.polyline(&points, color, 2.0, series.dashed);
+            }
+            if series.markers {
+                for (px, py) in points.iter() {
+                    canvas.fill_rect(px - 3.0, py - 3.0, 6.0, 6.0, color);
+                }
+            }
+        }
+
+        let labelled = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(_, series)| !series.label.is_empty())
+            .collect::<Vec<_>>();
+        if !labelled.is_empty() {
+            let line_height = TEXT_HEIGHT + 8.0;
+            let label_width = labelled
+                .iter()
+                .map(|(_, series)| text_width(&series.label, TEXT_SCALE))
+                .fold(0.0, f32::max);
+            let box_width = label_width + 48.0;
+            let box_x = right - box_width - 8.0;
+            let box_y = top + 8.0;
+            canvas.fill_rect(
+                box_x,
+                box_y,
+                box_width,
+                labelled.len() as f32 * line_height + 8.0,
+                WHITE,
+            );
+            for (row, (k, series)) in labelled.iter().enumerate() {
+                let color = series.color.unwrap_or(PALETTE[k % PALETTE.len()]);
+                let y = box_y + 8.0 + row as f32 * line_height;
+                canvas.polyline(
+                    &[
+                        (box_x + 8.0, y + TEXT_HEIGHT / 2.0),
+                        (box_x + 32.0, y + TEXT_HEIGHT / 2.0),
+                    ],
+                    color,
+                    2.0,
+                    series.dashed,
+                );
+                canvas.text(&series.label, box_x + 40.0, y, BLACK);
+            }
+        }
+    }
+
+    pub fn render(&self) -> Pixmap {
+        let mut canvas = PngCanvas::new(self.width, self.height);
+        self.draw(&mut canvas);
+        canvas.pixmap
+    }
+
+    /// Writes the chart as SVG if `path` ends in `.svg`, as PNG otherwise.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        save_with(path, self.width, self.height, |canvas| self.draw(canvas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticks() {
+        let (t, decimals) = ticks(0.0, 1.0);
+        assert_eq!(t.len(), 6);
+        assert_eq!(decimals, 1);
+        let (t, decimals) = ticks(0.0, 37.0);
+        assert_eq!(t, vec![0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(decimals, 0);
+    }
+
+    #[test]
+    fn test_save_png_and_svg() {
+        let chart = LineChart::new()
+            .set_title("Residual")
+            .set_log_y(true)
+            .add_series(Series::from_values("VI", &[1.0, 0.1, 0.01, 0.0]).set_markers(true));
+        let dir = std::env::temp_dir();
+        let png = dir.join("oamdp_test_line_chart.png");
+        let svg = dir.join("oamdp_test_line_chart.svg");
+        chart.save(png.to_str().unwrap()).unwrap();
+        chart.save(svg.to_str().unwrap()).unwrap();
+
+        assert_eq!(Pixmap::load_png(&png).unwrap().width(), 640);
+        let svg = std::fs::read_to_string(svg).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("1E-2"));
+    }
+}
@@ -0,0 +1,10 @@
+mod belief_plots;
+mod canvas;
+pub(crate) mod font;
+mod line_chart;
+mod simplex_heatmap;
+
+pub use belief_plots::{belief_trajectory_chart, convergence_chart};
+pub use canvas::{Rgb, PALETTE};
+pub use line_chart::{LineChart, Series};
+pub use simplex_heatmap::{viridis, SimplexHeatmap};
@@ -0,0 +1,218 @@
+use std::io;
+use tiny_skia::Pixmap;
+
+use super::canvas::{save_with, Canvas, PngCanvas, Rgb, BLACK, TEXT_SCALE};
+use super::font::{text_width, GLYPH_HEIGHT};
+
+const TEXT_HEIGHT: f32 = GLYPH_HEIGHT as f32 * TEXT_SCALE;
+const VIRIDIS: [Rgb; 5] = [
+    (68, 1, 84),
+    (59, 82, 139),
+    (33, 145, 140),
+    (94, 201, 98),
+    (253, 231, 37),
+];
+
+/// Maps `t` in `[0, 1]` onto the viridis color scale.
+pub fn viridis(t: f32) -> Rgb {
+    let t = t.clamp(0.0, 1.0) * (VIRIDIS.len() - 1) as f32;
+    let k = (t.floor() as usize).min(VIRIDIS.len() - 2);
+    let w = t - k as f32;
+    let (r0, g0, b0) = VIRIDIS[k];
+    let (r1, g1, b1) = VIRIDIS[k + 1];
+    let mix = |a: u8, b: u8| (a as f32 + w * (b as f32 - a as f32)).round() as u8;
+    (mix(r0, r1), mix(g0, g1), mix(b0, b1))
+}
+
+/// Heatmap of a function over the belief simplex of three goals, drawn as
+/// a triangle whose corners are the certain beliefs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimplexHeatmap {
+    title: String,
+    vertex_labels: [String; 3],
+    resolution: usize,
+    width: u32,
+    height: u32,
+}
+
+impl Default for SimplexHeatmap {
+    fn default() -> Self {
+        SimplexHeatmap {
+            title: String::new(),
+            vertex_labels: ["G0".to_string(), "G1".to_string(), "G2".to_string()],
+            resolution: 40,
+            width: 560,
+            height: 480,
+        }
+    }
+}
+
+impl SimplexHeatmap {
+    pub fn new() -> Self {
+        SimplexHeatmap::default()
+    }
+
+    pub fn set_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+
+        self
+    }
+
+    pub fn set_vertex_labels(mut self, labels: [&str; 3]) -> Self {
+        self.vertex_labels = labels.map(|label| label.to_string());
+
+        self
+    }
+
+    /// Number of cells along each edge of the triangle.
+    pub fn set_resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(1);
+
+        self
+    }
+
+    /// Small triangles tiling the simplex, each with the belief at its
+    /// centroid.
+    fn cells(&self) -> Vec<([[f32; 3]; 3], [f32; 3])> {
+        let n = self.resolution;
+        let b = |i: usize, j: usize| {
+            let (b1, b2) = (i as f32 / n as f32, j as f32 / n as f32);
+            [(1.0 - b1 - b2).max(0.0), b1, b2]
+        };
+        let centroid = |corners: &[[f32; 3]; 3]| {
+            let mut c = [0.0; 3];
+            for corner in corners.iter() {
+                for k in 0..3 {
+                    c[k] += corner[k] / 3.0;
+                }
+            }
+            c
+        };
+        let mut cells = vec![];
+        for i in 0..n {
+            for j in 0..(n - i) {
+                let up = [b(i, j), b(i + 1, j), b(i, j + 1)];
+                cells.push((up, centroid(&up)));
+                if i + j + 2 <= n {
+                    let down = [b(i + 1, j), b(i, j + 1), b(i + 1, j + 1)];
+                    cells.push((down, centroid(&down)));
+                }
+            }
+        }
+        cells
+    }
+
+    pub(crate) fn draw(&self, canvas: &mut dyn Canvas, value: &dyn Fn(&[f32; 3]) -> f32) {
+        let left = 40.0;
+        let right = self.width as f32 - 160.0;
+        let bottom = self.height as f32 - 50.0;
+        let side = (right - left).min((bottom - 60.0) * 2.0 / 3f32.sqrt());
+        let right = left + side;
+        let corners = [
+            (left, bottom),
+            (right, bottom),
+            ((left + right) / 2.0, bottom - side * 3f32.sqrt() / 2.0),
+        ];
+        let to_px = |b: &[f32; 3]| {
+            let x = (0..3).map(|k| b[k] * corners[k].0).sum::<f32>();
+            let y = (0..3).map(|k| b[k] * corners[k].1).sum::<f32>();
+            (x, y)
+        };
+
+        let cells = self.cells();
+        let values = cells.iter().map(|(_, c)| value(c)).collect::<Vec<_>>();
+        let (lo, hi) = values
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(*v), hi.max(*v))
+            });
+        let scale = |v: f32| if hi > lo { (v - lo) / (hi - lo) } else { 0.5 };
+        for ((cell, _), v) in cells.iter().zip(values.iter()) {
+            let points = cell.iter().map(to_px).collect::<Vec<_>>();
+            canvas.fill_polygon(&points, viridis(scale(*v)));
+        }
+        let mut outline = corners.to_vec();
+        outline.push(corners[0]);
+        canvas.polyline(&outline, BLACK, 1.5, false);
+
+        let [l0, l1, l2] = &self.vertex_labels;
+        canvas.text(l0, corners[0].0 - 8.0, corners[0].1 + 10.0, BLACK);
+        canvas.text(
+            l1,
+            corners[1].0 - text_width(l1, TEXT_SCALE) + 8.0,
+            corners[1].1 + 10.0,
+            BLACK,
+        );
+        canvas.text(
+            l2,
+            corners[2].0 - text_width(l2, TEXT_SCALE) / 2.0,
+            corners[2].1 - TEXT_HEIGHT - 8.0,
+            BLACK,
+        );
+        let title_width = text_width(&self.title, TEXT_SCALE);
+        canvas.text(
+            &self.title,
+            (self.width as f32 - title_width) / 2.0,
+            8.0,
+            BLACK,
+        );
+
+        let bar_x = self.width as f32 - 130.0;
+        let bar_top = 60.0;
+        let steps = 50;
+        let step_height = (bottom - bar_top) / steps as f32;
+        for k in 0..steps {
+            let t = 1.0 - (k as f32 + 0.5) / steps as f32;
+            canvas.fill_rect(
+                bar_x,
+                bar_top + k as f32 * step_height,
+                16.0,
+                step_height + 0.5,
+                viridis(t),
+            );
+        }
+        if lo.is_finite() && hi.is_finite() {
+            canvas.text(&format!("{:.2}", hi), bar_x + 24.0, bar_top, BLACK);
+            canvas.text(
+                &format!("{:.2}", lo),
+                bar_x + 24.0,
+                bottom - TEXT_HEIGHT,
+                BLACK,
+            );
+        }
+    }
+
+    pub fn render<F: Fn(&[f32; 3]) -> f32>(&self, value: F) -> Pixmap {
+        let mut canvas = PngCanvas::new(self.width, self.height);
+        self.draw(&mut canvas, &value);
+        canvas.pixmap
+    }
+
+    /// Writes the heatmap as SVG if `path` ends in `.svg`, as PNG otherwise.
+    pub fn save<F: Fn(&[f32; 3]) -> f32>(&self, path: &str, value: F) -> io::Result<()> {
+        save_with(path, self.width, self.height, |canvas| {
+            self.draw(canvas, &value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_tile_simplex() {
+        let heatmap = SimplexHeatmap::new().set_resolution(4);
+        let cells = heatmap.cells();
+        assert_eq!(cells.len(), 16);
+        for (_, c) in cells {
+            assert!((c.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        }
+
+        let pixmap = heatmap.render(|b| b[0]);
+        assert_eq!(pixmap.width(), 560);
+        assert_eq!(viridis(0.0), VIRIDIS[0]);
+        assert_eq!(viridis(1.0), VIRIDIS[4]);
+    }
+}
@@ -0,0 +1,345 @@
+use mdp::mdp_traits::{GetNextStateMut, InitialState, IsTerminal, StatesActions};
+use mdp::policy::policy_traits::GetActionMut;
+use mdp::policy::tabular_policy::TabularPolicy;
+use rand::rngs::ThreadRng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+
+use super::StateFeatures;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTreeConfig {
+    pub max_depth: usize,
+    pub min_samples_leaf: usize,
+}
+
+impl Default for DecisionTreeConfig {
+    fn default() -> Self {
+        DecisionTreeConfig {
+            max_depth: 6,
+            min_samples_leaf: 1,
+        }
+    }
+}
+
+impl DecisionTreeConfig {
+    pub fn set_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    pub fn set_min_samples_leaf(mut self, min_samples_leaf: usize) -> Self {
+        self.min_samples_leaf = min_samples_leaf;
+
+        self
+    }
+}
+
+/// A leaf keeps every action that reached it, most frequent first, so the
+/// policy can fall back to the next one when the best is unavailable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DecisionTreeNode<A> {
+    Leaf(Vec<(A, usize)>),
+    Split {
+        feature: usize,
+        threshold: f32,
+        left: Box<DecisionTreeNode<A>>,
+        right: Box<DecisionTreeNode<A>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTree<A> {
+    pub feature_names: Vec<String>,
+    pub root: DecisionTreeNode<A>,
+}
+
+struct Dataset<'a> {
+    features: &'a [Vec<f32>],
+    labels: &'a [usize],
+    num_classes: usize,
+}
+
+fn gini(counts: &[usize], n: usize) -> f32 {
+    if n == 0 {
+        return 0.0;
+    }
+    let n = n as f32;
+    1.0 - counts.iter().map(|c| (*c as f32 / n).powi(2)).sum::<f32>()
+}
+
+impl<A: Eq + Copy + Debug + Hash> DecisionTree<A> {
+    /// Fits a CART classifier (Gini impurity) mapping state features to the
+    /// actions taken by the policy in `samples`.
+    pub fn fit<S: StateFeatures>(samples: &[(S, A)], config: DecisionTreeConfig) -> Self {
+        assert!(!samples.is_empty());
+        let feature_names = samples[0].0.feature_names();
+        let features = samples
+            .iter()
+            .map(|(s, _)| s.features())
+            .collect::<Vec<_>>();
+
+        let mut classes = vec![];
+        let labels = samples
+            .iter()
+            .map(|(_, a)| {
+                if let Some(id) = classes.iter().position(|c| c == a) {
+                    id
+                } else {
+                    classes.push(*a);
+                    classes.len() - 1
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let data = Dataset {
+            features: &features,
+            labels: &labels,
+            num_classes: classes.len(),
+        };
+        let idx = (0..samples.len()).collect::<Vec<_>>();
+        let root = DecisionTree::build(&data, &classes, idx, 0, &config);
+
+        DecisionTree {
+            feature_names,
+            root,
+        }
+    }
+
+    pub fn from_tabular_policy<S>(policy: &TabularPolicy<S, A>, config: DecisionTreeConfig) -> Self
+    where
+        S: Eq + Copy + Debug + Hash + StateFeatures,
+    {
+        let samples = policy
+            .table
+            .iter()
+            .map(|(s, a)| (*s, *a))
+            .collect::<Vec<_>>();
+        DecisionTree::fit(&samples, config)
+    }
+
+    fn leaf(data: &Dataset, classes: &[A], idx: &[usize]) -> DecisionTreeNode<A> {
+        let mut counts = vec![0; data.num_classes];
+        for i in idx {
+            counts[data.labels[*i]] += 1;
+        }
+        let mut actions = counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, c)| *c > 0)
+            .map(|(k, c)| (classes[k], c))
+            .collect::<Vec<_>>();
+        actions.sort_by_key(|(_, c)| std::cmp::Reverse(*c));
+        DecisionTreeNode::Leaf(actions)
+    }
+
+    fn build(
+        data: &Dataset,
+        classes: &[A],
+        mut idx: Vec<usize>,
+        depth: usize,
+        config: &DecisionTreeConfig,
+    ) -> DecisionTreeNode<A> {
+        let n = idx.len();
+        let mut counts = vec![0; data.num_classes];
+        for i in idx.iter() {
+            counts[data.labels[*i]] += 1;
+        }
+        let parent_impurity = gini(&counts, n) * n as f32;
+        if depth >= config.max_depth
+            || n < 2 * config.min_samples_leaf.max(1)
+            || parent_impurity == 0.0
+        {
+            return DecisionTree::leaf(data, classes, &idx);
+        }
+
+        let num_features = data.features[idx[0]].len();
+        let mut best: Option<(usize, f32, f32)> = None;
+        for f in 0..num_features {
+            idx.sort_by(|a, b| {
+                data.features[*a][f]
+                    .partial_cmp(&data.features[*b][f])
+                    .unwrap()
+            });
+            let mut left = vec![0; data.num_classes];
+            let mut right = counts.clone();
+            for k in 1..n {
+                let moved = data.labels[idx[k - 1]];
+                left[moved] += 1;
+                right[moved] -= 1;
+
+                let x_prev = data.features[idx[k - 1]][f];
+                let x = data.features[idx[k]][f];
+                if x_prev == x || k < config.min_samples_leaf || n - k < config.min_samples_leaf {
+                    continue;
+                }
+                let impurity = gini(&left, k) * k as f32 + gini(&right, n - k) * (n - k) as f32;
+                if best.is_none_or(|(_, _, b)| impurity < b) {
+                    best = Some((f, (x_prev + x) / 2.0, impurity));
+                }
+            }
+        }
+
+        match best {
+            Some((feature, threshold, impurity)) if impurity < parent_impurity - 1e-6 => {
+                let (l, r): (Vec<usize>, Vec<usize>) = idx
+                    .into_iter()
+                    .partition(|i| data.features[*i][feature] <= threshold);
+                DecisionTreeNode::Split {
+                    feature,
+                    threshold,
+                    left: Box::new(DecisionTree::build(data, classes, l, depth + 1, config)),
+                    right: Box::new(DecisionTree::build(data, classes, r, depth + 1, config)),
+                }
+            }
+            _ => DecisionTree::leaf(data, classes, &idx),
+        }
+    }
+
+    /// Actions recorded at the leaf reached by `features`, most frequent first.
+    pub fn predict_all(&self, features: &[f32]) -> &[(A, usize)] {
+        let mut node = &self.root;
+        loop {
+            match node {
+                DecisionTreeNode::Leaf(actions) => return actions,
+                DecisionTreeNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                } => {
+                    node = if features[*feature] <= *threshold {
+                        left
+                    } else {
+                        right
+                    };
+                }
+            }
+        }
+    }
+
+    pub fn predict<S: StateFeatures>(&self, s: &S) -> A {
+        self.predict_all(&s.features())[0].0
+    }
+
+    /// Fraction of `samples` on which the tree picks the same action.
+    pub fn fidelity<S: StateFeatures>(&self, samples: &[(S, A)]) -> f32 {
+        let agree = samples
+            .iter()
+            .filter(|(s, a)| self.predict(s) == *a)
+            .count();
+        agree as f32 / samples.len() as f32
+    }
+
+    pub fn depth(&self) -> usize {
+        fn depth<A>(node: &DecisionTreeNode<A>) -> usize {
+            match node {
+                DecisionTreeNode::Leaf(_) => 0,
+                DecisionTreeNode::Split { left, right, .. } => 1 + depth(left).max(depth(right)),
+            }
+        }
+        depth(&self.root)
+    }
+
+    pub fn num_leaves(&self) -> usize {
+        fn num_leaves<A>(node: &DecisionTreeNode<A>) -> usize {
+            match node {
+                DecisionTreeNode::Leaf(_) => 1,
+                DecisionTreeNode::Split { left, right, .. } => num_leaves(left) + num_leaves(right),
+            }
+        }
+        num_leaves(&self.root)
+    }
+
+    fn fmt_node(
+        &self,
+        node: &DecisionTreeNode<A>,
+        indent: usize,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        let pad = "  ".repeat(indent);
+        match node {
+            DecisionTreeNode::Leaf(actions) => {
+                let total = actions.iter().map(|(_, c)| c).sum::<usize>();
+                writeln!(
+                    f,
+                    "{}-> {:?} ({}/{})",
+                    pad, actions[0].0, actions[0].1, total
+                )
+            }
+            DecisionTreeNode::Split {
+                feature,
+                threshold,
+                left,
+                right,
+            } => {
+                let name = &self.feature_names[*feature];
+                writeln!(f, "{}if {} <= {}:", pad, name, threshold)?;
+                self.fmt_node(left, indent + 1, f)?;
+                writeln!(f, "{}else:", pad)?;
+                self.fmt_node(right, indent + 1, f)
+            }
+        }
+    }
+}
+
+impl<A: Eq + Copy + Debug + Hash> Display for DecisionTree<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_node(&self.root, 0, f)
+    }
+}
+
+/// Records the state-action pairs visited by `policy` over `num_episodes`
+/// episodes of at most `max_steps` steps, as training data for
+/// `DecisionTree::fit`.
+pub fn collect_samples<M, P>(
+    mdp: &mut M,
+    policy: &mut P,
+    rng: &mut ThreadRng,
+    num_episodes: usize,
+    max_steps: usize,
+) -> Vec<(M::State, M::Action)>
+where
+    M: StatesActions + InitialState + IsTerminal + GetNextStateMut,
+    P: GetActionMut<M::State, M>,
+{
+    let mut samples = vec![];
+    for _ in 0..num_episodes {
+        let mut s = mdp.initial_state();
+        for _ in 0..max_steps {
+            if mdp.is_terminal(&s) {
+                break;
+            }
+            if let Some(a) = policy.get_action_mut(&s, mdp, rng) {
+                samples.push((s, a));
+                s = mdp.get_next_state_mut(&s, &a, rng);
+            } else {
+                break;
+            }
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::common::coordinate2::Coordinate2;
+
+    use super::*;
+
+    #[test]
+    fn test_fit_separable() {
+        let samples = (0..5)
+            .flat_map(|i| (0..5).map(move |j| (Coordinate2::new(i, j), i < 2 || j == 4)))
+            .collect::<Vec<_>>();
+        let tree = DecisionTree::fit(&samples, DecisionTreeConfig::default());
+        assert_eq!(tree.fidelity(&samples), 1.0);
+        assert_eq!(tree.num_leaves(), 3);
+
+        let stump = DecisionTree::fit(&samples, DecisionTreeConfig::default().set_max_depth(1));
+        assert_eq!(stump.depth(), 1);
+        assert!(stump.fidelity(&samples) < 1.0);
+    }
+}
@@ -0,0 +1,86 @@
+use mdp::mdp_traits::{ActionAvailability, StatesActions};
+use mdp::policy::policy_traits::{GetAction, GetActionMut};
+use rand::rngs::ThreadRng;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use super::{DecisionTree, StateFeatures};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTreePolicy<A> {
+    pub tree: DecisionTree<A>,
+}
+
+impl<A> DecisionTreePolicy<A> {
+    pub fn new(tree: DecisionTree<A>) -> DecisionTreePolicy<A> {
+        DecisionTreePolicy { tree }
+    }
+}
+
+impl<M: StatesActions + ActionAvailability> GetAction<M::State, M> for DecisionTreePolicy<M::Action>
+where
+    M::State: StateFeatures,
+    M::Action: Eq + Copy + Debug + Hash,
+{
+    fn get_action(&self, s: &M::State, mdp: &M, _rng: &mut ThreadRng) -> Option<M::Action> {
+        self.tree
+            .predict_all(&s.features())
+            .iter()
+            .map(|(a, _)| *a)
+            .find(|a| mdp.action_available(s, a))
+    }
+}
+
+impl<M: StatesActions + ActionAvailability> GetActionMut<M::State, M>
+    for DecisionTreePolicy<M::Action>
+where
+    M::State: StateFeatures,
+    M::Action: Eq + Copy + Debug + Hash,
+{
+    fn get_action_mut(
+        &mut self,
+        s: &M::State,
+        mdp: &mut M,
+        rng: &mut ThreadRng,
+    ) -> Option<M::Action> {
+        self.get_action(s, mdp, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::mdp_traits::{Build, InitialState};
+    use rand::thread_rng;
+
+    use crate::algorithms::rtdp::{RTDPTrait, RTDP_OAMDP};
+    use crate::domains::baker_grid::BakerOAMDPBuilder;
+    use crate::policy_distillation::{collect_samples, DecisionTreeConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_distill_baker_oamdp_policy() {
+        let mut rng = thread_rng();
+        let oamdp = BakerOAMDPBuilder::<3>::new(101).build().mdp;
+        let mut solver = RTDP_OAMDP::new(oamdp, ZeroHeuristic {}, 2);
+        solver.lrtdp(0, &mut rng);
+
+        let samples = collect_samples(&mut solver.oamdp_d, &mut solver.rtdp, &mut rng, 20, 30);
+        assert!(!samples.is_empty());
+        assert!(samples.len() <= 20 * 30);
+        let tree = DecisionTree::fit(&samples, DecisionTreeConfig::default().set_max_depth(12));
+        assert!(tree.fidelity(&samples) > 0.95);
+
+        let held_out = collect_samples(&mut solver.oamdp_d, &mut solver.rtdp, &mut rng, 10, 30);
+        assert!(tree.fidelity(&held_out) > 0.85);
+
+        let mut policy = DecisionTreePolicy::new(tree);
+        let s0 = solver.oamdp_d.initial_state();
+        assert_eq!(
+            policy.get_action_mut(&s0, &mut solver.oamdp_d, &mut rng),
+            solver.rtdp.best_action_mut(&s0, &mut solver.oamdp_d)
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use mdp::common::coordinate2::Coordinate2;
+use mdp::finite_horizon_wrapper::FiniteHorizonWrapperState;
+use mdp::grid_turning::GridTurningState;
+use mdp::race_track::RaceTrackState;
+use mdp::search_rescue::{ObstacleStatus, SearchRescueState};
+use mdp::simple_av::{SimpleAVVehicleInFrontState, VehicleConfiguration};
+use mdp::simple_av_lane_change::SimpleAVLaneChangeState;
+use mdp::simple_av_obstacle_avoidance::ObstacleAvoidanceState;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::oamdp::BeliefState;
+use crate::oamdp_d::VState;
+
+/// Numeric description of a state that a decision tree can split on.
+pub trait StateFeatures {
+    fn features(&self) -> Vec<f32>;
+    fn feature_names(&self) -> Vec<String>;
+}
+
+impl StateFeatures for Coordinate2 {
+    fn features(&self) -> Vec<f32> {
+        vec![self.i as f32, self.j as f32]
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        vec!["i".to_string(), "j".to_string()]
+    }
+}
+
+fn prefixed(prefix: &str, names: Vec<String>) -> Vec<String> {
+    names
+        .into_iter()
+        .map(|name| format!("{}.{}", prefix, name))
+        .collect()
+}
+
+impl StateFeatures for GridTurningState {
+    fn features(&self) -> Vec<f32> {
+        vec![self.i as f32, self.j as f32, self.direction as usize as f32]
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        vec!["i".to_string(), "j".to_string(), "direction".to_string()]
+    }
+}
+
+/// The dummy state before the start line has every feature at -1.
+impl StateFeatures for RaceTrackState {
+    fn features(&self) -> Vec<f32> {
+        match self {
+            RaceTrackState::Dummy => vec![-1.0; 4],
+            RaceTrackState::Wrapper(s) => {
+                vec![s.x as f32, s.y as f32, s.dx as f32, s.dy as f32]
+            }
+        }
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        vec![
+            "x".to_string(),
+            "y".to_string(),
+            "dx".to_string(),
+            "dy".to_string(),
+        ]
+    }
+}
+
+impl StateFeatures for SearchRescueState {
+    fn features(&self) -> Vec<f32> {
+        let c = self.coordinate();
+        let mut features = vec![c.i as f32, c.j as f32, self.victim_status() as usize as f32];
+        features.extend(
+            self.obstacles_status()
+                .iter()
+                .map(|o| (*o == ObstacleStatus::Removed) as usize as f32),
+        );
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = vec!["i".to_string(), "j".to_string(), "victim".to_string()];
+        names.extend((0..4).map(|k| format!("removed[{}]", k)));
+        names
+    }
+}
+
+impl StateFeatures for VehicleConfiguration {
+    fn features(&self) -> Vec<f32> {
+        vec![self.y as f32, self.dy as f32]
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        vec!["y".to_string(), "dy".to_string()]
+    }
+}
+
+impl StateFeatures for SimpleAVVehicleInFrontState {
+    fn features(&self) -> Vec<f32> {
+        let mut features = self.ego_vehicle().features();
+        features.extend(self.vehicle_in_front().features());
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = prefixed("ego", self.ego_vehicle().feature_names());
+        names.extend(prefixed("front", self.vehicle_in_front().feature_names()));
+        names
+    }
+}
+
+impl StateFeatures for ObstacleAvoidanceState {
+    fn features(&self) -> Vec<f32> {
+        let ego = self.ego_vehicle;
+        let mut features = vec![ego.y as f32, ego.dy as f32, ego.lane() as usize as f32];
+        features.extend(self.other_vehicle.features());
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = prefixed(
+            "ego",
+            vec!["y".to_string(), "dy".to_string(), "lane".to_string()],
+        );
+        names.extend(prefixed("other", self.other_vehicle.feature_names()));
+        names
+    }
+}
+
+impl StateFeatures for SimpleAVLaneChangeState {
+    fn features(&self) -> Vec<f32> {
+        let ego = self.ego_vehicle;
+        let mut features = vec![ego.y as f32, ego.dy as f32, ego.lane() as usize as f32];
+        features.extend(self.other_vehicle.features());
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = prefixed(
+            "ego",
+            vec!["y".to_string(), "dy".to_string(), "lane".to_string()],
+        );
+        names.extend(prefixed("other", self.other_vehicle.feature_names()));
+        names
+    }
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash + StateFeatures, const N: usize> StateFeatures
+    for BeliefState<S, N>
+{
+    fn features(&self) -> Vec<f32> {
+        let mut features = self.s.features();
+        features.extend(self.belief_over_goal.iter().map(|p| p.into_inner()));
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = self.s.feature_names();
+        names.extend((0..N).map(|k| format!("b[{}]", k)));
+        names
+    }
+}
+
+impl<S: PartialEq + Debug + Copy + Clone + StateFeatures> StateFeatures
+    for FiniteHorizonWrapperState<S>
+{
+    fn features(&self) -> Vec<f32> {
+        let mut features = self.s.features();
+        features.push(self.t as f32);
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = self.s.feature_names();
+        names.push("t".to_string());
+        names
+    }
+}
+
+/// The discretized belief is described by its grid indices.
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash + StateFeatures, const N: usize> StateFeatures
+    for VState<S, N>
+{
+    fn features(&self) -> Vec<f32> {
+        let mut features = self.s.features();
+        features.extend(self.v.iter().map(|v| *v as f32));
+        features
+    }
+
+    fn feature_names(&self) -> Vec<String> {
+        let mut names = self.s.feature_names();
+        names.extend((0..N).map(|k| format!("v[{}]", k)));
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::simple_av_obstacle_avoidance::{Lane, VehicleConfigurationLane};
+
+    use super::*;
+
+    fn assert_named<S: StateFeatures>(s: &S, names: &[&str]) {
+        assert_eq!(s.feature_names(), names);
+        assert_eq!(s.features().len(), names.len());
+    }
+
+    #[test]
+    fn test_state_features() {
+        let s = ObstacleAvoidanceState::new(
+            VehicleConfigurationLane::new(3, 1, Lane::Right),
+            VehicleConfiguration::new(5, 2),
+        );
+        assert_named(&s, &["ego.y", "ego.dy", "ego.lane", "other.y", "other.dy"]);
+        assert_eq!(s.features(), vec![3.0, 1.0, 2.0, 5.0, 2.0]);
+
+        let vs = VState::new(Coordinate2::new(1, 2), [0, 3]);
+        assert_named(&vs, &["i", "j", "v[0]", "v[1]"]);
+        assert_eq!(vs.features(), vec![1.0, 2.0, 0.0, 3.0]);
+
+        assert_named(&RaceTrackState::Dummy, &["x", "y", "dx", "dy"]);
+    }
+}
@@ -0,0 +1,7 @@
+mod decision_tree;
+mod decision_tree_policy;
+mod features;
+
+pub use decision_tree::{collect_samples, DecisionTree, DecisionTreeConfig, DecisionTreeNode};
+pub use decision_tree_policy::DecisionTreePolicy;
+pub use features::StateFeatures;
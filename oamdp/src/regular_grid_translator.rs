@@ -153,11 +153,10 @@ impl<const N: usize> RegularGridTranslator<N> {
         result
     }
 
-    //     pub(crate) fn discretize(&self, b: &[NotNan<f32>]) -> [NotNan<f32>; N] {
-    //         let x = self.b_to_x(b);
-    //         let v = self.x_to_v(x);
-    //         self.v_to_b(&v)
-    //     }
+    pub fn discretize(&self, b: &[NotNan<f32>]) -> [NotNan<f32>; N] {
+        let v = self.b_to_v(b);
+        self.v_to_b(&v)
+    }
 }
 
 #[cfg(test)]
@@ -237,6 +236,30 @@ mod tests {
         println!("{:?}", result);
     }
 
+    #[test]
+    fn test_discretize() {
+        let t = RegularGridTranslator::<3> { num_bin_per_dim: 4 };
+        let b = [
+            NotNan::<f32>::from_f32(0.27).unwrap(),
+            NotNan::<f32>::from_f32(0.26).unwrap(),
+            NotNan::<f32>::from_f32(0.47).unwrap(),
+        ];
+        let bb = [
+            NotNan::<f32>::from_f32(0.26).unwrap(),
+            NotNan::<f32>::from_f32(0.27).unwrap(),
+            NotNan::<f32>::from_f32(0.47).unwrap(),
+        ];
+        assert_eq!(t.discretize(&b), t.discretize(&bb));
+        assert_eq!(t.discretize(&t.discretize(&b)), t.discretize(&b));
+
+        let bbb = [
+            NotNan::<f32>::from_f32(0.8).unwrap(),
+            NotNan::<f32>::from_f32(0.1).unwrap(),
+            NotNan::<f32>::from_f32(0.1).unwrap(),
+        ];
+        assert_ne!(t.discretize(&b), t.discretize(&bbb));
+    }
+
     #[test]
     fn test_b_to_p() {
         let t = RegularGridTranslator::<3> {
@@ -1,6 +1,7 @@
 use mdp::heuristic::{HeuristicWithMDP, HeuristicWithMDPMut};
 use mdp::into_inner::Inner;
-use mdp::mdp_traits::StatesActions;
+use mdp::mdp_traits::{ActionAvailability, ActionEnumerable, Cost, PMass, StatesActions};
+use mdp::value_estimator::{CostEstimator, CostEstimatorMut};
 use mdp::value_iteration::ValueTable;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -39,3 +40,38 @@ where
         self.alpha * self.vt.get_value(&s.inner())
     }
 }
+
+impl<S: Eq + PartialEq + Debug + Clone + Hash + Copy, M> CostEstimator<M> for ScaledValueTable<S>
+where
+    M: StatesActions + ActionEnumerable + ActionAvailability + PMass<f32> + Cost,
+    M::State: Inner<Result = S>,
+{
+    fn get_qsa_ssp(&self, s: &M::State, a: &M::Action, mdp: &M) -> f32 {
+        mdp.p_mass(s, a)
+            .into_iter()
+            .map(|(ss, p)| self.alpha * self.vt.get_value(&ss.inner()) * p)
+            .sum::<f32>()
+            + mdp.cost(s, a)
+    }
+
+    fn get_value_ssp(&self, s: &M::State, mdp: &M) -> f32 {
+        mdp.enumerate_actions()
+            .filter(|a| mdp.action_available(s, a))
+            .map(|a| self.get_qsa_ssp(s, a, mdp))
+            .fold(1. / 0., f32::min)
+    }
+}
+
+impl<S: Eq + PartialEq + Debug + Clone + Hash + Copy, M> CostEstimatorMut<M> for ScaledValueTable<S>
+where
+    M: StatesActions + ActionEnumerable + ActionAvailability + PMass<f32> + Cost,
+    M::State: Inner<Result = S>,
+{
+    fn get_qsa_ssp_mut(&mut self, s: &M::State, a: &M::Action, mdp: &mut M) -> f32 {
+        self.get_qsa_ssp(s, a, mdp)
+    }
+
+    fn get_value_ssp_mut(&mut self, s: &M::State, mdp: &mut M) -> f32 {
+        self.get_value_ssp(s, mdp)
+    }
+}
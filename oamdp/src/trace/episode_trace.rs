@@ -0,0 +1,84 @@
+use ordered_float::NotNan;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+/// One step of an episode: the observer's belief when `a` is taken in `s`
+/// and the cost incurred. The last step of a finished episode has no action.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TraceStep<S, A, const N: usize> {
+    pub t: usize,
+    pub s: S,
+    pub a: Option<A>,
+    #[serde(with = "serde_arrays")]
+    pub belief: [f32; N],
+    pub cost: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Trace<S, A, const N: usize> {
+    pub steps: Vec<TraceStep<S, A, N>>,
+}
+
+impl<S: Copy, A: Copy, const N: usize> Trace<S, A, N> {
+    pub fn new(steps: Vec<TraceStep<S, A, N>>) -> Self {
+        Trace { steps }
+    }
+
+    pub fn total_cost(&self) -> f32 {
+        self.steps.iter().map(|step| step.cost).sum()
+    }
+
+    /// The `(s, a)` pairs expected by `OAMDP::get_belief_changes` and the
+    /// `render_trace` functions of the domains.
+    pub fn domain_trace(&self) -> Vec<(S, Option<A>)> {
+        self.steps.iter().map(|step| (step.s, step.a)).collect()
+    }
+
+    pub fn beliefs(&self) -> Vec<[NotNan<f32>; N]> {
+        self.steps
+            .iter()
+            .map(|step| step.belief.map(|p| NotNan::new(p).unwrap()))
+            .collect()
+    }
+}
+
+/// Writes one episode per line.
+pub fn write_jsonl<S: Serialize, A: Serialize, W: Write, const N: usize>(
+    traces: &[Trace<S, A, N>],
+    mut writer: W,
+) -> io::Result<()> {
+    for trace in traces {
+        serde_json::to_writer(&mut writer, trace)?;
+        writeln!(writer)?;
+    }
+    writer.flush()
+}
+
+pub fn read_jsonl<S: DeserializeOwned, A: DeserializeOwned, R: BufRead, const N: usize>(
+    reader: R,
+) -> io::Result<Vec<Trace<S, A, N>>> {
+    let mut traces = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        traces.push(serde_json::from_str(&line)?);
+    }
+    Ok(traces)
+}
+
+pub fn save_jsonl<S: Serialize, A: Serialize, const N: usize>(
+    traces: &[Trace<S, A, N>],
+    path: &str,
+) -> io::Result<()> {
+    write_jsonl(traces, BufWriter::new(File::create(path)?))
+}
+
+pub fn load_jsonl<S: DeserializeOwned, A: DeserializeOwned, const N: usize>(
+    path: &str,
+) -> io::Result<Vec<Trace<S, A, N>>> {
+    read_jsonl(BufReader::new(File::open(path)?))
+}
@@ -0,0 +1,8 @@
+mod episode_trace;
+mod record;
+mod render;
+mod replay;
+
+pub use episode_trace::{load_jsonl, read_jsonl, save_jsonl, write_jsonl, Trace, TraceStep};
+pub use record::{record_episode, record_episode_with, TraceState};
+pub use render::TraceRenderer;
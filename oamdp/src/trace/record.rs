@@ -0,0 +1,123 @@
+use mdp::finite_horizon_wrapper::FiniteHorizonWrapperState;
+use mdp::mdp_traits::{DCost, GetNextStateMut, InitialState, IsTerminal, StatesActions};
+use mdp::policy::policy_traits::GetActionMut;
+use rand::rngs::ThreadRng;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::message_budget::MessageBudgetState;
+use crate::message_channel::MessageChannelState;
+use crate::oamdp::BeliefState;
+use crate::traits::BeliefOverGoal;
+
+use super::{Trace, TraceStep};
+
+/// States of (wrapped) finite horizon OAMDPs that can be written to a trace.
+pub trait TraceState<S, const N: usize>: BeliefOverGoal<N> {
+    fn domain_state(&self) -> S;
+    fn time_step(&self) -> usize;
+}
+
+impl<S: Eq + PartialEq + Debug + Copy + Clone + Hash, const N: usize> TraceState<S, N>
+    for FiniteHorizonWrapperState<BeliefState<S, N>>
+{
+    fn domain_state(&self) -> S {
+        self.s.s
+    }
+
+    fn time_step(&self) -> usize {
+        self.t
+    }
+}
+
+impl<X, S, const N: usize> TraceState<S, N> for MessageBudgetState<X>
+where
+    X: Eq + PartialEq + Debug + Copy + Clone + Hash + TraceState<S, N>,
+{
+    fn domain_state(&self) -> S {
+        self.s.domain_state()
+    }
+
+    fn time_step(&self) -> usize {
+        self.s.time_step()
+    }
+}
+
+impl<X, Msg, S, const N: usize> TraceState<S, N> for MessageChannelState<X, Msg>
+where
+    X: Eq + PartialEq + Debug + Copy + Clone + Hash + TraceState<S, N>,
+    Msg: Eq + PartialEq + Debug + Copy + Clone + Hash,
+{
+    fn domain_state(&self) -> S {
+        self.s.domain_state()
+    }
+
+    fn time_step(&self) -> usize {
+        self.s.time_step()
+    }
+}
+
+/// Runs `policy` from the initial state until a terminal state is reached
+/// and records every step.
+pub fn record_episode<M, P, S, const N: usize>(
+    mdp: &mut M,
+    policy: &mut P,
+    rng: &mut ThreadRng,
+) -> Trace<S, M::Action, N>
+where
+    M: StatesActions + InitialState + IsTerminal + GetNextStateMut + DCost,
+    M::State: TraceState<S, N>,
+    P: GetActionMut<M::State, M>,
+    S: Copy,
+{
+    record_episode_with(mdp, policy, rng, |_, _, _| {})
+}
+
+/// `record_episode` that also calls `inspect` on every recorded state, after
+/// `policy` has chosen its action.
+pub fn record_episode_with<M, P, S, F, const N: usize>(
+    mdp: &mut M,
+    policy: &mut P,
+    rng: &mut ThreadRng,
+    mut inspect: F,
+) -> Trace<S, M::Action, N>
+where
+    M: StatesActions + InitialState + IsTerminal + GetNextStateMut + DCost,
+    M::State: TraceState<S, N>,
+    P: GetActionMut<M::State, M>,
+    S: Copy,
+    F: FnMut(&M::State, &mut M, &mut P),
+{
+    let mut steps = vec![];
+    let mut s = mdp.initial_state();
+    loop {
+        let belief = s.get_belief_over_goal().map(|p| p.into_inner());
+        let a = if mdp.is_terminal(&s) {
+            None
+        } else {
+            policy.get_action_mut(&s, mdp, rng)
+        };
+        inspect(&s, mdp, policy);
+        if let Some(a) = a {
+            let ss = mdp.get_next_state_mut(&s, &a, rng);
+            steps.push(TraceStep {
+                t: s.time_step(),
+                s: s.domain_state(),
+                a: Some(a),
+                belief,
+                cost: mdp.d_cost(&s, &a, &ss),
+            });
+            s = ss;
+        } else {
+            steps.push(TraceStep {
+                t: s.time_step(),
+                s: s.domain_state(),
+                a: None,
+                belief,
+                cost: 0.0,
+            });
+            break;
+        }
+    }
+    Trace::new(steps)
+}
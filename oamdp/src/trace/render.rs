@@ -0,0 +1,340 @@
+use mdp::mdp_traits::RenderTo;
+use std::fs::{self, File};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tiny_skia::{Color, Paint, PathBuilder, Pixmap, PixmapPaint, Rect, Stroke, Transform};
+
+use super::Trace;
+use crate::plotting::font::{draw_text, text_width, GLYPH_HEIGHT};
+use crate::plotting::PALETTE;
+
+const SCALE: f32 = 2.0;
+const PAD: f32 = 8.0;
+const LINE_HEIGHT: f32 = GLYPH_HEIGHT as f32 * SCALE + PAD;
+const MIN_WIDTH: u32 = 280;
+
+static NEXT_FRAME_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn fill_rect(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, color: Color) {
+    if let Some(rect) = Rect::from_xywh(x, y, w, h) {
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+    }
+}
+
+type MessageText<'a, A> = Box<dyn Fn(&A) -> Option<String> + 'a>;
+
+/// Turns a recorded episode into annotated frames: the domain rendering of
+/// each state with the observer's belief as a bar chart, the step counter and
+/// the message sent at that step.
+pub struct TraceRenderer<'a, A> {
+    goal_names: Vec<String>,
+    message: Option<MessageText<'a, A>>,
+    frame_delay: u16,
+}
+
+impl<'a, A> Default for TraceRenderer<'a, A> {
+    fn default() -> Self {
+        TraceRenderer {
+            goal_names: vec![],
+            message: None,
+            frame_delay: 50,
+        }
+    }
+}
+
+impl<'a, A> TraceRenderer<'a, A> {
+    pub fn new() -> Self {
+        TraceRenderer::default()
+    }
+
+    pub fn set_goal_names(mut self, goal_names: &[&str]) -> Self {
+        self.goal_names = goal_names.iter().map(|name| name.to_string()).collect();
+
+        self
+    }
+
+    /// `message` returns the text of the message carried by an action, if
+    /// any, and is drawn as a speech bubble.
+    pub fn set_message<F: Fn(&A) -> Option<String> + 'a>(mut self, message: F) -> Self {
+        self.message = Some(Box::new(message));
+
+        self
+    }
+
+    /// Delay between GIF frames in hundredths of a second.
+    pub fn set_frame_delay(mut self, frame_delay: u16) -> Self {
+        self.frame_delay = frame_delay;
+
+        self
+    }
+
+    fn goal_name(&self, k: usize) -> String {
+        self.goal_names
+            .get(k)
+            .cloned()
+            .unwrap_or_else(|| format!("G{}", k))
+    }
+
+    /// `RenderTo` only writes files, so each frame goes through its own
+    /// temporary PNG to keep concurrent renderers apart. Domains whose
+    /// renderer writes nothing give `None`.
+    fn render_domain<M: RenderTo>(mdp: &M, s: &M::State) -> Option<Pixmap> {
+        let path = std::env::temp_dir().join(format!(
+            "oamdp_trace_frame_{}_{}.png",
+            std::process::id(),
+            NEXT_FRAME_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path = path.to_str().unwrap();
+        mdp.render_to(s, path);
+        let pixmap = Pixmap::load_png(path).ok();
+        fs::remove_file(path).ok();
+        pixmap
+    }
+
+    fn draw_bubble(&self, pixmap: &mut Pixmap, text: &str) {
+        let (x, y) = (PAD, PAD);
+        let w = text_width(text, SCALE) + 2.0 * PAD;
+        let h = LINE_HEIGHT + PAD;
+        fill_rect(pixmap, x, y, w, h, Color::WHITE);
+
+        let mut pb = PathBuilder::new();
+        pb.move_to(x, y);
+        pb.line_to(x + w, y);
+        pb.line_to(x + w, y + h);
+        pb.line_to(x + 3.0 * PAD, y + h);
+        pb.line_to(x + 1.5 * PAD, y + h + PAD);
+        pb.line_to(x + 1.5 * PAD, y + h);
+        pb.line_to(x, y + h);
+        pb.close();
+        let mut paint = Paint::default();
+        paint.set_color(Color::BLACK);
+        let stroke = Stroke {
+            width: 2.0,
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(
+            &pb.finish().unwrap(),
+            &paint,
+            &stroke,
+            Transform::identity(),
+            None,
+        );
+        draw_text(pixmap, text, x + PAD, y + PAD, SCALE, Color::BLACK);
+    }
+
+    fn draw_panel<const N: usize>(
+        &self,
+        pixmap: &mut Pixmap,
+        top: f32,
+        t: usize,
+        belief: &[f32; N],
+        cost: f32,
+    ) {
+        let width = pixmap.width() as f32;
+        draw_text(
+            pixmap,
+            &format!("T={} COST={:.2}", t, cost),
+            PAD,
+            top + PAD,
+            SCALE,
+            Color::BLACK,
+        );
+
+        let label_width = (0..N)
+            .map(|k| text_width(&self.goal_name(k), SCALE))
+            .fold(0.0, f32::max);
+        let value_width = text_width("0.00", SCALE);
+        let bar_x = 2.0 * PAD + label_width;
+        let bar_width = width - bar_x - value_width - 2.0 * PAD;
+        for (k, p) in belief.iter().enumerate() {
+            let y = top + PAD + (k + 1) as f32 * LINE_HEIGHT;
+            let (r, g, b) = PALETTE[k % PALETTE.len()];
+            draw_text(pixmap, &self.goal_name(k), PAD, y, SCALE, Color::BLACK);
+            let h = GLYPH_HEIGHT as f32 * SCALE;
+            fill_rect(
+                pixmap,
+                bar_x,
+                y,
+                bar_width,
+                h,
+                Color::from_rgba8(220, 220, 220, 255),
+            );
+            fill_rect(
+                pixmap,
+                bar_x,
+                y,
+                bar_width * p.clamp(0.0, 1.0),
+                h,
+                Color::from_rgba8(r, g, b, 255),
+            );
+            draw_text(
+                pixmap,
+                &format!("{:.2}", p),
+                bar_x + bar_width + PAD,
+                y,
+                SCALE,
+                Color::BLACK,
+            );
+        }
+    }
+
+    pub fn render_frames<M: RenderTo, const N: usize>(
+        &self,
+        mdp: &M,
+        trace: &Trace<M::State, A, N>,
+    ) -> Vec<Pixmap> {
+        let panel_height = (N + 1) as f32 * LINE_HEIGHT + 2.0 * PAD;
+        let mut cumulative_cost = 0.0;
+        let mut frames = vec![];
+        for step in trace.steps.iter() {
+            let domain = TraceRenderer::<A>::render_domain(mdp, &step.s);
+            // Without a domain rendering, keep room above the panel for the
+            // speech bubble.
+            let (domain_width, domain_height) = domain
+                .as_ref()
+                .map_or((0, (LINE_HEIGHT + 3.0 * PAD) as u32), |domain| {
+                    (domain.width(), domain.height())
+                });
+            let width = domain_width.max(MIN_WIDTH);
+            let height = domain_height + panel_height as u32;
+            let mut frame = Pixmap::new(width, height).unwrap();
+            frame.fill(Color::WHITE);
+            if let Some(domain) = &domain {
+                frame.draw_pixmap(
+                    0,
+                    0,
+                    domain.as_ref(),
+                    &PixmapPaint::default(),
+                    Transform::identity(),
+                    None,
+                );
+            }
+
+            let message = match (&self.message, &step.a) {
+                (Some(message), Some(a)) => message(a),
+                _ => None,
+            };
+            if let Some(text) = message {
+                self.draw_bubble(&mut frame, &text);
+            }
+
+            cumulative_cost += step.cost;
+            self.draw_panel(
+                &mut frame,
+                domain_height as f32,
+                step.t,
+                &step.belief,
+                cumulative_cost,
+            );
+            frames.push(frame);
+        }
+        frames
+    }
+
+    /// Writes `<prefix>_<t>.png` for every step and returns the paths.
+    pub fn save_frames<M: RenderTo, const N: usize>(
+        &self,
+        mdp: &M,
+        trace: &Trace<M::State, A, N>,
+        prefix: &str,
+    ) -> Vec<String> {
+        self.render_frames(mdp, trace)
+            .iter()
+            .enumerate()
+            .map(|(k, frame)| {
+                let path = format!("{}_{:03}.png", prefix, k);
+                frame.save_png(&path).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    pub fn save_gif<M: RenderTo, const N: usize>(
+        &self,
+        mdp: &M,
+        trace: &Trace<M::State, A, N>,
+        path: &str,
+    ) {
+        let frames = self.render_frames(mdp, trace);
+        let width = frames.iter().map(|f| f.width()).max().unwrap_or(1);
+        let height = frames.iter().map(|f| f.height()).max().unwrap_or(1);
+
+        let file = File::create(path).expect("Unable to create gif");
+        let mut encoder = gif::Encoder::new(file, width as u16, height as u16, &[]).unwrap();
+        encoder.set_repeat(gif::Repeat::Infinite).unwrap();
+        for frame in frames {
+            let mut canvas = Pixmap::new(width, height).unwrap();
+            canvas.fill(Color::WHITE);
+            canvas.draw_pixmap(
+                0,
+                0,
+                frame.as_ref(),
+                &PixmapPaint::default(),
+                Transform::identity(),
+                None,
+            );
+            let mut rgba = canvas.data().to_vec();
+            let mut gif_frame =
+                gif::Frame::from_rgba_speed(width as u16, height as u16, &mut rgba, 10);
+            gif_frame.delay = self.frame_delay;
+            encoder.write_frame(&gif_frame).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mdp::baker_grid::BakerGridState;
+    use mdp::mdp_traits::Build;
+    use rand::thread_rng;
+
+    use crate::domains::baker_grid::{
+        BakerCOAMDPBuilder, BakerCommunicationAction, BakerJointAction,
+    };
+    use crate::domains::simple_av::SimpleAVOAMDPBuilder;
+    use crate::policy::RandomOAMDPPolicy;
+    use crate::trace::{record_episode, Trace};
+
+    use super::{TraceRenderer, MIN_WIDTH};
+
+    #[test]
+    fn test_render_baker_trace() {
+        let mut rng = thread_rng();
+        let mut oamdp = BakerCOAMDPBuilder::new(1).set_horizon(5).build();
+        let mut policy = RandomOAMDPPolicy::new();
+        let trace: Trace<BakerGridState, BakerJointAction, 3> =
+            record_episode(&mut oamdp, &mut policy, &mut rng);
+
+        let renderer = TraceRenderer::new()
+            .set_goal_names(&["A", "B", "C"])
+            .set_message(|a: &BakerJointAction| {
+                let m: BakerCommunicationAction = (*a).into();
+                if m == BakerCommunicationAction::None {
+                    None
+                } else {
+                    Some(format!("{:?}", m))
+                }
+            });
+        let frames = renderer.render_frames(&oamdp.mdp.mdp, &trace);
+        assert_eq!(frames.len(), trace.steps.len());
+        assert!(frames.iter().all(|f| f.width() == frames[0].width()));
+
+        let path = std::env::temp_dir().join("oamdp_test_render_baker_trace.gif");
+        renderer.save_gif(&oamdp.mdp.mdp, &trace, path.to_str().unwrap());
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..6], b"GIF89a");
+    }
+
+    #[test]
+    fn test_render_trace_without_domain_rendering() {
+        let mut rng = thread_rng();
+        let mut oamdp = SimpleAVOAMDPBuilder::<3>::new(1).set_horizon(5).build();
+        let mut policy = RandomOAMDPPolicy::new();
+        let trace: Trace<_, _, 3> = record_episode(&mut oamdp, &mut policy, &mut rng);
+
+        let frames = TraceRenderer::new().render_frames(&oamdp.mdp.mdp, &trace);
+        assert_eq!(frames.len(), trace.steps.len());
+        assert!(frames.iter().all(|f| f.width() == MIN_WIDTH));
+    }
+}
@@ -0,0 +1,89 @@
+use mdp::into_inner::Inner;
+use mdp::mdp_traits::{ActionEnumerable, DCost, StatesActions};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::oamdp::oamdp::OAMDP;
+use crate::oamdp::BeliefState;
+use crate::traits::ProbSassGivenTheta;
+
+use super::{Trace, TraceStep};
+
+impl<OM, M, A, const N: usize> OAMDP<OM, M, A, N>
+where
+    M: StatesActions,
+    A: Eq + Debug + Hash + Copy + Inner<Result = M::Action>,
+    Self: StatesActions<State = BeliefState<M::State, N>, Action = A> + ActionEnumerable + DCost,
+    for<'a> &'a OM: ProbSassGivenTheta<M::State, A>,
+{
+    /// Re-evaluates a recorded episode under this OAMDP's observer model,
+    /// belief cost function and objective. States and actions are kept as
+    /// recorded, so no planning is involved.
+    pub fn replay(&self, trace: &Trace<M::State, A, N>) -> Trace<M::State, A, N> {
+        let mut belief = self.initial_belief;
+        let mut steps = vec![];
+        for (k, step) in trace.steps.iter().enumerate() {
+            let mut cost = 0.0;
+            let current = belief;
+            if let (Some(a), Some(next)) = (step.a, trace.steps.get(k + 1)) {
+                belief = self.get_new_belief(&current, &step.s, &a, &next.s);
+                cost = self.d_cost(
+                    &BeliefState::new(step.s, current),
+                    &a,
+                    &BeliefState::new(next.s, belief),
+                );
+            }
+            steps.push(TraceStep {
+                t: step.t,
+                s: step.s,
+                a: step.a,
+                belief: current.map(|p| p.into_inner()),
+                cost,
+            });
+        }
+        Trace::new(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use mdp::baker_grid::BakerGridState;
+    use mdp::mdp_traits::Build;
+    use rand::thread_rng;
+
+    use crate::belief_cost_function::BeliefCostFunction;
+    use crate::domains::baker_grid::{BakerCOAMDPBuilder, BakerJointAction};
+    use crate::policy::RandomOAMDPPolicy;
+    use crate::trace::{read_jsonl, record_episode, write_jsonl, Trace};
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut rng = thread_rng();
+        let mut oamdp = BakerCOAMDPBuilder::new(1).build();
+        let mut policy = RandomOAMDPPolicy::new();
+        let trace: Trace<BakerGridState, BakerJointAction, 3> =
+            record_episode(&mut oamdp, &mut policy, &mut rng);
+        assert!(trace.steps.last().unwrap().a.is_none());
+
+        let mut buffer = vec![];
+        write_jsonl(&[trace.clone(), trace.clone()], &mut buffer).unwrap();
+        let traces = read_jsonl(buffer.as_slice()).unwrap();
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0], trace);
+
+        let replayed = oamdp.mdp.replay(&trace);
+        for (a, b) in replayed.steps.iter().zip(trace.steps.iter()) {
+            assert_approx_eq!(a.cost, b.cost);
+            for (pa, pb) in a.belief.iter().zip(b.belief.iter()) {
+                assert_approx_eq!(pa, pb);
+            }
+        }
+
+        oamdp.mdp.distance_measure = BeliefCostFunction::get_legible_cost_function(0);
+        let relabeled = oamdp.mdp.replay(&trace);
+        assert_eq!(relabeled.domain_trace(), trace.domain_trace());
+        assert_eq!(relabeled.beliefs(), replayed.beliefs());
+        assert!((relabeled.total_cost() - trace.total_cost()).abs() > 1e-3);
+    }
+}
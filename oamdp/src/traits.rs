@@ -7,6 +7,8 @@ use mdp::{
 use ordered_float::*;
 use std::slice::Iter;
 
+use crate::regular_grid_translator::RegularGridTranslator;
+
 pub trait BeliefOverGoal<const N: usize> {
     fn get_belief_over_goal(&self) -> [NotNan<f32>; N];
 }
@@ -19,6 +21,18 @@ impl<S: Eq + PartialEq + Debug + Hash + Copy + BeliefOverGoal<N>, const N: usize
     }
 }
 
+pub trait DiscretizeBelief<const N: usize> {
+    fn discretize_belief(&self, translator: &RegularGridTranslator<N>) -> Self;
+}
+
+impl<S: Eq + PartialEq + Debug + Hash + Copy + DiscretizeBelief<N>, const N: usize>
+    DiscretizeBelief<N> for FiniteHorizonWrapperState<S>
+{
+    fn discretize_belief(&self, translator: &RegularGridTranslator<N>) -> Self {
+        FiniteHorizonWrapperState::new(self.s.discretize_belief(translator), self.t)
+    }
+}
+
 pub trait ProbSassGivenTheta<S, A> {
     fn prob_sass_given_theta(self, id: usize, s: &S, a: &A, ss: &S) -> f32;
 }
@@ -49,6 +63,16 @@ impl<M: Message + StatesActions> Message for FiniteHorizonWrapper<M> {
     type Message = M::Message;
 }
 
+pub trait SilentMessage: Message {
+    fn is_silent(&self, m: &Self::Message) -> bool;
+}
+
+impl<M: SilentMessage + StatesActions> SilentMessage for FiniteHorizonWrapper<M> {
+    fn is_silent(&self, m: &Self::Message) -> bool {
+        self.mdp.is_silent(m)
+    }
+}
+
 pub trait EnumerateMessage: Message {
     fn enumerate_message(&self) -> Iter<Self::Message>;
 }
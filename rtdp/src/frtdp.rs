@@ -0,0 +1,231 @@
+use super::rtdp::RTDP;
+use core::fmt::Debug;
+use core::hash::Hash;
+use mdp::heuristic::HeuristicWithMDPMut;
+use mdp::mdp_traits::{
+    ActionAvailability, ActionEnumerable, Cost, GetNextStateMut, InitialState, IsTerminal,
+    PMassMut, StatesActions,
+};
+use std::collections::HashMap;
+
+const INITIAL_MAX_DEPTH: usize = 10;
+const MAX_DEPTH_GROWTH: f32 = 1.1;
+
+/// Occupancy-weighted reductions of the gap made during one trial, split
+/// into updates deep in the trial and the rest.
+#[derive(Default)]
+struct UpdateQuality {
+    shallow: (f32, usize),
+    deep: (f32, usize),
+}
+
+impl UpdateQuality {
+    fn track(&mut self, quality: f32, depth: usize, max_depth: usize) {
+        let (sum, n) = if depth as f32 > max_depth as f32 / MAX_DEPTH_GROWTH {
+            &mut self.deep
+        } else {
+            &mut self.shallow
+        };
+        *sum += quality;
+        *n += 1;
+    }
+
+    fn deep_at_least_shallow(&self) -> bool {
+        let mean = |(sum, n): (f32, usize)| if n == 0 { 0.0 } else { sum / n as f32 };
+        self.deep.1 > 0 && mean(self.deep) >= mean(self.shallow)
+    }
+}
+
+impl<S: PartialEq + Eq + Copy + Clone + Debug + Hash, H> RTDP<S, H> {
+    /// Focused RTDP (Smith & Simmons, 2006). Trials follow the successor with
+    /// the largest probability-weighted priority, where a state's priority is
+    /// its excess gap (the gap between its bounds beyond `epsilon / 2`)
+    /// capped by the priorities propagated back from its successors. A trial
+    /// ends at a state without excess gap or at the depth bound, which grows
+    /// whenever updates near the bound narrow the occupancy-weighted gap no
+    /// less than those above it.
+    pub fn frtdp<M>(&mut self, mdp: &mut M, num_trials: usize, epsilon: f32)
+    where
+        M: InitialState
+            + StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + GetNextStateMut
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let s0 = mdp.initial_state();
+        let mut priority = HashMap::new();
+        let mut max_depth = INITIAL_MAX_DEPTH.min(self.max_t);
+        let mut trial = 0;
+        while self.gap_mut(&s0, mdp) > epsilon && (num_trials == 0 || trial < num_trials) {
+            let quality = self.frtdp_trial(s0, mdp, &mut priority, max_depth, epsilon);
+            if quality.deep_at_least_shallow() {
+                max_depth = ((max_depth as f32 * MAX_DEPTH_GROWTH).ceil() as usize).min(self.max_t);
+            }
+            trial += 1;
+        }
+    }
+
+    fn frtdp_trial<M>(
+        &mut self,
+        s0: M::State,
+        mdp: &mut M,
+        priority: &mut HashMap<M::State, f32>,
+        max_depth: usize,
+        epsilon: f32,
+    ) -> UpdateQuality
+    where
+        M: StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let mut quality = UpdateQuality::default();
+        let mut visited = Vec::new();
+        let mut current_state = s0;
+        let mut occupancy = 1.0;
+
+        while !mdp.is_terminal(&current_state) {
+            let gap_before = self.gap_mut(&current_state, mdp);
+            let next = self.frtdp_update(&current_state, mdp, priority, epsilon);
+            let gap = self.gap_mut(&current_state, mdp);
+            quality.track(occupancy * (gap_before - gap), visited.len(), max_depth);
+            visited.push(current_state);
+
+            if gap <= epsilon / 2.0 || visited.len() > max_depth {
+                break;
+            }
+            match next {
+                Some((ss, p)) => {
+                    current_state = ss;
+                    occupancy *= p;
+                }
+                None => break,
+            }
+        }
+
+        while let Some(s) = visited.pop() {
+            self.frtdp_update(&s, mdp, priority, epsilon);
+        }
+
+        quality
+    }
+
+    /// Backs up both bounds and the priority of `s`, and returns the
+    /// successor to descend to together with its transition probability.
+    fn frtdp_update<M>(
+        &mut self,
+        s: &M::State,
+        mdp: &mut M,
+        priority: &mut HashMap<M::State, f32>,
+        epsilon: f32,
+    ) -> Option<(M::State, f32)>
+    where
+        M: StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let a = self.update_bounds(s, mdp)?;
+
+        let mut next: Option<(M::State, f32, f32)> = None;
+        for (ss, p) in mdp.p_mass_mut(s, &a) {
+            let pp = p * self.get_priority(&ss, mdp, priority, epsilon);
+            match next {
+                Some((_, _, best)) if best >= pp => {}
+                _ => next = Some((ss, p, pp)),
+            }
+        }
+        let excess_gap = self.excess_gap(s, mdp, epsilon);
+        let p_s = next.map_or(excess_gap, |(_, _, pp)| pp.min(excess_gap));
+        priority.insert(*s, p_s);
+
+        next.map(|(ss, p, _)| (ss, p))
+    }
+
+    fn excess_gap<M>(&mut self, s: &M::State, mdp: &mut M, epsilon: f32) -> f32
+    where
+        M: StatesActions<State = S> + PMassMut<f32> + Cost + IsTerminal,
+        H: HeuristicWithMDPMut<M>,
+    {
+        self.gap_mut(s, mdp) - epsilon / 2.0
+    }
+
+    fn get_priority<M>(
+        &mut self,
+        s: &M::State,
+        mdp: &mut M,
+        priority: &HashMap<M::State, f32>,
+        epsilon: f32,
+    ) -> f32
+    where
+        M: StatesActions<State = S> + PMassMut<f32> + Cost + IsTerminal,
+        H: HeuristicWithMDPMut<M>,
+    {
+        match priority.get(s) {
+            Some(p) => *p,
+            None => self.excess_gap(s, mdp, epsilon),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use mdp::grid_world::{GridWorldMDP, GridWorldState};
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::value_iteration::value_iteration_ssp;
+
+    #[test]
+    fn test_grid_world_frtdp() {
+        let mut mdp = GridWorldMDP::new(
+            4,
+            4,
+            GridWorldState::new(0, 0),
+            GridWorldState::new(3, 3),
+            vec![GridWorldState::new(2, 3)],
+            vec![],
+        );
+        let vt = value_iteration_ssp(&mdp);
+
+        let mut rtdp = RTDP::new(ZeroHeuristic {}).set_upper_bound(100.0);
+        rtdp.frtdp(&mut mdp, 0, 1e-3);
+        assert_approx_eq!(
+            vt.get_value(&mdp.initial_state()),
+            rtdp.vt.get_value(&mdp.initial_state()),
+            1e-2
+        );
+    }
+
+    #[test]
+    fn test_grid_world_frtdp_beyond_initial_max_depth() {
+        let mut mdp = GridWorldMDP::new(
+            12,
+            12,
+            GridWorldState::new(0, 0),
+            GridWorldState::new(11, 11),
+            vec![],
+            vec![],
+        );
+        let vt = value_iteration_ssp(&mdp);
+
+        let mut rtdp = RTDP::new(ZeroHeuristic {}).set_upper_bound(100.0);
+        rtdp.frtdp(&mut mdp, 0, 1e-3);
+        assert!(vt.get_value(&mdp.initial_state()) > INITIAL_MAX_DEPTH as f32);
+        assert_approx_eq!(
+            vt.get_value(&mdp.initial_state()),
+            rtdp.vt.get_value(&mdp.initial_state()),
+            1e-2
+        );
+    }
+}
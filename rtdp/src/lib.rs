@@ -1,9 +1,12 @@
 extern crate mdp;
 
 mod check_solved;
+pub mod frtdp;
 pub mod rtdp;
 pub mod rtdp_ensure_convergence_wrapper;
 // mod rtdp_policy_evaluation;
 pub mod rtdp_softmax_policy;
 // pub mod rtdp_trait;
 pub mod lrtdp;
+mod ub;
+pub mod vpi_rtdp;
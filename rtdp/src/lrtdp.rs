@@ -78,6 +78,7 @@ mod tests {
     use mdp::blocks_world::BlocksWorldPartialMDP;
     use mdp::blocks_world::Location::*;
     use mdp::blocks_world::*;
+    use mdp::canadian_traveler::CanadianTravelerMDP;
     use mdp::grid_world::{GridWorldMDP, GridWorldState};
     use mdp::heuristic::HminHeuristic;
     use mdp::mdp_traits::BuildFrom;
@@ -137,4 +138,15 @@ mod tests {
             1e-1
         );
     }
+
+    #[test]
+    fn test_canadian_traveler_lrtdp() {
+        let mut mdp = CanadianTravelerMDP::from_data("small.yaml");
+        let mut rng = thread_rng();
+        let err = 1e-3;
+        let mut lrtdp = RTDP::new(HminHeuristic::new());
+        lrtdp.lrtdp(&mut mdp, 0, &mut rng, err);
+
+        assert_approx_eq!(7.0, lrtdp.vt.get_value(&mdp.initial_state()), 1e-2);
+    }
 }
@@ -17,6 +17,7 @@ pub struct RTDP<S: PartialEq + Eq + Copy + Clone + Debug + Hash, H> {
     pub h: H,
     pub is_solved: HashSet<S>,
     pub max_t: usize,
+    pub ub: ValueTable<S>,
 }
 
 impl<M: StatesActions + PMass<f32> + Cost, H: HeuristicWithMDP<M>> CostEstimator<M>
@@ -138,9 +139,15 @@ impl<S: PartialEq + Eq + Copy + Clone + Debug + Hash, H> RTDP<S, H> {
             h: h,
             is_solved: HashSet::new(),
             max_t: 1000,
+            ub: ValueTable::new(1e+8),
         }
     }
 
+    pub fn set_upper_bound(mut self, ub: f32) -> Self {
+        self.ub = ValueTable::new(ub);
+        self
+    }
+
     pub fn num_states(&self) -> usize {
         self.vt.value_table.len()
     }
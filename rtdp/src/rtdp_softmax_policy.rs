@@ -2,6 +2,7 @@ use crate::rtdp::RTDP;
 use mdp::heuristic::{HeuristicWithMDPMut, HminHeuristic, ZeroHeuristic};
 use mdp::mdp_traits::*;
 use mdp::policy::policy_traits::GetActionProbabilityMut;
+use mdp::policy::softmax_policy::boltzmann_probability;
 use mdp::value_estimator::CostEstimatorMut;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -64,31 +65,21 @@ impl<
             }
         }
 
-        let min_qsa = self.rtdp.get_value_ssp_mut(s, mdp);
-        let numerator =
-            (self.beta * (-1.0) * (self.rtdp.get_qsa_ssp_mut(s, a, mdp) - min_qsa)).exp();
-        let mut denominator = 0.0;
+        let qsa = self.rtdp.get_qsa_ssp_mut(s, a, mdp);
+        let mut qs = vec![];
         for a_id in 0..mdp.num_actions() {
             let at = *mdp.id_to_action(a_id);
-            denominator +=
-                (self.beta * (-1.0) * (self.rtdp.get_qsa_ssp_mut(s, &at, mdp) - min_qsa)).exp();
-        }
-        let result = numerator / denominator;
-
-        if result == 0.0 {
-            1e-6
-        } else {
-            result
+            qs.push(self.rtdp.get_qsa_ssp_mut(s, &at, mdp));
         }
+        boltzmann_probability(self.beta, qsa, &qs)
     }
 }
 
 mod tests {
     use super::*;
     use assert_approx_eq::assert_approx_eq;
-    
+
     use mdp::grid_world::{GridWorldAction::*, GridWorldMDP, GridWorldState};
-    
 
     #[test]
     fn test_grid_world_softmax() {
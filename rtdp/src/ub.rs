@@ -0,0 +1,65 @@
+use super::rtdp::RTDP;
+use core::fmt::Debug;
+use core::hash::Hash;
+use mdp::heuristic::HeuristicWithMDPMut;
+use mdp::mdp_traits::{
+    ActionAvailability, ActionEnumerable, Cost, IsTerminal, PMassMut, StatesActions,
+};
+use mdp::value_estimator::CostEstimatorMut;
+
+impl<S: PartialEq + Eq + Copy + Clone + Debug + Hash, H> RTDP<S, H> {
+    pub fn get_ub<M>(&self, s: &M::State, mdp: &M) -> f32
+    where
+        M: StatesActions<State = S> + IsTerminal,
+    {
+        if mdp.is_terminal(s) {
+            0.0
+        } else {
+            self.ub.get_value(s)
+        }
+    }
+
+    pub fn gap_mut<M>(&mut self, s: &M::State, mdp: &mut M) -> f32
+    where
+        M: StatesActions<State = S> + PMassMut<f32> + Cost + IsTerminal,
+        H: HeuristicWithMDPMut<M>,
+    {
+        (self.get_ub(s, mdp) - self.get_value_ssp_mut(s, mdp)).max(0.0)
+    }
+
+    pub(crate) fn get_ub_qsa_mut<M>(&mut self, s: &M::State, a: &M::Action, mdp: &mut M) -> f32
+    where
+        M: StatesActions<State = S> + PMassMut<f32> + Cost + IsTerminal,
+    {
+        mdp.p_mass_mut(s, a)
+            .into_iter()
+            .map(|(ss, p)| self.get_ub(&ss, mdp) * p)
+            .sum::<f32>()
+            + mdp.cost(s, a)
+    }
+
+    /// Backs up both bounds of `s` and returns the action greedy with respect to the lower bound.
+    pub(crate) fn update_bounds<M>(&mut self, s: &M::State, mdp: &mut M) -> Option<M::Action>
+    where
+        M: StatesActions<State = S>
+            + PMassMut<f32>
+            + Cost
+            + IsTerminal
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let mut ub = self.get_ub(s, mdp);
+        for a_id in 0..mdp.num_actions() {
+            let a = *mdp.id_to_action(a_id);
+            if mdp.action_available(s, &a) {
+                ub = ub.min(self.get_ub_qsa_mut(s, &a, mdp));
+            }
+        }
+        self.ub.set_value(s, ub);
+
+        let a = self.best_action_mut(s, mdp)?;
+        self.update(s, &a, mdp);
+        Some(a)
+    }
+}
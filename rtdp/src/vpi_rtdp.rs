@@ -0,0 +1,172 @@
+use super::rtdp::RTDP;
+use core::fmt::Debug;
+use core::hash::Hash;
+use mdp::heuristic::HeuristicWithMDPMut;
+use mdp::mdp_traits::{
+    ActionAvailability, ActionEnumerable, Cost, GetNextStateMut, InitialState, IsTerminal,
+    PMassMut, StatesActions,
+};
+use mdp::value_estimator::CostEstimatorMut;
+use rand::prelude::*;
+
+impl<S: PartialEq + Eq + Copy + Clone + Debug + Hash, H> RTDP<S, H> {
+    /// VPI-RTDP. Trials descend to the successor whose exact value would be
+    /// most informative for the greedy action choice, falling back to sampling
+    /// successors in proportion to their probability-weighted gaps.
+    pub fn vpi_rtdp<M>(&mut self, mdp: &mut M, num_trials: usize, rng: &mut ThreadRng, epsilon: f32)
+    where
+        M: InitialState
+            + StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + GetNextStateMut
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let s0 = mdp.initial_state();
+        let mut trial = 0;
+        while self.gap_mut(&s0, mdp) > epsilon && (num_trials == 0 || trial < num_trials) {
+            self.vpi_rtdp_trial(s0, mdp, rng, epsilon);
+            trial += 1;
+        }
+    }
+
+    fn vpi_rtdp_trial<M>(&mut self, s0: M::State, mdp: &mut M, rng: &mut ThreadRng, epsilon: f32)
+    where
+        M: StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let tau = 100.0;
+        let mut visited = Vec::new();
+        let mut current_state = s0;
+
+        while !mdp.is_terminal(&current_state) && visited.len() < self.max_t {
+            let a = match self.update_bounds(&current_state, mdp) {
+                Some(a) => a,
+                None => break,
+            };
+            visited.push(current_state);
+
+            let next_states = mdp
+                .p_mass_mut(&current_state, &a)
+                .into_iter()
+                .map(|(ss, p)| {
+                    let gap = self.gap_mut(&ss, mdp);
+                    (ss, p * gap)
+                })
+                .collect::<Vec<_>>();
+            let sum_gap = next_states.iter().map(|(_, g)| g).sum::<f32>();
+            if sum_gap <= self.gap_mut(&s0, mdp) / tau || sum_gap <= epsilon {
+                break;
+            }
+
+            let mut best_vpi = None;
+            for (ss, _) in &next_states {
+                let vpi = self.vpi(&current_state, &a, ss, mdp);
+                match best_vpi {
+                    Some((_, v)) if v >= vpi => {}
+                    _ => best_vpi = Some((*ss, vpi)),
+                }
+            }
+
+            current_state = match best_vpi {
+                Some((ss, vpi)) if vpi > epsilon => ss,
+                _ => {
+                    next_states
+                        .choose_weighted(rng, |(_, g)| *g / sum_gap)
+                        .unwrap()
+                        .0
+                }
+            };
+        }
+
+        while let Some(s) = visited.pop() {
+            self.update_bounds(&s, mdp);
+        }
+    }
+
+    /// Expected decrease in the cost of the action chosen at `s` if the value
+    /// of `ss` were known, assuming it is uniformly distributed between its bounds.
+    fn vpi<M>(&mut self, s: &M::State, a_star: &M::Action, ss: &M::State, mdp: &mut M) -> f32
+    where
+        M: StatesActions<State = S>
+            + PMassMut<f32>
+            + IsTerminal
+            + Cost
+            + ActionEnumerable
+            + ActionAvailability,
+        H: HeuristicWithMDPMut<M>,
+    {
+        let width = self.gap_mut(ss, mdp);
+        if width <= 0.0 {
+            return 0.0;
+        }
+
+        let q_star = self.get_qsa_ssp_mut(s, a_star, mdp);
+        let p_star = probability(mdp.p_mass_mut(s, a_star), ss);
+        let mut vpi = 0.0f32;
+        for a_id in 0..mdp.num_actions() {
+            let a = *mdp.id_to_action(a_id);
+            if a == *a_star || !mdp.action_available(s, &a) {
+                continue;
+            }
+            let q = self.get_qsa_ssp_mut(s, &a, mdp);
+            let slope = p_star - probability(mdp.p_mass_mut(s, &a), ss);
+            if slope <= 0.0 {
+                continue;
+            }
+            let root = (q - q_star) / slope;
+            if root < width {
+                vpi = vpi.max(slope * (width - root) * (width - root) / (2.0 * width));
+            }
+        }
+
+        vpi
+    }
+}
+
+fn probability<S: PartialEq, I: IntoIterator<Item = (S, f32)>>(distribution: I, s: &S) -> f32 {
+    distribution
+        .into_iter()
+        .filter(|(ss, _)| ss == s)
+        .map(|(_, p)| p)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use mdp::grid_world::{GridWorldMDP, GridWorldState};
+    use mdp::heuristic::ZeroHeuristic;
+    use mdp::value_iteration::value_iteration_ssp;
+
+    #[test]
+    fn test_grid_world_vpi_rtdp() {
+        let mut mdp = GridWorldMDP::new(
+            4,
+            4,
+            GridWorldState::new(0, 0),
+            GridWorldState::new(3, 3),
+            vec![GridWorldState::new(2, 3)],
+            vec![],
+        );
+        let mut rng = thread_rng();
+        let vt = value_iteration_ssp(&mdp);
+
+        let mut rtdp = RTDP::new(ZeroHeuristic {}).set_upper_bound(100.0);
+        rtdp.vpi_rtdp(&mut mdp, 0, &mut rng, 1e-3);
+        assert_approx_eq!(
+            vt.get_value(&mdp.initial_state()),
+            rtdp.vt.get_value(&mdp.initial_state()),
+            1e-2
+        );
+    }
+}